@@ -0,0 +1,845 @@
+use core::{cell::RefCell, fmt::Display};
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aes_gcm::{
+    aead::{rand_core::RngCore, Aead, OsRng},
+    Aes256Gcm, Key, KeyInit, Nonce,
+};
+use argon2::Argon2;
+use base64::Engine;
+
+/// Marks data encrypted with a key derived from a passphrase, followed by the base64 of the salt,
+/// nonce and ciphertext.
+const PREFIX: &str = "argon2id$";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+/// Encrypts and decrypts puzzle data with keys derived from a passphrase.
+///
+/// Every salt's key is derived once, and everything encrypted by the same `Keys` shares a salt,
+/// so that a whole data directory costs a single derivation.
+pub(crate) struct Keys {
+    passphrase: String,
+    salt: [u8; SALT_LEN],
+    derived: RefCell<BTreeMap<[u8; SALT_LEN], Aes256Gcm>>,
+}
+
+impl Keys {
+    pub(crate) fn new(passphrase: String) -> Self {
+        let mut salt = [0_u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+
+        Self {
+            passphrase,
+            salt,
+            derived: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    fn cipher(&self, salt: [u8; SALT_LEN]) -> Result<Aes256Gcm, DecryptError> {
+        if let Some(cipher) = self.derived.borrow().get(&salt) {
+            return Ok(cipher.clone());
+        }
+
+        let mut key = [0_u8; KEY_LEN];
+        Argon2::default()
+            .hash_password_into(self.passphrase.as_bytes(), &salt, &mut key)
+            .map_err(DecryptError::KeyDerivation)?;
+        let cipher = Aes256Gcm::new(&Key::<Aes256Gcm>::from(key));
+
+        let _prev = self.derived.borrow_mut().insert(salt, cipher.clone());
+        Ok(cipher)
+    }
+
+    pub(crate) fn encrypt(&self, plaintext: &[u8]) -> anyhow::Result<String> {
+        let mut nonce = [0_u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+
+        let ciphertext = self
+            .cipher(self.salt)?
+            .encrypt(&Nonce::from(nonce), plaintext)?;
+
+        let encoded = base64::engine::general_purpose::STANDARD.encode(
+            self.salt
+                .iter()
+                .chain(&nonce)
+                .chain(&ciphertext)
+                .copied()
+                .collect::<Vec<u8>>(),
+        );
+        Ok(format!("{PREFIX}{encoded}"))
+    }
+
+    /// Decrypts data written by [`Keys::encrypt`], or by earlier versions that used the
+    /// passphrase itself as a 32 byte key.
+    pub(crate) fn decrypt(&self, contents: &str) -> Result<Vec<u8>, DecryptError> {
+        let contents = contents.trim();
+
+        let (cipher, decoded) = if let Some(encoded) = contents.strip_prefix(PREFIX) {
+            let decoded = base64::engine::general_purpose::STANDARD
+                .decode(encoded)
+                .map_err(DecryptError::Base64)?;
+            let Some((salt, rest)) = decoded.split_first_chunk::<SALT_LEN>() else {
+                return Err(DecryptError::TooShort { missing: "salt" });
+            };
+            (self.cipher(*salt)?, rest.to_vec())
+        } else {
+            let Ok(key) = <[u8; KEY_LEN]>::try_from(self.passphrase.as_bytes()) else {
+                return Err(DecryptError::UnsaltedKey);
+            };
+            let decoded = base64::engine::general_purpose::STANDARD
+                .decode(contents)
+                .map_err(DecryptError::Base64)?;
+            (Aes256Gcm::new(&Key::<Aes256Gcm>::from(key)), decoded)
+        };
+
+        let Some((nonce, ciphertext)) = decoded.split_first_chunk::<NONCE_LEN>() else {
+            return Err(DecryptError::TooShort { missing: "nonce" });
+        };
+        cipher
+            .decrypt(&Nonce::from(*nonce), ciphertext)
+            .map_err(|_err| DecryptError::Authentication)
+    }
+}
+
+/// Why an encrypted file could not be decrypted.
+#[derive(Debug)]
+pub(crate) enum DecryptError {
+    Base64(base64::DecodeError),
+    /// The decoded data ends before the named field does.
+    TooShort {
+        missing: &'static str,
+    },
+    /// The key is wrong, or the ciphertext was modified.
+    Authentication,
+    /// Unsalted data from before passphrases, which needs the raw key.
+    UnsaltedKey,
+    KeyDerivation(argon2::Error),
+}
+
+impl Display for DecryptError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Base64(err) => write!(formatter, "invalid base64: {err}"),
+            Self::TooShort { missing } => {
+                write!(formatter, "too short to contain a {missing}")
+            }
+            Self::Authentication => formatter.write_str(
+                "authentication failed, either the key is wrong or the file was modified",
+            ),
+            Self::UnsaltedKey => formatter.write_str(
+                "data without a salt can only be decrypted with the original 32 byte key",
+            ),
+            Self::KeyDerivation(err) => {
+                write!(
+                    formatter,
+                    "failed to derive a key from the passphrase: {err}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecryptError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Base64(err) => Some(err),
+            Self::TooShort { .. }
+            | Self::Authentication
+            | Self::UnsaltedKey
+            | Self::KeyDerivation(_) => None,
+        }
+    }
+}
+
+/// Every `.enc.txt` file in `data_dir`, including named inputs one level down in
+/// `data/yYYYY_day_XX/<name>.enc.txt`.
+pub(crate) fn encrypted_files(data_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = data_files(data_dir)?;
+    files.retain(|path| is_encrypted(path));
+    Ok(files)
+}
+
+/// Every input file in `data_dir` that has not been encrypted yet.
+fn plaintext_files(data_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = data_files(data_dir)?;
+    files.retain(|path| {
+        !is_encrypted(path)
+            && day_of(data_dir, path).is_some()
+            && path.extension().is_some_and(|extension| extension == "txt")
+    });
+    Ok(files)
+}
+
+fn is_encrypted(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(".enc.txt"))
+}
+
+/// Every file in `data_dir` and the directories directly inside it, sorted.
+fn data_files(data_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(data_dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            for nested in fs::read_dir(&path)? {
+                files.push(nested?.path());
+            }
+        } else {
+            files.push(path);
+        }
+    }
+    files.sort_unstable();
+
+    Ok(files)
+}
+
+/// The plaintext file an encrypted file decrypts to.
+pub(crate) fn plaintext_path(encrypted: &Path) -> anyhow::Result<PathBuf> {
+    let Some(file_name) = encrypted.file_name().and_then(|name| name.to_str()) else {
+        anyhow::bail!("Invalid file name for decryption: {encrypted:?}");
+    };
+    Ok(encrypted.with_file_name(file_name.replace(".enc.txt", ".txt")))
+}
+
+/// The year and day a data file holds input for, from `yYYYY_day_XX.enc.txt` or
+/// `yYYYY_day_XX/<name>.enc.txt`.
+fn day_of(data_dir: &Path, path: &Path) -> Option<(u32, u32)> {
+    let first = path.strip_prefix(data_dir).ok()?.components().next()?;
+    crate::parse_package_name(first.as_os_str().to_str()?.split('.').next()?)
+}
+
+/// Whether the file holds input for one of `days` of `year`, where no year means every year and
+/// no days means all of them.
+fn is_selected(data_dir: &Path, path: &Path, year: Option<u32>, days: &[u32]) -> bool {
+    day_of(data_dir, path).is_some_and(|(file_year, day)| {
+        year.is_none_or(|year| year == file_year) && (days.is_empty() || days.contains(&day))
+    })
+}
+
+/// How many files [`decrypt_data`] or [`encrypt_data`] wrote, and how many already matched.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Written {
+    pub(crate) written: usize,
+    pub(crate) up_to_date: usize,
+}
+
+/// Decrypts the encrypted files for `days` of `year`, or every day and year if they are empty,
+/// next to themselves.
+///
+/// Plaintext that already matches is left alone. Every file that fails to decrypt is listed in
+/// the error, along with the reason.
+pub(crate) fn decrypt_data(
+    keys: &Keys,
+    data_dir: &Path,
+    year: Option<u32>,
+    days: &[u32],
+) -> anyhow::Result<Written> {
+    let mut decrypted = Written::default();
+    let mut failures = Vec::new();
+
+    for path in encrypted_files(data_dir)? {
+        if !is_selected(data_dir, &path, year, days) {
+            continue;
+        }
+
+        let plaintext = match keys.decrypt(&fs::read_to_string(&path)?) {
+            Ok(plaintext) => plaintext,
+            Err(err) => {
+                failures.push(format!("  {}: {err}", path.display()));
+                continue;
+            }
+        };
+
+        let plaintext_path = plaintext_path(&path)?;
+        if fs::read(&plaintext_path).is_ok_and(|existing| existing == plaintext) {
+            decrypted.up_to_date += 1;
+        } else {
+            fs::write(plaintext_path, plaintext)?;
+            decrypted.written += 1;
+        }
+    }
+
+    if !failures.is_empty() {
+        anyhow::bail!(
+            "Failed to decrypt {} file(s):\n{}",
+            failures.len(),
+            failures.join("\n")
+        );
+    }
+
+    Ok(decrypted)
+}
+
+/// Encrypts the plaintext input for `days` of `year`, or every day and year if they are empty,
+/// next to itself.
+///
+/// Files whose encrypted copy already decrypts to the same plaintext are left alone, so that
+/// re-running this does not change committed files.
+pub(crate) fn encrypt_data(
+    keys: &Keys,
+    data_dir: &Path,
+    year: Option<u32>,
+    days: &[u32],
+) -> anyhow::Result<Written> {
+    let mut encrypted = Written::default();
+
+    for path in plaintext_files(data_dir)? {
+        if !is_selected(data_dir, &path, year, days) {
+            continue;
+        }
+
+        let plaintext = fs::read(&path)?;
+        let encrypted_path = path.with_extension("enc.txt");
+        let up_to_date = fs::read_to_string(&encrypted_path)
+            .ok()
+            .and_then(|existing| keys.decrypt(&existing).ok())
+            .is_some_and(|existing| existing == plaintext);
+
+        if up_to_date {
+            encrypted.up_to_date += 1;
+        } else {
+            fs::write(encrypted_path, keys.encrypt(&plaintext)?)?;
+            encrypted.written += 1;
+        }
+    }
+
+    Ok(encrypted)
+}
+
+/// Re-encrypts every encrypted file in `data_dir` from `old` to `new`, returning how many there
+/// were.
+///
+/// Nothing is written unless every file decrypts, and the new contents are staged next to the
+/// originals before [`replace_all`] renames them over the originals.
+pub(crate) fn rotate(old: &Keys, new: &Keys, data_dir: &Path) -> anyhow::Result<usize> {
+    let files = encrypted_files(data_dir)?;
+
+    let mut reencrypted = Vec::with_capacity(files.len());
+    for path in &files {
+        let decrypted = old.decrypt(&fs::read_to_string(path)?).map_err(|err| {
+            anyhow::anyhow!("Failed to decrypt {path:?}, nothing was changed: {err}")
+        })?;
+        reencrypted.push(new.encrypt(&decrypted)?);
+    }
+
+    let staged = files
+        .iter()
+        .map(|path| path.with_extension("txt.rotating"))
+        .collect::<Vec<_>>();
+    for (staged_path, contents) in staged.iter().zip(&reencrypted) {
+        if let Err(err) = fs::write(staged_path, contents) {
+            for leftover in &staged {
+                let _ignored = fs::remove_file(leftover);
+            }
+            return Err(err.into());
+        }
+    }
+
+    replace_all(&files, &staged, |from, to| fs::rename(from, to))?;
+
+    Ok(files.len())
+}
+
+/// Renames each of the `staged` files over its original in `files`, keeping a `.bak` copy of
+/// every original until all of them are replaced. If any rename fails, the originals that were
+/// already replaced are restored from their copies and the remaining staged files are removed,
+/// so either every file is replaced or none is.
+fn replace_all(
+    files: &[PathBuf],
+    staged: &[PathBuf],
+    rename: impl Fn(&Path, &Path) -> io::Result<()>,
+) -> anyhow::Result<()> {
+    let remove_all = |paths: &[PathBuf]| {
+        for path in paths {
+            let _ignored = fs::remove_file(path);
+        }
+    };
+    let backups = files
+        .iter()
+        .map(|path| path.with_extension("txt.bak"))
+        .collect::<Vec<_>>();
+
+    for (path, backup) in files.iter().zip(&backups) {
+        if let Err(err) = fs::copy(path, backup) {
+            remove_all(&backups);
+            remove_all(staged);
+            anyhow::bail!("Failed to back up {path:?}, nothing was changed: {err}");
+        }
+    }
+
+    for (idx, (staged_path, path)) in staged.iter().zip(files).enumerate() {
+        if let Err(err) = rename(staged_path, path) {
+            let unrestored = files
+                .iter()
+                .zip(&backups)
+                .take(idx)
+                .filter(|&(original, backup)| fs::rename(backup, original).is_err())
+                .map(|(_, backup)| backup.clone())
+                .collect::<Vec<_>>();
+            remove_all(staged.get(idx..).unwrap_or_default());
+            remove_all(backups.get(idx..).unwrap_or_default());
+
+            if unrestored.is_empty() {
+                anyhow::bail!("Failed to replace {path:?}, nothing was changed: {err}");
+            }
+            anyhow::bail!(
+                "Failed to replace {path:?} ({err}), and could not restore the originals kept in {unrestored:?}"
+            );
+        }
+    }
+
+    remove_all(&backups);
+    Ok(())
+}
+
+#[cfg(test)]
+mod crypto_tests {
+    use super::*;
+
+    const PASSPHRASE: &str = "correct horse battery staple";
+    const LEGACY_KEY: &str = "0123456789abcdef0123456789abcdef";
+
+    fn temp_data_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!("fetch_day_crypto_tests_{name}"))
+            .join("data");
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        fs::create_dir_all(dir.join("y2024_day_02")).unwrap();
+        dir
+    }
+
+    /// Encrypts the way earlier versions did, with the passphrase as the key and no salt.
+    fn legacy_encrypt(plaintext: &str) -> String {
+        let cipher = Aes256Gcm::new(&Key::<Aes256Gcm>::from(
+            <[u8; KEY_LEN]>::try_from(LEGACY_KEY.as_bytes()).unwrap(),
+        ));
+        let nonce = [7_u8; NONCE_LEN];
+        let ciphertext = cipher
+            .encrypt(&Nonce::from(nonce), plaintext.as_bytes())
+            .unwrap();
+        base64::engine::general_purpose::STANDARD
+            .encode(nonce.iter().chain(&ciphertext).copied().collect::<Vec<_>>())
+    }
+
+    mod keys {
+        use super::*;
+
+        #[test]
+        fn round_trip_with_any_passphrase() {
+            let encrypted = Keys::new(PASSPHRASE.to_owned()).encrypt(b"1 2 3").unwrap();
+
+            assert!(encrypted.starts_with(PREFIX), "Missing prefix: {encrypted}");
+            assert_eq!(
+                b"1 2 3".to_vec(),
+                Keys::new(PASSPHRASE.to_owned())
+                    .decrypt(&encrypted)
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn salts_differ_between_keys() {
+            let first = Keys::new(PASSPHRASE.to_owned());
+            let second = Keys::new(PASSPHRASE.to_owned());
+
+            assert_ne!(
+                first.encrypt(b"1").unwrap().get(..PREFIX.len() + 20),
+                second.encrypt(b"1").unwrap().get(..PREFIX.len() + 20),
+            );
+        }
+
+        #[test]
+        fn wrong_passphrase() {
+            let encrypted = Keys::new(PASSPHRASE.to_owned()).encrypt(b"1 2 3").unwrap();
+
+            assert_eq!(
+                "authentication failed, either the key is wrong or the file was modified",
+                Keys::new(String::from("wrong"))
+                    .decrypt(&encrypted)
+                    .unwrap_err()
+                    .to_string()
+            );
+        }
+
+        #[test]
+        fn legacy_data() {
+            assert_eq!(
+                b"3   4\n".to_vec(),
+                Keys::new(LEGACY_KEY.to_owned())
+                    .decrypt(&legacy_encrypt("3   4\n"))
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn legacy_data_needs_a_32_byte_key() {
+            assert_eq!(
+                "data without a salt can only be decrypted with the original 32 byte key",
+                Keys::new(PASSPHRASE.to_owned())
+                    .decrypt(&legacy_encrypt("3   4\n"))
+                    .unwrap_err()
+                    .to_string()
+            );
+        }
+    }
+
+    mod decrypt_errors {
+        use super::*;
+
+        struct Case {
+            input: String,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn bad_base64() {
+            run(&Case {
+                input: format!("{PREFIX}not*base64"),
+                expected: "invalid base64: Invalid symbol 42, offset 3.",
+            });
+        }
+
+        #[test]
+        fn no_salt() {
+            run(&Case {
+                input: format!("{PREFIX}AAAA"),
+                expected: "too short to contain a salt",
+            });
+        }
+
+        #[test]
+        fn no_nonce() {
+            run(&Case {
+                input: format!("{PREFIX}AAAAAAAAAAAAAAAAAAAAAAAAAAAA"),
+                expected: "too short to contain a nonce",
+            });
+        }
+
+        #[test]
+        fn tampered() {
+            let encrypted = Keys::new(PASSPHRASE.to_owned()).encrypt(b"1 2 3").unwrap();
+            let mut decoded = base64::engine::general_purpose::STANDARD
+                .decode(encrypted.trim_start_matches(PREFIX))
+                .unwrap();
+            if let Some(last) = decoded.last_mut() {
+                *last ^= 1;
+            }
+
+            run(&Case {
+                input: format!(
+                    "{PREFIX}{}",
+                    base64::engine::general_purpose::STANDARD.encode(decoded)
+                ),
+                expected: "authentication failed, either the key is wrong or the file was modified",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                test.expected,
+                Keys::new(PASSPHRASE.to_owned())
+                    .decrypt(&test.input)
+                    .unwrap_err()
+                    .to_string()
+            );
+        }
+    }
+
+    mod decrypt_data {
+        use super::*;
+
+        fn write_encrypted(data_dir: &Path) {
+            fs::write(data_dir.join("y2024_day_01.enc.txt"), legacy_encrypt("one")).unwrap();
+            fs::write(data_dir.join("y2024_day_02.enc.txt"), legacy_encrypt("two")).unwrap();
+            fs::write(
+                data_dir.join("y2024_day_02/alice.enc.txt"),
+                legacy_encrypt("alice"),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn selected_days() {
+            let data_dir = temp_data_dir("selected_days");
+            write_encrypted(&data_dir);
+
+            let keys = Keys::new(LEGACY_KEY.to_owned());
+            assert_eq!(
+                Written {
+                    written: 2,
+                    up_to_date: 0
+                },
+                decrypt_data(&keys, &data_dir, None, &[2]).unwrap()
+            );
+            assert!(
+                !data_dir.join("y2024_day_01.txt").exists(),
+                "Day 1 was not selected"
+            );
+            assert_eq!(
+                "alice",
+                fs::read_to_string(data_dir.join("y2024_day_02/alice.txt")).unwrap()
+            );
+        }
+
+        #[test]
+        fn selected_year() {
+            let data_dir = temp_data_dir("selected_year");
+            write_encrypted(&data_dir);
+            fs::write(data_dir.join("y2023_day_02.enc.txt"), legacy_encrypt("old")).unwrap();
+
+            let keys = Keys::new(LEGACY_KEY.to_owned());
+            assert_eq!(
+                Written {
+                    written: 1,
+                    up_to_date: 0
+                },
+                decrypt_data(&keys, &data_dir, Some(2023), &[2]).unwrap()
+            );
+            assert!(
+                !data_dir.join("y2024_day_02.txt").exists(),
+                "2024 was not selected"
+            );
+            assert_eq!(
+                "old",
+                fs::read_to_string(data_dir.join("y2023_day_02.txt")).unwrap()
+            );
+        }
+
+        #[test]
+        fn skips_up_to_date_plaintext() {
+            let data_dir = temp_data_dir("skips_up_to_date_plaintext");
+            write_encrypted(&data_dir);
+            fs::write(data_dir.join("y2024_day_01.txt"), "one").unwrap();
+            fs::write(data_dir.join("y2024_day_02.txt"), "stale").unwrap();
+
+            let keys = Keys::new(LEGACY_KEY.to_owned());
+            assert_eq!(
+                Written {
+                    written: 2,
+                    up_to_date: 1
+                },
+                decrypt_data(&keys, &data_dir, None, &[]).unwrap()
+            );
+            assert_eq!(
+                "two",
+                fs::read_to_string(data_dir.join("y2024_day_02.txt")).unwrap()
+            );
+        }
+
+        #[test]
+        fn names_every_failure() {
+            let data_dir = temp_data_dir("names_every_failure");
+            write_encrypted(&data_dir);
+            fs::write(data_dir.join("y2024_day_03.enc.txt"), "AAAA").unwrap();
+            fs::write(data_dir.join("y2024_day_04.enc.txt"), "%%%").unwrap();
+
+            let keys = Keys::new(LEGACY_KEY.to_owned());
+            assert_eq!(
+                format!(
+                    "Failed to decrypt 2 file(s):\n  {}: too short to contain a nonce\n  {}: invalid base64: Invalid symbol 37, offset 0.",
+                    data_dir.join("y2024_day_03.enc.txt").display(),
+                    data_dir.join("y2024_day_04.enc.txt").display()
+                ),
+                decrypt_data(&keys, &data_dir, None, &[]).unwrap_err().to_string()
+            );
+            assert_eq!(
+                "one",
+                fs::read_to_string(data_dir.join("y2024_day_01.txt")).unwrap(),
+                "Files that decrypt are still written"
+            );
+        }
+    }
+
+    mod encrypt_data {
+        use super::*;
+
+        #[test]
+        fn only_changed_inputs() {
+            let data_dir = temp_data_dir("only_changed_inputs");
+            let keys = Keys::new(PASSPHRASE.to_owned());
+            let unchanged = keys.encrypt(b"one").unwrap();
+            fs::write(data_dir.join("y2024_day_01.txt"), "one").unwrap();
+            fs::write(data_dir.join("y2024_day_01.enc.txt"), &unchanged).unwrap();
+            fs::write(data_dir.join("y2024_day_02/alice.txt"), "alice").unwrap();
+            fs::write(data_dir.join("answers.txt"), "2024 01 1 default 1").unwrap();
+
+            assert_eq!(
+                Written {
+                    written: 1,
+                    up_to_date: 1
+                },
+                encrypt_data(&keys, &data_dir, None, &[]).unwrap()
+            );
+            assert_eq!(
+                unchanged,
+                fs::read_to_string(data_dir.join("y2024_day_01.enc.txt")).unwrap()
+            );
+            assert_eq!(
+                b"alice".to_vec(),
+                keys.decrypt(
+                    &fs::read_to_string(data_dir.join("y2024_day_02/alice.enc.txt")).unwrap()
+                )
+                .unwrap()
+            );
+            assert!(
+                !data_dir.join("answers.enc.txt").exists(),
+                "Only inputs are encrypted"
+            );
+        }
+
+        #[test]
+        fn selected_days() {
+            let data_dir = temp_data_dir("encrypt_selected_days");
+            fs::write(data_dir.join("y2024_day_01.txt"), "one").unwrap();
+            fs::write(data_dir.join("y2024_day_02.txt"), "two").unwrap();
+
+            let keys = Keys::new(PASSPHRASE.to_owned());
+            assert_eq!(
+                Written {
+                    written: 1,
+                    up_to_date: 0
+                },
+                encrypt_data(&keys, &data_dir, None, &[2]).unwrap()
+            );
+            assert!(
+                !data_dir.join("y2024_day_01.enc.txt").exists(),
+                "Day 1 was not selected"
+            );
+        }
+    }
+
+    mod rotate {
+        use super::*;
+
+        #[test]
+        fn reencrypts_every_file() {
+            let data_dir = temp_data_dir("reencrypts_every_file");
+            fs::write(data_dir.join("y2024_day_01.enc.txt"), legacy_encrypt("one")).unwrap();
+            fs::write(
+                data_dir.join("y2024_day_02/alice.enc.txt"),
+                legacy_encrypt("two"),
+            )
+            .unwrap();
+            fs::write(data_dir.join("answers.txt"), "2024 01 1 default 1").unwrap();
+
+            let old = Keys::new(LEGACY_KEY.to_owned());
+            let new = Keys::new(PASSPHRASE.to_owned());
+            assert_eq!(2, rotate(&old, &new, &data_dir).unwrap());
+
+            let reloaded = Keys::new(PASSPHRASE.to_owned());
+            assert_eq!(
+                Written {
+                    written: 2,
+                    up_to_date: 0
+                },
+                decrypt_data(&reloaded, &data_dir, None, &[]).unwrap()
+            );
+            assert_eq!(
+                "one",
+                fs::read_to_string(data_dir.join("y2024_day_01.txt")).unwrap()
+            );
+            assert_eq!(
+                "two",
+                fs::read_to_string(data_dir.join("y2024_day_02/alice.txt")).unwrap()
+            );
+            assert_eq!(
+                vec![
+                    data_dir.join("answers.txt"),
+                    data_dir.join("y2024_day_01.enc.txt"),
+                    data_dir.join("y2024_day_01.txt"),
+                    data_dir.join("y2024_day_02"),
+                ],
+                {
+                    let mut entries = fs::read_dir(&data_dir)
+                        .unwrap()
+                        .map(|entry| entry.unwrap().path())
+                        .collect::<Vec<_>>();
+                    entries.sort_unstable();
+                    entries
+                },
+                "No staged files should be left behind"
+            );
+        }
+
+        #[test]
+        fn restores_originals_when_a_rename_fails() {
+            let data_dir = temp_data_dir("restores_originals_when_a_rename_fails");
+            let files = [
+                data_dir.join("y2024_day_01.enc.txt"),
+                data_dir.join("y2024_day_02/alice.enc.txt"),
+            ];
+            let staged = files
+                .iter()
+                .map(|path| path.with_extension("txt.rotating"))
+                .collect::<Vec<_>>();
+            for (path, staged_path) in files.iter().zip(&staged) {
+                fs::write(path, "old").unwrap();
+                fs::write(staged_path, "new").unwrap();
+            }
+
+            let err = replace_all(&files, &staged, |from, to| {
+                if to.ends_with("alice.enc.txt") {
+                    Err(io::Error::other("disk full"))
+                } else {
+                    fs::rename(from, to)
+                }
+            })
+            .unwrap_err()
+            .to_string();
+
+            assert!(
+                err.contains("alice.enc.txt") && err.contains("nothing was changed"),
+                "Unexpected error: {err}"
+            );
+            for path in &files {
+                assert_eq!("old", fs::read_to_string(path).unwrap());
+            }
+            let mut entries = [data_dir.clone(), data_dir.join("y2024_day_02")]
+                .iter()
+                .flat_map(|dir| fs::read_dir(dir).unwrap())
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.is_file())
+                .collect::<Vec<_>>();
+            entries.sort_unstable();
+            assert_eq!(
+                files.to_vec(),
+                entries,
+                "No staged or backup files should be left behind"
+            );
+        }
+
+        #[test]
+        fn leaves_files_untouched_on_failure() {
+            let data_dir = temp_data_dir("leaves_files_untouched_on_failure");
+            let good = legacy_encrypt("one");
+            fs::write(data_dir.join("y2024_day_01.enc.txt"), &good).unwrap();
+            fs::write(
+                data_dir.join("y2024_day_02/alice.enc.txt"),
+                "bm90IGVuY3J5cHRlZCBkYXRh",
+            )
+            .unwrap();
+
+            let old = Keys::new(LEGACY_KEY.to_owned());
+            let new = Keys::new(PASSPHRASE.to_owned());
+            let err = rotate(&old, &new, &data_dir).unwrap_err().to_string();
+
+            assert!(
+                err.contains("alice.enc.txt") && err.contains("nothing was changed"),
+                "Unexpected error: {err}"
+            );
+            assert_eq!(
+                good,
+                fs::read_to_string(data_dir.join("y2024_day_01.enc.txt")).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,730 @@
+use crate::markdown::{self, Node};
+
+/// The sample inputs in a day's instructions, and what each part's example test should check.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct Examples {
+    /// Sample inputs in the order they first appear, without repeats. The first is `example_1`.
+    pub(crate) inputs: Vec<String>,
+    /// Each part's example, in part order.
+    pub(crate) parts: Vec<PartExample>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PartExample {
+    /// The `example_N` the part's answer is worked out for.
+    pub(crate) number: usize,
+    /// The answer the instructions give for the example, if one was found.
+    pub(crate) answer: Option<String>,
+}
+
+impl Examples {
+    /// Picks the examples out of the HTML of each part.
+    ///
+    /// Blocks introduced by a paragraph mentioning an example are taken, such as the one after
+    /// "For example:", so that worked illustrations of the answer are skipped. If no block is
+    /// introduced that way, the first block on the page is taken instead.
+    ///
+    /// A part's answer is the last emphasised code in it, such as `<code><em>11</em></code>`,
+    /// and is taken to be for the last example shown in or before the part.
+    pub(crate) fn from_parts(parts: &[&str]) -> Self {
+        let parsed = parts
+            .iter()
+            .map(|part| markdown::parse(part))
+            .collect::<Vec<_>>();
+        let blocks = parsed
+            .iter()
+            .map(|nodes| code_blocks(nodes))
+            .collect::<Vec<_>>();
+
+        let any_introduced = blocks.iter().flatten().any(|block| block.introduced);
+        let chosen = blocks
+            .iter()
+            .flatten()
+            .filter(|block| block.introduced || !any_introduced)
+            .take(if any_introduced { usize::MAX } else { 1 })
+            .map(|block| &block.text)
+            .collect::<Vec<_>>();
+
+        let mut examples = Self::default();
+        for &text in &chosen {
+            if !examples.inputs.contains(text) {
+                examples.inputs.push(text.clone());
+            }
+        }
+
+        let mut number = 1;
+        for (nodes, part_blocks) in parsed.iter().zip(&blocks) {
+            if let Some(last) = part_blocks
+                .iter()
+                .rfind(|block| chosen.contains(&&block.text))
+            {
+                number = examples.number_of(&last.text);
+            }
+            examples.parts.push(PartExample {
+                number,
+                answer: last_emphasised_code(nodes),
+            });
+        }
+
+        examples
+    }
+
+    fn number_of(&self, input: &str) -> usize {
+        self.inputs
+            .iter()
+            .position(|known| known == input)
+            .map_or(1, |index| index + 1)
+    }
+
+    /// The `example_N` that `part`'s test checks.
+    pub(crate) fn number(&self, part: usize) -> usize {
+        self.parts.get(part - 1).map_or(1, |example| example.number)
+    }
+
+    /// What `part`'s example test expects, as Rust: the answer as a number, or as a string such
+    /// as `"4,6,3"` if it is not one, or `todo!()` if there is no answer yet.
+    pub(crate) fn expected(&self, part: usize) -> String {
+        self.answer(part).map_or_else(
+            || String::from("todo!()"),
+            |answer| rust_number(answer).unwrap_or_else(|| rust_literal(answer)),
+        )
+    }
+
+    /// The type `part` is solved as: `String` if its answer is not a number, otherwise `usize`.
+    pub(crate) fn answer_type(&self, part: usize) -> &'static str {
+        if self.is_text(part) {
+            "String"
+        } else {
+            "usize"
+        }
+    }
+
+    /// The type of what `part`'s example test expects, to match [`Self::expected`].
+    pub(crate) fn expected_type(&self, part: usize) -> &'static str {
+        if self.is_text(part) {
+            "&'static str"
+        } else {
+            "usize"
+        }
+    }
+
+    fn answer(&self, part: usize) -> Option<&str> {
+        self.parts.get(part - 1)?.answer.as_deref()
+    }
+
+    fn is_text(&self, part: usize) -> bool {
+        self.answer(part)
+            .is_some_and(|answer| rust_number(answer).is_none())
+    }
+
+    /// The answers as lines of `<year> <day> <part> example_<N> <answer>`, like the answers
+    /// registry.
+    pub(crate) fn answers(&self, year: u32, day: u32) -> String {
+        (1..)
+            .zip(&self.parts)
+            .filter_map(|(part, example)| {
+                let answer = example.answer.as_ref()?;
+                Some(format!(
+                    "{year} {day} {part} example_{} {answer}\n",
+                    example.number
+                ))
+            })
+            .collect()
+    }
+
+    /// Reads back `inputs` along with the answers written by [`Self::answers`].
+    pub(crate) fn from_files(inputs: Vec<String>, answers: &str) -> Self {
+        let mut parts = Vec::new();
+        for line in answers.lines() {
+            let mut fields = line.splitn(5, ' ').skip(2);
+            let (Some(part), Some(name), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                continue;
+            };
+            let (Ok(part), Some(Ok(number))) = (
+                part.parse::<usize>(),
+                name.strip_prefix("example_").map(str::parse),
+            ) else {
+                continue;
+            };
+            if parts.len() < part {
+                parts.resize(
+                    part,
+                    PartExample {
+                        number: 1,
+                        answer: None,
+                    },
+                );
+            }
+            if let Some(example) = parts.get_mut(part - 1) {
+                *example = PartExample {
+                    number,
+                    answer: Some(answer.to_owned()),
+                };
+            }
+        }
+
+        Self { inputs, parts }
+    }
+}
+
+/// Writes a numeric `answer` as a Rust literal with its digits grouped in threes, or `None` if
+/// it is not a number.
+pub(crate) fn rust_number(answer: &str) -> Option<String> {
+    if answer.is_empty() || !answer.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    if answer.len() <= 4 {
+        return Some(answer.to_owned());
+    }
+
+    let mut grouped = String::new();
+    for (index, digit) in answer.chars().enumerate() {
+        if index > 0 && (answer.len() - index).is_multiple_of(3) {
+            grouped.push('_');
+        }
+        grouped.push(digit);
+    }
+    Some(grouped)
+}
+
+/// Writes `text` as a Rust string literal over as many lines as it has, without its trailing
+/// newline, like the examples written by hand.
+pub(crate) fn rust_literal(text: &str) -> String {
+    format!(
+        "\"{}\"",
+        text.trim_end_matches('\n')
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+    )
+}
+
+/// Fills in the expected answer of the `part_2` example test in a `lib.rs` scaffolded before
+/// part 2 was unlocked, pointing the test at part 2's example and adding that example if it is
+/// new. An answer that is not a number also retypes part 2 from `usize` to `String`. `None` if
+/// there is nothing to fill in, such as when the test was already written or no answer was found.
+pub(crate) fn fill_part_2(lib: &str, examples: &Examples) -> Option<String> {
+    let example = examples.parts.get(1)?;
+    let _answer = examples.answer(2)?;
+    let expected = examples.expected(2);
+
+    let part_2 = lib.find("mod part_2 {")?;
+    let test_start = part_2 + lib.get(part_2..)?.find("fn example() {")?;
+    let test_end = test_start + lib.get(test_start..)?.find("\n        }\n")?;
+    let test = lib.get(test_start..test_end)?;
+    if !test.contains("expected: todo!()") {
+        return None;
+    }
+    let test = test
+        .replacen("expected: todo!()", &format!("expected: {expected}"), 1)
+        .replacen("example_1()", &format!("example_{}()", example.number), 1);
+    let mut filled = format!("{}{test}{}", lib.get(..test_start)?, lib.get(test_end..)?);
+
+    if examples.is_text(2) {
+        let case_type = part_2 + filled.get(part_2..)?.find("expected: usize,")?;
+        filled.replace_range(
+            case_type..case_type + "expected: usize,".len(),
+            &format!("expected: {},", examples.expected_type(2)),
+        );
+        filled = filled
+            .replacen("type Part2 = usize;", "type Part2 = String;", 1)
+            .replacen(
+                "fn part_2(&self) -> usize {",
+                "fn part_2(&self) -> String {",
+                1,
+            );
+    }
+
+    if !filled.contains(&format!("fn example_{}()", example.number)) {
+        // Copy the signature of the first example, which knows about any lifetimes on `Input`.
+        let signature_start = filled.find("fn example_1() -> ")?;
+        let signature = filled
+            .get(signature_start..)?
+            .split_once(" {\n")?
+            .0
+            .replacen("example_1", &format!("example_{}", example.number), 1);
+        let input = examples.inputs.get(example.number - 1)?;
+        let at = filled.find("\n    fn actual(")?;
+        filled.insert_str(
+            at,
+            &format!(
+                "\n    {signature} {{\n        (\n            {},\n            todo!(),\n        )\n    }}\n",
+                rust_literal(input)
+            ),
+        );
+    }
+
+    Some(filled)
+}
+
+#[derive(Debug)]
+struct CodeBlock {
+    text: String,
+    /// Whether the paragraph before the block mentions an example.
+    introduced: bool,
+}
+
+/// Every `<pre>` at the top level of a part, along with how it was introduced.
+fn code_blocks(nodes: &[Node]) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    let mut previous: Option<String> = None;
+
+    for node in nodes {
+        let Node::Element(element) = node else {
+            continue;
+        };
+        if element.tag == "pre" {
+            blocks.push(CodeBlock {
+                text: node.text(),
+                introduced: previous
+                    .as_ref()
+                    .is_some_and(|text| text.to_lowercase().contains("example")),
+            });
+        }
+        previous = (element.tag == "p").then(|| node.text());
+    }
+
+    blocks
+}
+
+/// The text of the last emphasised code in `nodes`, either `<code><em>` or `<em><code>`.
+fn last_emphasised_code(nodes: &[Node]) -> Option<String> {
+    let mut last = None;
+    for node in nodes {
+        let Node::Element(element) = node else {
+            continue;
+        };
+        let inner = match element.tag.as_str() {
+            "code" => "em",
+            "em" => "code",
+            _ => "",
+        };
+        let found = if !inner.is_empty() && contains(&element.children, inner) {
+            Some(node.text().trim().to_owned()).filter(|text| !text.is_empty())
+        } else {
+            last_emphasised_code(&element.children)
+        };
+        last = found.or(last);
+    }
+    last
+}
+
+fn contains(nodes: &[Node], tag: &str) -> bool {
+    nodes.iter().any(|node| match node {
+        Node::Element(element) => element.tag == tag || contains(&element.children, tag),
+        Node::Text(_) => false,
+    })
+}
+
+#[cfg(test)]
+mod examples_tests {
+    use super::*;
+
+    mod inputs {
+        use super::*;
+
+        struct Case<'c> {
+            parts: &'c [&'c str],
+            expected: Vec<&'c str>,
+        }
+
+        #[test]
+        fn after_for_example() {
+            run(&Case {
+                parts: &["<p>For example:</p>\n<pre><code>3   4\n4   3\n</code></pre>"],
+                expected: vec!["3   4\n4   3\n"],
+            });
+        }
+
+        #[test]
+        fn skips_illustrations() {
+            run(&Case {
+                parts: &[concat!(
+                    "<p>For example, consider this map:</p><pre><code>#..\n.#.\n</code></pre>",
+                    "<p>After one step, it looks like this:</p><pre><code>.#.\n..#\n</code></pre>",
+                    "<p>Here is a larger example:</p><pre><code>##\n##\n</code></pre>",
+                )],
+                expected: vec!["#..\n.#.\n", "##\n##\n"],
+            });
+        }
+
+        #[test]
+        fn first_block_without_introduction() {
+            run(&Case {
+                parts: &[concat!(
+                    "<p>Consider this list:</p><pre><code>1\n2\n</code></pre>",
+                    "<p>Then this one:</p><pre><code>3\n</code></pre>",
+                )],
+                expected: vec!["1\n2\n"],
+            });
+        }
+
+        #[test]
+        fn without_markup() {
+            run(&Case {
+                parts: &[
+                    "<p>For example:</p><pre><code>a &lt;- <em>b</em> &amp;&amp; c\n</code></pre>",
+                ],
+                expected: vec!["a <- b && c\n"],
+            });
+        }
+
+        #[test]
+        fn once_across_parts() {
+            run(&Case {
+                parts: &[
+                    "<p>For example:</p><pre><code>1 2\n</code></pre>",
+                    concat!(
+                        "<p>Using the same example:</p><pre><code>1 2\n</code></pre>",
+                        "<p>Another example:</p><pre><code>3 4\n</code></pre>",
+                    ),
+                ],
+                expected: vec!["1 2\n", "3 4\n"],
+            });
+        }
+
+        #[test]
+        fn no_blocks() {
+            run(&Case {
+                parts: &["<p>What is the answer?</p>"],
+                expected: vec![],
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(test.expected, Examples::from_parts(test.parts).inputs);
+        }
+    }
+
+    mod parts {
+        use super::*;
+
+        struct Case<'c> {
+            parts: &'c [&'c str],
+            expected: Vec<PartExample>,
+        }
+
+        #[test]
+        fn last_emphasised_code() {
+            run(&Case {
+                parts: &[concat!(
+                    "<p>For example:</p><pre><code>3   4\n</code></pre>",
+                    "<p>The first pair is <code><em>1</em></code> apart, ",
+                    "for a total of <code><em>11</em></code>!</p>",
+                    "<p><em>What is the total distance?</em></p>",
+                )],
+                expected: vec![PartExample {
+                    number: 1,
+                    answer: Some(String::from("11")),
+                }],
+            });
+        }
+
+        #[test]
+        fn emphasis_around_code() {
+            run(&Case {
+                parts: &["<p>For example:</p><pre><code>x\n</code></pre><p>It reads <em><code>XMAS</code></em>.</p>"],
+                expected: vec![PartExample {
+                    number: 1,
+                    answer: Some(String::from("XMAS")),
+                }],
+            });
+        }
+
+        #[test]
+        fn nested_in_lists() {
+            run(&Case {
+                parts: &["<p>For example:</p><pre><code>x\n</code></pre><ul><li>Total: <code><em>42</em></code></li></ul>"],
+                expected: vec![PartExample {
+                    number: 1,
+                    answer: Some(String::from("42")),
+                }],
+            });
+        }
+
+        #[test]
+        fn part_2_with_new_example() {
+            run(&Case {
+                parts: &[
+                    "<p>For example:</p><pre><code>a\n</code></pre><p>Total <code><em>161</em></code>.</p>",
+                    "<p>For example:</p><pre><code>b\n</code></pre><p>Total <code><em>48</em></code>.</p>",
+                ],
+                expected: vec![
+                    PartExample {
+                        number: 1,
+                        answer: Some(String::from("161")),
+                    },
+                    PartExample {
+                        number: 2,
+                        answer: Some(String::from("48")),
+                    },
+                ],
+            });
+        }
+
+        #[test]
+        fn part_2_reusing_example() {
+            run(&Case {
+                parts: &[
+                    "<p>For example:</p><pre><code>a\n</code></pre><p>Total <code><em>11</em></code>.</p>",
+                    "<p>In the example above, the score is <code><em>31</em></code>.</p>",
+                ],
+                expected: vec![
+                    PartExample {
+                        number: 1,
+                        answer: Some(String::from("11")),
+                    },
+                    PartExample {
+                        number: 1,
+                        answer: Some(String::from("31")),
+                    },
+                ],
+            });
+        }
+
+        #[test]
+        fn no_answer() {
+            run(&Case {
+                parts: &[
+                    "<p>For example:</p><pre><code>a\n</code></pre><p><em>What is it?</em></p>",
+                ],
+                expected: vec![PartExample {
+                    number: 1,
+                    answer: None,
+                }],
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(test.expected, Examples::from_parts(test.parts).parts);
+        }
+    }
+
+    mod answers {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let examples = Examples {
+                inputs: vec![String::from("a\n"), String::from("b\n")],
+                parts: vec![
+                    PartExample {
+                        number: 1,
+                        answer: None,
+                    },
+                    PartExample {
+                        number: 2,
+                        answer: Some(String::from("48")),
+                    },
+                ],
+            };
+
+            let answers = examples.answers(2024, 3);
+
+            assert_eq!("2024 3 2 example_2 48\n", answers);
+            assert_eq!(
+                examples,
+                Examples::from_files(examples.inputs.clone(), &answers)
+            );
+        }
+    }
+
+    mod expected {
+        use super::*;
+
+        struct Case<'c> {
+            answer: Option<&'c str>,
+            expected: &'c str,
+        }
+
+        #[test]
+        fn small_number() {
+            run(&Case {
+                answer: Some("1234"),
+                expected: "1234",
+            });
+        }
+
+        #[test]
+        fn grouped_digits() {
+            run(&Case {
+                answer: Some("12345678"),
+                expected: "12_345_678",
+            });
+        }
+
+        #[test]
+        fn text() {
+            run(&Case {
+                answer: Some("4,6,3,5"),
+                expected: "\"4,6,3,5\"",
+            });
+        }
+
+        #[test]
+        fn missing() {
+            run(&Case {
+                answer: None,
+                expected: "todo!()",
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            let examples = Examples {
+                inputs: vec![],
+                parts: vec![PartExample {
+                    number: 1,
+                    answer: test.answer.map(str::to_owned),
+                }],
+            };
+            assert_eq!(test.expected, examples.expected(1));
+        }
+    }
+
+    mod fill_part_2 {
+        use super::*;
+
+        const LIB: &str = r#"    type Part2 = usize;
+
+    fn part_2(&self) -> usize {
+        0
+    }
+
+    mod part_2 {
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: example_1().1,
+                expected: todo!(),
+            });
+        }
+    }
+
+    fn example_1() -> (&'static str, Input<'static>) {
+        (
+            "a",
+            Input {},
+        )
+    }
+
+    fn actual(name: &str) {}
+"#;
+
+        struct Case<'c> {
+            lib: &'c str,
+            part_2: PartExample,
+            expected: Option<&'c str>,
+        }
+
+        #[test]
+        fn same_example() {
+            run(&Case {
+                lib: LIB,
+                part_2: PartExample {
+                    number: 1,
+                    answer: Some(String::from("31")),
+                },
+                expected: Some(&LIB.replace("todo!()", "31")),
+            });
+        }
+
+        #[test]
+        fn new_example() {
+            run(&Case {
+                lib: LIB,
+                part_2: PartExample {
+                    number: 2,
+                    answer: Some(String::from("123456")),
+                },
+                expected: Some(
+                    &LIB.replace("todo!()", "123_456")
+                        .replace("example_1().1", "example_2().1")
+                        .replace(
+                            "\n    fn actual",
+                            "\n    fn example_2() -> (&'static str, Input<'static>) {\n        (\n            \"b\",\n            todo!(),\n        )\n    }\n\n    fn actual",
+                        ),
+                ),
+            });
+        }
+
+        #[test]
+        fn already_written() {
+            run(&Case {
+                lib: &LIB.replace("todo!()", "31"),
+                part_2: PartExample {
+                    number: 1,
+                    answer: Some(String::from("31")),
+                },
+                expected: None,
+            });
+        }
+
+        #[test]
+        fn text_answer() {
+            run(&Case {
+                lib: LIB,
+                part_2: PartExample {
+                    number: 1,
+                    answer: Some(String::from("abc")),
+                },
+                expected: Some(
+                    &LIB.replace("todo!()", "\"abc\"")
+                        .replace("usize", "String")
+                        .replace("expected: String", "expected: &'static str"),
+                ),
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            let examples = Examples {
+                inputs: vec![String::from("a\n"), String::from("b\n")],
+                parts: vec![
+                    PartExample {
+                        number: 1,
+                        answer: None,
+                    },
+                    test.part_2.clone(),
+                ],
+            };
+            assert_eq!(
+                test.expected.map(str::to_owned),
+                fill_part_2(test.lib, &examples)
+            );
+        }
+    }
+
+    mod rust_literal {
+        use super::*;
+
+        struct Case<'c> {
+            text: &'c str,
+            expected: &'c str,
+        }
+
+        #[test]
+        fn multiple_lines() {
+            run(&Case {
+                text: "3   4\n4   3\n",
+                expected: "\"3   4\n4   3\"",
+            });
+        }
+
+        #[test]
+        fn escapes() {
+            run(&Case {
+                text: "say \"hi\" \\o/\n",
+                expected: r#""say \"hi\" \\o/""#,
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(test.expected, rust_literal(test.text));
+        }
+    }
+}
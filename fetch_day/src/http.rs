@@ -0,0 +1,685 @@
+#[cfg(test)]
+use core::cell::RefCell;
+use core::time::Duration;
+#[cfg(test)]
+use std::{collections::BTreeMap, path::Path};
+use std::{
+    fs,
+    path::PathBuf,
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::{
+    blocking::{RequestBuilder, Response},
+    header::{self, HeaderMap},
+    StatusCode,
+};
+
+use crate::leaderboard;
+
+/// The site that puzzles are fetched from and answers are submitted to.
+pub(crate) const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies `fetch_day` to the site when no `--user-agent` is given, followed by `AOC_CONTACT`
+/// if it is set.
+pub(crate) const DEFAULT_USER_AGENT: &str = "fetch_day (+https://github.com/maneac/aoc2024)";
+
+/// Where fetched pages are cached, relative to the state directory.
+const CACHE_DIR: &str = "cache";
+
+/// When the last request was sent, relative to the state directory.
+const LAST_REQUEST_FILE: &str = "last_request.txt";
+
+/// The User-Agent to send when none was given, naming whoever runs `fetch_day` if `contact` is
+/// set.
+pub(crate) fn default_user_agent(contact: Option<&str>) -> String {
+    contact
+        .map(str::trim)
+        .filter(|contact| !contact.is_empty())
+        .map_or_else(
+            || DEFAULT_USER_AGENT.to_owned(),
+            |contact| format!("{DEFAULT_USER_AGENT} {contact}"),
+        )
+}
+
+/// Keeps scripted use of the site within its automation guidelines.
+#[derive(Debug, Clone)]
+pub(crate) struct Politeness {
+    /// Sent with every request, so the site knows who to contact about the traffic.
+    pub(crate) user_agent: String,
+    /// The shortest time allowed between two requests, even across runs.
+    pub(crate) min_interval: Duration,
+    /// Holds the page cache and the time of the last request.
+    pub(crate) state_dir: PathBuf,
+}
+
+/// Every request `fetch_day` makes to the site goes through this, so that it can be swapped for
+/// [`Fixtures`] in tests.
+pub(crate) trait Http {
+    /// The body of the page at `url`.
+    fn get(&self, url: &str) -> anyhow::Result<String>;
+
+    /// The body of the page returned after posting `form` to `url`.
+    fn post_form(&self, url: &str, form: &[(&str, &str)]) -> anyhow::Result<String>;
+}
+
+impl<T: Http> Http for &T {
+    fn get(&self, url: &str) -> anyhow::Result<String> {
+        (**self).get(url)
+    }
+
+    fn post_form(&self, url: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        (**self).post_form(url, form)
+    }
+}
+
+/// Talks to the site with the session cookie of a logged in user. Pages are cached and
+/// revalidated, and requests are spaced out by [`Politeness::min_interval`].
+pub(crate) struct Client {
+    inner: reqwest::blocking::Client,
+    aoc_token: String,
+    cache: Cache,
+    throttle: Throttle,
+}
+
+impl Client {
+    pub(crate) fn new(aoc_token: String, politeness: &Politeness) -> anyhow::Result<Self> {
+        Ok(Self {
+            inner: reqwest::blocking::Client::builder()
+                .user_agent(&politeness.user_agent)
+                .build()?,
+            aoc_token,
+            cache: Cache {
+                dir: politeness.state_dir.join(CACHE_DIR),
+            },
+            throttle: Throttle {
+                path: politeness.state_dir.join(LAST_REQUEST_FILE),
+                min_interval: politeness.min_interval,
+            },
+        })
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.aoc_token)
+    }
+}
+
+impl Http for Client {
+    fn get(&self, url: &str) -> anyhow::Result<String> {
+        let cached = self.cache.load(url);
+        if let Some(page) = cached
+            .as_ref()
+            .filter(|_| self.cache.age(url).is_some_and(|age| age < max_age(url)))
+        {
+            return Ok(page.body.clone());
+        }
+
+        let mut request = self.inner.get(url).header(header::COOKIE, self.cookie());
+        if let Some(page) = &cached {
+            if let Some(etag) = &page.etag {
+                request = request.header(header::IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &page.last_modified {
+                request = request.header(header::IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = self.throttle.send(request)?;
+        if let (StatusCode::NOT_MODIFIED, Some(page)) = (response.status(), &cached) {
+            // Storing it again restarts its age.
+            self.cache.store(url, page)?;
+            return Ok(page.body.clone());
+        }
+        let response = response.error_for_status()?;
+        let headers = response.headers().clone();
+        let page = Page {
+            etag: header_value(&headers, header::ETAG),
+            last_modified: header_value(&headers, header::LAST_MODIFIED),
+            body: response.text()?,
+        };
+        self.cache.store(url, &page)?;
+        Ok(page.body)
+    }
+
+    fn post_form(&self, url: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        let request = self
+            .inner
+            .post(url)
+            .header(header::COOKIE, self.cookie())
+            .form(form);
+        Ok(self.throttle.send(request)?.error_for_status()?.text()?)
+    }
+}
+
+/// How long a cached copy of `url` is served without asking the site again. Puzzle inputs never
+/// change once published, and private leaderboards must not be fetched more often than
+/// [`leaderboard::MAX_AGE`]. Everything else is revalidated every time.
+fn max_age(url: &str) -> Duration {
+    if url.ends_with("/input") {
+        Duration::MAX
+    } else if leaderboard::is_leaderboard(url) {
+        leaderboard::MAX_AGE
+    } else {
+        Duration::ZERO
+    }
+}
+
+fn header_value(headers: &HeaderMap, name: header::HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned)
+}
+
+/// A fetched page, along with the validators the site sent for it.
+#[derive(Debug, PartialEq, Eq)]
+struct Page {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: String,
+}
+
+/// Fetched pages on disk, one file per URL. Each file starts with the URL and the page's
+/// validators as `<name>: <value>` lines, followed by a blank line and the body.
+#[derive(Debug)]
+struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    /// The cached copy of `url`. Unreadable entries are treated as missing, and are replaced
+    /// when the page is fetched again.
+    fn load(&self, url: &str) -> Option<Page> {
+        let contents = fs::read_to_string(self.path(url)).ok()?;
+        let (head, body) = contents.split_once("\n\n")?;
+
+        let mut page = Page {
+            etag: None,
+            last_modified: None,
+            body: body.to_owned(),
+        };
+        let mut cached_url = None;
+        for line in head.lines() {
+            let (name, value) = line.split_once(": ")?;
+            match name {
+                "url" => cached_url = Some(value),
+                "etag" => page.etag = Some(value.to_owned()),
+                "last-modified" => page.last_modified = Some(value.to_owned()),
+                _ => return None,
+            }
+        }
+        (cached_url == Some(url)).then_some(page)
+    }
+
+    /// How long ago `url` was stored, if it is in the cache at all.
+    fn age(&self, url: &str) -> Option<Duration> {
+        let modified = fs::metadata(self.path(url)).ok()?.modified().ok()?;
+        // An entry from the future means the clock moved back, so treat it as just stored.
+        Some(
+            SystemTime::now()
+                .duration_since(modified)
+                .unwrap_or_default(),
+        )
+    }
+
+    fn store(&self, url: &str, page: &Page) -> anyhow::Result<()> {
+        let validators = [("etag", &page.etag), ("last-modified", &page.last_modified)];
+        let head = core::iter::once(format!("url: {url}\n"))
+            .chain(validators.iter().filter_map(|(name, value)| {
+                value.as_ref().map(|value| format!("{name}: {value}\n"))
+            }))
+            .collect::<String>();
+
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(url), format!("{head}\n{}", page.body))?;
+        Ok(())
+    }
+
+    /// Names the entry after the URL with everything but letters and digits replaced, such as
+    /// `https___adventofcode_com_2024_day_1`. Clashing URLs are told apart by the URL stored in
+    /// the entry.
+    fn path(&self, url: &str) -> PathBuf {
+        let name = url
+            .chars()
+            .map(|char| {
+                if char.is_ascii_alphanumeric() {
+                    char
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        self.dir.join(name)
+    }
+}
+
+/// Spaces requests out by at least `min_interval`, remembering the last request in a file so
+/// that back to back runs are spaced out too.
+#[derive(Debug)]
+struct Throttle {
+    path: PathBuf,
+    min_interval: Duration,
+}
+
+impl Throttle {
+    fn send(&self, request: RequestBuilder) -> anyhow::Result<Response> {
+        thread::sleep(self.wait(SystemTime::now()));
+        let response = request.send();
+        self.record(SystemTime::now())?;
+        Ok(response?)
+    }
+
+    /// How long to wait at `now` before the next request may be sent.
+    fn wait(&self, now: SystemTime) -> Duration {
+        let Some(last_request) = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis))
+        else {
+            return Duration::ZERO;
+        };
+        // A last request in the future means the clock moved back, so wait the full interval.
+        self.min_interval
+            .saturating_sub(now.duration_since(last_request).unwrap_or_default())
+    }
+
+    fn record(&self, now: SystemTime) -> anyhow::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let millis = now.duration_since(UNIX_EPOCH)?.as_millis();
+        fs::write(&self.path, format!("{millis}\n"))?;
+        Ok(())
+    }
+}
+
+/// Serves canned responses keyed by URL, and remembers every request it was sent.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct Fixtures {
+    responses: BTreeMap<String, String>,
+    requests: RefCell<Vec<String>>,
+}
+
+#[cfg(test)]
+impl Fixtures {
+    /// Responds to `url` with the contents of `file`.
+    pub(crate) fn with_file(mut self, url: &str, file: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let _prev = self
+            .responses
+            .insert(url.to_owned(), fs::read_to_string(file)?);
+        Ok(self)
+    }
+
+    /// Responds to `url` with `body`.
+    pub(crate) fn with_body(mut self, url: &str, body: &str) -> Self {
+        let _prev = self.responses.insert(url.to_owned(), body.to_owned());
+        self
+    }
+
+    /// Every request made so far, as `GET <url>` or `POST <url> <form>`.
+    pub(crate) fn requests(&self) -> Vec<String> {
+        self.requests.borrow().clone()
+    }
+
+    fn respond(&self, url: &str, request: String) -> anyhow::Result<String> {
+        self.requests.borrow_mut().push(request);
+        self.responses
+            .get(url)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No fixture for '{url}'"))
+    }
+}
+
+#[cfg(test)]
+impl Http for Fixtures {
+    fn get(&self, url: &str) -> anyhow::Result<String> {
+        self.respond(url, format!("GET {url}"))
+    }
+
+    fn post_form(&self, url: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        let form = form
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join("&");
+        self.respond(url, format!("POST {url} {form}"))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod http_tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        time::Instant,
+    };
+
+    /// Answers one connection with each of `responses` in turn, then hands back every request
+    /// it received.
+    pub(crate) fn stub_server(responses: Vec<String>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|response| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let request = read_request(&mut BufReader::new(stream.try_clone().unwrap()));
+                    stream.write_all(response.as_bytes()).unwrap();
+                    request
+                })
+                .collect()
+        });
+
+        (url, handle)
+    }
+
+    /// A response with `status`, the extra `headers` lines and `body`.
+    pub(crate) fn response(status: &str, headers: &[&str], body: &str) -> String {
+        let headers = headers
+            .iter()
+            .flat_map(|line| [*line, "\r\n"])
+            .collect::<String>();
+        format!(
+            "HTTP/1.1 {status}\r\nContent-Type: text/html\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    fn read_request(reader: &mut impl BufRead) -> String {
+        let mut request = String::new();
+        let mut content_length = 0_usize;
+        loop {
+            let mut line = String::new();
+            _ = reader.read_line(&mut line).unwrap();
+            if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                content_length = length.trim().parse().unwrap();
+            }
+            request.push_str(&line);
+            if line == "\r\n" {
+                break;
+            }
+        }
+        let mut content = vec![0; content_length];
+        reader.read_exact(&mut content).unwrap();
+        request.push_str(&String::from_utf8(content).unwrap());
+        request
+    }
+
+    pub(crate) fn politeness(name: &str) -> Politeness {
+        let state_dir = std::env::temp_dir().join("fetch_day_http_tests").join(name);
+        if state_dir.exists() {
+            fs::remove_dir_all(&state_dir).unwrap();
+        }
+        Politeness {
+            user_agent: String::from("fetch_day tests (tests@example.com)"),
+            min_interval: Duration::ZERO,
+            state_dir,
+        }
+    }
+
+    mod get {
+        use super::*;
+
+        #[test]
+        fn revalidates_cached_page() {
+            let (url, server) = stub_server(vec![
+                response("200 OK", &["ETag: \"v1\""], "part 1"),
+                response("304 Not Modified", &[], ""),
+            ]);
+            let client = Client::new(String::from("secret"), &politeness("revalidates")).unwrap();
+
+            assert_eq!("part 1", client.get(&format!("{url}/2024/day/1")).unwrap());
+            assert_eq!("part 1", client.get(&format!("{url}/2024/day/1")).unwrap());
+
+            let [first, second] = <[String; 2]>::try_from(server.join().unwrap()).unwrap();
+            for request in [&first, &second] {
+                assert!(
+                    request.contains("user-agent: fetch_day tests (tests@example.com)\r\n"),
+                    "Missing User-Agent: {request}"
+                );
+            }
+            assert!(
+                !first.contains("if-none-match"),
+                "Unexpected revalidation: {first}"
+            );
+            assert!(
+                second.contains("if-none-match: \"v1\"\r\n"),
+                "Missing revalidation: {second}"
+            );
+        }
+
+        #[test]
+        fn replaces_changed_page() {
+            let (url, server) = stub_server(vec![
+                response(
+                    "200 OK",
+                    &["Last-Modified: Sun, 01 Dec 2024 05:00:00 GMT"],
+                    "part 1",
+                ),
+                response("200 OK", &[], "part 1 and part 2"),
+                response("200 OK", &[], "part 1 and part 2"),
+            ]);
+            let client = Client::new(String::from("secret"), &politeness("replaces")).unwrap();
+            let page_url = format!("{url}/2024/day/1");
+
+            assert_eq!("part 1", client.get(&page_url).unwrap());
+            assert_eq!("part 1 and part 2", client.get(&page_url).unwrap());
+            assert_eq!("part 1 and part 2", client.get(&page_url).unwrap());
+
+            let [_, second, third] = <[String; 3]>::try_from(server.join().unwrap()).unwrap();
+            assert!(
+                second.contains("if-modified-since: Sun, 01 Dec 2024 05:00:00 GMT\r\n"),
+                "Missing revalidation: {second}"
+            );
+            assert!(
+                !third.contains("if-modified-since"),
+                "Revalidated without validators: {third}"
+            );
+        }
+
+        #[test]
+        fn serves_inputs_from_cache() {
+            let (url, server) = stub_server(vec![response("200 OK", &[], "1 2\n3 4\n")]);
+            let client = Client::new(String::from("secret"), &politeness("inputs")).unwrap();
+
+            assert_eq!(
+                "1 2\n3 4\n",
+                client.get(&format!("{url}/2024/day/1/input")).unwrap()
+            );
+            assert_eq!(
+                "1 2\n3 4\n",
+                client.get(&format!("{url}/2024/day/1/input")).unwrap()
+            );
+
+            assert_eq!(1, server.join().unwrap().len());
+        }
+
+        #[test]
+        fn serves_leaderboard_from_cache_for_15_minutes() {
+            let (url, server) = stub_server(vec![
+                response("200 OK", &[], "{\"members\":{}}"),
+                response("200 OK", &[], "{\"members\":{\"1\":{}}}"),
+            ]);
+            let politeness = politeness("leaderboard");
+            let client = Client::new(String::from("secret"), &politeness).unwrap();
+            let leaderboard_url = format!("{url}/2024/leaderboard/private/view/1.json");
+
+            assert_eq!("{\"members\":{}}", client.get(&leaderboard_url).unwrap());
+            assert_eq!("{\"members\":{}}", client.get(&leaderboard_url).unwrap());
+
+            let entry = client.cache.path(&leaderboard_url);
+            fs::File::options()
+                .write(true)
+                .open(entry)
+                .unwrap()
+                .set_modified(SystemTime::now() - leaderboard::MAX_AGE)
+                .unwrap();
+            assert_eq!(
+                "{\"members\":{\"1\":{}}}",
+                client.get(&leaderboard_url).unwrap()
+            );
+
+            assert_eq!(2, server.join().unwrap().len());
+        }
+
+        #[test]
+        fn spaces_out_requests() {
+            let (url, server) = stub_server(vec![
+                response("200 OK", &[], "first"),
+                response("200 OK", &[], "second"),
+            ]);
+            let politeness = Politeness {
+                min_interval: Duration::from_millis(200),
+                ..politeness("spaces_out")
+            };
+            let start = Instant::now();
+
+            // Separate clients, as separate runs would have.
+            for body in ["first", "second"] {
+                let client = Client::new(String::from("secret"), &politeness).unwrap();
+                assert_eq!(body, client.post_form(&url, &[]).unwrap());
+            }
+
+            assert_eq!(2, server.join().unwrap().len());
+            assert!(start.elapsed() >= Duration::from_millis(200));
+        }
+    }
+
+    mod cache {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let cache = Cache {
+                dir: politeness("cache_round_trip").state_dir,
+            };
+            let page = Page {
+                etag: Some(String::from("\"v1\"")),
+                last_modified: None,
+                body: String::from("<p>First line\n\nafter a blank line</p>"),
+            };
+
+            cache.store("https://aoc.test/2024/day/1", &page).unwrap();
+
+            assert_eq!(Some(page), cache.load("https://aoc.test/2024/day/1"));
+        }
+
+        #[test]
+        fn keyed_by_url() {
+            let cache = Cache {
+                dir: politeness("cache_keyed_by_url").state_dir,
+            };
+            let page = Page {
+                etag: None,
+                last_modified: None,
+                body: String::from("day 1"),
+            };
+
+            cache.store("https://aoc.test/2024/day/1", &page).unwrap();
+
+            assert_eq!(None, cache.load("https://aoc.test/2024/day_1"));
+            assert_eq!(None, cache.load("https://aoc.test/2024/day/2"));
+        }
+    }
+
+    mod wait {
+        use super::*;
+
+        struct Case {
+            name: &'static str,
+            last_request: Option<&'static str>,
+            now: u64,
+            expected: Duration,
+        }
+
+        #[test]
+        fn first_request() {
+            run(&Case {
+                name: "first_request",
+                last_request: None,
+                now: 10_000,
+                expected: Duration::ZERO,
+            });
+        }
+
+        #[test]
+        fn recent_request() {
+            run(&Case {
+                name: "recent_request",
+                last_request: Some("9000\n"),
+                now: 10_000,
+                expected: Duration::from_secs(4),
+            });
+        }
+
+        #[test]
+        fn old_request() {
+            run(&Case {
+                name: "old_request",
+                last_request: Some("1000\n"),
+                now: 10_000,
+                expected: Duration::ZERO,
+            });
+        }
+
+        #[test]
+        fn request_in_the_future() {
+            run(&Case {
+                name: "request_in_the_future",
+                last_request: Some("20000\n"),
+                now: 10_000,
+                expected: Duration::from_secs(5),
+            });
+        }
+
+        #[test]
+        fn unreadable_record() {
+            run(&Case {
+                name: "unreadable_record",
+                last_request: Some("yesterday\n"),
+                now: 10_000,
+                expected: Duration::ZERO,
+            });
+        }
+
+        fn run(test: &Case) {
+            let state_dir = politeness(&format!("wait_{}", test.name)).state_dir;
+            let throttle = Throttle {
+                path: state_dir.join(LAST_REQUEST_FILE),
+                min_interval: Duration::from_secs(5),
+            };
+            if let Some(last_request) = test.last_request {
+                fs::create_dir_all(&state_dir).unwrap();
+                fs::write(&throttle.path, last_request).unwrap();
+            }
+
+            assert_eq!(
+                test.expected,
+                throttle.wait(UNIX_EPOCH + Duration::from_millis(test.now))
+            );
+        }
+    }
+
+    mod default_user_agent {
+        use super::*;
+
+        #[test]
+        fn without_contact() {
+            assert_eq!(DEFAULT_USER_AGENT, default_user_agent(None));
+            assert_eq!(DEFAULT_USER_AGENT, default_user_agent(Some("  ")));
+        }
+
+        #[test]
+        fn with_contact() {
+            assert_eq!(
+                "fetch_day (+https://github.com/maneac/aoc2024) someone@example.com",
+                default_user_agent(Some("someone@example.com"))
+            );
+        }
+    }
+}
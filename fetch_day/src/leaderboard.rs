@@ -0,0 +1,358 @@
+use core::{fmt::Write as _, time::Duration};
+use std::collections::BTreeMap;
+
+use chrono::{FixedOffset, TimeZone};
+use serde_json::Value;
+
+/// The site asks for private leaderboards to be fetched no more than once every 15 minutes.
+pub(crate) const MAX_AGE: Duration = Duration::from_mins(15);
+
+/// The JSON of the private leaderboard `id` for `year`.
+pub(crate) fn url(base_url: &str, year: u32, id: &str) -> String {
+    format!(
+        "{}/{year}/leaderboard/private/view/{id}.json",
+        base_url.trim_end_matches('/')
+    )
+}
+
+/// Whether `url` is the JSON of a private leaderboard.
+pub(crate) fn is_leaderboard(url: &str) -> bool {
+    url.contains("/leaderboard/private/view/") && url.to_ascii_lowercase().ends_with(".json")
+}
+
+/// A private leaderboard, with its members in order of local score.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Leaderboard {
+    year: i32,
+    members: Vec<Member>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Member {
+    id: u64,
+    name: Option<String>,
+    local_score: u64,
+    stars: u64,
+    /// When each star was earned, as seconds since the Unix epoch, keyed by day and part.
+    stars_at: BTreeMap<(u32, u8), i64>,
+}
+
+impl Member {
+    /// Members without a public name are shown the way the site shows them.
+    fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// `*` for a day with both stars, `+` for a day with only the first, and `.` for neither.
+    fn days(&self, last_day: u32) -> String {
+        (1..=last_day)
+            .map(|day| {
+                match (
+                    self.stars_at.contains_key(&(day, 1)),
+                    self.stars_at.contains_key(&(day, 2)),
+                ) {
+                    (true, true) => '*',
+                    (true, false) => '+',
+                    (false, _) => '.',
+                }
+            })
+            .collect()
+    }
+}
+
+impl Leaderboard {
+    /// Reads the leaderboard JSON the site serves. Anything else, such as the login page served
+    /// to an expired session, is reported as an error.
+    pub(crate) fn parse(json: &str) -> anyhow::Result<Self> {
+        let value = serde_json::from_str::<Value>(json).map_err(|err| {
+            anyhow::anyhow!(
+                "Invalid leaderboard JSON ({err}); check that the session token is valid and a member of the leaderboard"
+            )
+        })?;
+
+        let Some(year) = value
+            .get("event")
+            .and_then(Value::as_str)
+            .and_then(|event| event.parse().ok())
+        else {
+            anyhow::bail!("Leaderboard has no 'event' year");
+        };
+        let Some(members) = value.get("members").and_then(Value::as_object) else {
+            anyhow::bail!("Leaderboard has no 'members'");
+        };
+
+        let mut members = members
+            .iter()
+            .map(|(id, member)| parse_member(id, member))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        members.sort_by(|left, right| {
+            right
+                .local_score
+                .cmp(&left.local_score)
+                .then(right.stars.cmp(&left.stars))
+                .then(left.id.cmp(&right.id))
+        });
+
+        Ok(Self { year, members })
+    }
+
+    /// The latest day any member has a star for.
+    pub(crate) fn last_day(&self) -> Option<u32> {
+        self.members
+            .iter()
+            .filter_map(|member| member.stars_at.keys().map(|&(day, _)| day).max())
+            .max()
+    }
+
+    /// Every member's rank, local score and stars, along with the stars they have for each day.
+    pub(crate) fn table(&self) -> String {
+        let width = self.name_width();
+        let last_day = self.last_day().unwrap_or_default();
+
+        let mut table = format!(
+            "{:<4}  {:<width$}  {:>5}  {:>5}  Days\n",
+            "Rank", "Name", "Score", "Stars"
+        );
+        for (rank, member) in (1_usize..).zip(&self.members) {
+            _ = writeln!(
+                table,
+                "{rank:<4}  {:<width$}  {:>5}  {:>5}  {}",
+                member.display_name(),
+                member.local_score,
+                member.stars,
+                member.days(last_day)
+            );
+        }
+        table
+    }
+
+    /// How long after `day` unlocked each member earned each of its stars.
+    pub(crate) fn times(&self, day: u32) -> anyhow::Result<String> {
+        let Some(unlock) = unlock(self.year, day) else {
+            anyhow::bail!("Day {day} of {} is not a puzzle day", self.year);
+        };
+        let width = self.name_width();
+
+        let mut table = format!(
+            "Day {day:02}, since unlock\n{:<width$}  {:>10}  {:>10}\n",
+            "Name", "Part 1", "Part 2"
+        );
+        for member in &self.members {
+            let [part_1, part_2] = [1, 2].map(|part| {
+                member
+                    .stars_at
+                    .get(&(day, part))
+                    .map_or_else(|| String::from("-"), |&at| format_since(at - unlock))
+            });
+            _ = writeln!(
+                table,
+                "{:<width$}  {part_1:>10}  {part_2:>10}",
+                member.display_name()
+            );
+        }
+        Ok(table)
+    }
+
+    fn name_width(&self) -> usize {
+        self.members
+            .iter()
+            .map(|member| member.display_name().chars().count())
+            .max()
+            .unwrap_or_default()
+            .max("Name".len())
+    }
+}
+
+fn parse_member(id: &str, member: &Value) -> anyhow::Result<Member> {
+    let number = |field: &str| {
+        member
+            .get(field)
+            .and_then(Value::as_u64)
+            .ok_or_else(|| anyhow::anyhow!("Member {id} has no '{field}'"))
+    };
+
+    let mut stars_at = BTreeMap::new();
+    if let Some(days) = member
+        .get("completion_day_level")
+        .and_then(Value::as_object)
+    {
+        for (day, parts) in days {
+            for (part, star) in parts.as_object().into_iter().flatten() {
+                let (Ok(day), Ok(part), Some(at)) = (
+                    day.parse(),
+                    part.parse(),
+                    star.get("get_star_ts").and_then(Value::as_i64),
+                ) else {
+                    anyhow::bail!("Member {id} has an invalid star for day {day} part {part}");
+                };
+                let _prev = stars_at.insert((day, part), at);
+            }
+        }
+    }
+
+    Ok(Member {
+        id: id
+            .parse()
+            .map_err(|err| anyhow::anyhow!("Invalid member ID '{id}': {err}"))?,
+        name: member
+            .get("name")
+            .and_then(Value::as_str)
+            .map(str::to_owned),
+        local_score: number("local_score")?,
+        stars: number("stars")?,
+        stars_at,
+    })
+}
+
+/// When `day` of `year` unlocked, as seconds since the Unix epoch: midnight in EST.
+fn unlock(year: i32, day: u32) -> Option<i64> {
+    if !(1..=25).contains(&day) {
+        return None;
+    }
+    FixedOffset::west_opt(5 * 3600)?
+        .with_ymd_and_hms(year, 12, day, 0, 0, 0)
+        .single()
+        .map(|unlock| unlock.timestamp())
+}
+
+/// `seconds` as `H:MM:SS`, with as many hours as it takes.
+fn format_since(seconds: i64) -> String {
+    let (hours, rest) = (seconds.div_euclid(3600), seconds.rem_euclid(3600));
+    format!(
+        "{hours}:{:02}:{:02}",
+        rest.div_euclid(60),
+        rest.rem_euclid(60)
+    )
+}
+
+#[cfg(test)]
+mod leaderboard_tests {
+    use super::*;
+
+    fn fixture() -> Leaderboard {
+        Leaderboard::parse(include_str!("../fixtures/leaderboard.json")).unwrap()
+    }
+
+    #[test]
+    fn table() {
+        assert_eq!(
+            "Rank  Name                    Score  Stars  Days\n\
+             1     Bob                        11      4  **\n\
+             2     Alice                       7      3  *+\n\
+             3     (anonymous user #1002)      1      1  +.\n",
+            fixture().table()
+        );
+    }
+
+    mod times {
+        use super::*;
+
+        struct Case {
+            day: u32,
+            expected: Result<&'static str, &'static str>,
+        }
+
+        #[test]
+        fn both_stars() {
+            run(&Case {
+                day: 1,
+                expected: Ok("Day 01, since unlock\n\
+                     Name                        Part 1      Part 2\n\
+                     Bob                        0:05:00     0:06:00\n\
+                     Alice                      0:03:25     0:07:10\n\
+                     (anonymous user #1002)    26:00:00           -\n"),
+            });
+        }
+
+        #[test]
+        fn one_star() {
+            run(&Case {
+                day: 2,
+                expected: Ok("Day 02, since unlock\n\
+                     Name                        Part 1      Part 2\n\
+                     Bob                        0:30:00     0:45:00\n\
+                     Alice                      1:02:03           -\n\
+                     (anonymous user #1002)           -           -\n"),
+            });
+        }
+
+        #[test]
+        fn not_a_puzzle_day() {
+            run(&Case {
+                day: 26,
+                expected: Err("Day 26 of 2024 is not a puzzle day"),
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                test.expected.map(str::to_owned).map_err(str::to_owned),
+                fixture().times(test.day).map_err(|err| err.to_string())
+            );
+        }
+    }
+
+    mod parse {
+        use super::*;
+
+        struct Case {
+            json: &'static str,
+            expected: Result<Leaderboard, &'static str>,
+        }
+
+        #[test]
+        fn no_members() {
+            run(&Case {
+                json: r#"{"event":"2023","owner_id":1,"members":{}}"#,
+                expected: Ok(Leaderboard {
+                    year: 2023,
+                    members: Vec::new(),
+                }),
+            });
+        }
+
+        #[test]
+        fn login_page() {
+            run(&Case {
+                json: "<!DOCTYPE html>\n<html lang=\"en-us\">",
+                expected: Err("Invalid leaderboard JSON (expected value at line 1 column 1); check that the session token is valid and a member of the leaderboard"),
+            });
+        }
+
+        #[test]
+        fn missing_score() {
+            run(&Case {
+                json: r#"{"event":"2024","members":{"7":{"id":7,"name":"Carol","stars":0}}}"#,
+                expected: Err("Member 7 has no 'local_score'"),
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                test.expected.as_ref().map_err(|err| (*err).to_owned()),
+                Leaderboard::parse(test.json)
+                    .as_ref()
+                    .map_err(ToString::to_string)
+            );
+        }
+    }
+
+    #[test]
+    fn last_day() {
+        assert_eq!(Some(2), fixture().last_day());
+    }
+
+    #[test]
+    fn url_is_leaderboard() {
+        let url = url("https://adventofcode.com/", 2024, "1001");
+
+        assert_eq!(
+            "https://adventofcode.com/2024/leaderboard/private/view/1001.json",
+            url
+        );
+        assert!(is_leaderboard(&url));
+        assert!(!is_leaderboard("https://adventofcode.com/2024/day/1"));
+    }
+}
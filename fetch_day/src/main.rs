@@ -1,186 +1,549 @@
-use core::fmt::Write;
-use core::{fmt::Display, iter::Peekable, str::Chars};
-use std::path::PathBuf;
-use std::{fs, path::Path};
-
-use aes_gcm::{
-    aead::{rand_core::RngCore, Aead, OsRng},
-    Aes256Gcm, Key, KeyInit, Nonce,
+use core::time::Duration;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
 };
-use base64::Engine;
+
 use chrono::{Datelike, Utc};
-use clap::{Parser, ValueEnum};
+use clap::Parser;
 use regex::Regex;
-use reqwest::Error;
-use sailfish::TemplateOnce;
+
+mod crypto;
+mod examples;
+mod http;
+mod leaderboard;
+mod markdown;
+mod readme;
+mod record;
+mod status;
+mod submit;
+mod template;
+
+use http::Http;
+
+/// Where `fetch_day` keeps what it needs between runs, such as submitted answers and cached pages.
+pub(crate) const STATE_DIR: &str = ".fetch_day";
+
+/// Names a day's crate, data files and directories after its year and day, such as
+/// `y2024_day_01`, so that several years can share the repository.
+pub(crate) fn package_name(year: u32, day: u32) -> String {
+    format!("y{year}_day_{day:02}")
+}
+
+/// The year and day of a [`package_name`].
+pub(crate) fn parse_package_name(name: &str) -> Option<(u32, u32)> {
+    let (year, day) = name.strip_prefix('y')?.split_once("_day_")?;
+    if year.len() != 4 || day.len() != 2 {
+        return None;
+    }
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Where templates that replace or add to the built-in ones are looked for, relative to the
+/// repository root.
+const TEMPLATE_DIR: &str = "templates";
 
 #[derive(clap::Parser, Debug)]
-#[expect(clippy::struct_excessive_bools)]
 struct Opts {
+    #[arg(
+        long,
+        global = true,
+        default_value = http::DEFAULT_BASE_URL,
+        help = "Site to fetch puzzles from and submit answers to"
+    )]
+    base_url: String,
+
+    #[arg(
+        long,
+        global = true,
+        help = "User-Agent sent with every request (defaults to naming this repository and 'AOC_CONTACT')"
+    )]
+    user_agent: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "SECONDS",
+        default_value_t = 5,
+        help = "Minimum time between requests to the site, including across runs"
+    )]
+    min_interval: u64,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "DIR",
+        help = "Directory of language templates that replace or add to the built-in ones (defaults to 'templates')"
+    )]
+    templates: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Download a day's input and instructions, and create its solution templates
+    Fetch(FetchArgs),
+    /// Download a day's instructions again to update its READMEs, such as once part 2 is unlocked
+    Readme(ReadmeArgs),
+    /// Create a day's solution templates and register it with the runner, without downloading
+    Scaffold(ScaffoldArgs),
+    /// Decrypt the committed input data. Needs no session token
+    Decrypt(DaysArgs),
+    /// Encrypt input data added by hand, such as named inputs, so it can be committed
+    Encrypt(DaysArgs),
+    /// Re-encrypt the input data with the passphrase in `AOC_NEW_AES_KEY`
+    RotateKey,
+    /// Submit an answer, recording it in the day's solutions if it is correct
+    Submit(AnswerArgs),
+    /// Record an answer confirmed to be correct in the day's solutions
+    Record(AnswerArgs),
+    /// Show a private leaderboard, cached for 15 minutes as the site asks
+    Leaderboard(LeaderboardArgs),
+    /// List which days have solutions, data, READMEs and known answers in each language
+    Status(StatusArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct DayArgs {
     #[arg(
         short,
         long = "day",
         value_name = "DAY",
         value_parser = clap::value_parser!(u32).range(1..=25),
         default_value_t = Utc::now().with_timezone(&chrono::offset::FixedOffset::west_opt(5 * 3600).unwrap()).day().min(25),
-        help = "Day to download the instructions and input for (defaults to the min(current day, 25) in EST)"
+        help = "Day of the puzzle (defaults to the min(current day, 25) in EST)"
     )]
     day: u32,
 
     #[arg(long, default_value_t = Utc::now().year() as u32, help = "Competition year")]
     year: u32,
+}
+
+impl DayArgs {
+    fn url(&self, base_url: &str) -> String {
+        format!(
+            "{}/{}/day/{}",
+            base_url.trim_end_matches('/'),
+            self.year,
+            self.day
+        )
+    }
+}
+
+#[derive(clap::Args, Debug, Clone)]
+struct LangArgs {
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        default_value = "go,ts,rs",
+        help = "Languages to create instructions and templates for, built in or in the template directory"
+    )]
+    langs: Vec<String>,
+}
 
+#[derive(clap::Args, Debug)]
+struct InstructionArgs {
     #[arg(
         short = 'f',
         long = "download",
-        help = "Force the download of the instructions"
+        help = "Download the instructions even if 'instructions.html' exists"
     )]
     force_download: bool,
 
-    #[arg(long, group = "data", help = "Skip the downloading of the input data")]
+    #[arg(long, help = "Keep the raw instruction HTML file")]
+    keep_instructions: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct FetchArgs {
+    #[command(flatten)]
+    day: DayArgs,
+
+    #[command(flatten)]
+    langs: LangArgs,
+
+    #[command(flatten)]
+    instructions: InstructionArgs,
+
+    #[arg(long, help = "Skip the downloading of the input data")]
     no_data: bool,
 
-    #[arg(long, group = "data", help = "Only decrypt the input data")]
-    decrypt_data: bool,
+    #[arg(long, help = "Skip code template creation for each language")]
+    skip_templates: bool,
 
     #[arg(
-        short,
         long,
-        use_value_delimiter = true,
-        value_parser,
-        default_value = "go,ts,rs",
-        help = "Languages to create instructions and templates for"
+        help = "Replace template files that already exist, losing any changes made to them"
     )]
-    langs: Vec<Lang>,
+    overwrite: bool,
+}
 
-    #[arg(long, help = "Skip code template creation for each language")]
-    skip_templates: bool,
+#[derive(clap::Args, Debug)]
+struct ReadmeArgs {
+    #[command(flatten)]
+    day: DayArgs,
+
+    #[command(flatten)]
+    langs: LangArgs,
 
     #[arg(long, help = "Keep the raw instruction HTML file")]
     keep_instructions: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct ScaffoldArgs {
+    #[command(flatten)]
+    day: DayArgs,
+
+    #[command(flatten)]
+    langs: LangArgs,
 
     #[arg(
         long,
-        help = "Update the READMEs to contain part 2. Alias for '--download --no-data --skip-templates'"
+        help = "Replace template files that already exist, losing any changes made to them"
     )]
-    part_2: bool,
+    overwrite: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
-enum Lang {
-    Go,
-    Rs,
-    Ts,
+#[derive(clap::Args, Debug)]
+struct DaysArgs {
+    #[arg(
+        value_name = "DAYS",
+        value_delimiter = ',',
+        value_parser = clap::value_parser!(u32).range(1..=25),
+        help = "Days to include, including their named inputs (defaults to every day)"
+    )]
+    days: Vec<u32>,
+
+    #[arg(
+        long,
+        help = "Only include this competition year (defaults to every year)"
+    )]
+    year: Option<u32>,
 }
 
-impl Display for Lang {
-    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match *self {
-            Self::Go => formatter.write_str("go"),
-            Self::Rs => formatter.write_str("rs"),
-            Self::Ts => formatter.write_str("ts"),
+#[derive(clap::Args, Debug)]
+struct AnswerArgs {
+    #[command(flatten)]
+    day: DayArgs,
+
+    #[arg(
+        short,
+        long,
+        value_parser = clap::value_parser!(u8).range(1..=2),
+        default_value_t = 1,
+        help = "Part the answer is for"
+    )]
+    part: u8,
+
+    #[arg(help = "The answer")]
+    answer: String,
+}
+
+#[derive(clap::Args, Debug)]
+struct LeaderboardArgs {
+    #[arg(
+        long,
+        help = "ID of the private leaderboard (defaults to 'AOC_LEADERBOARD_ID')"
+    )]
+    id: Option<String>,
+
+    #[arg(long, default_value_t = Utc::now().year() as u32, help = "Competition year")]
+    year: u32,
+
+    #[arg(
+        short,
+        long,
+        value_parser = clap::value_parser!(u32).range(1..=25),
+        help = "Day to show completion times for (defaults to the latest day with a star)"
+    )]
+    day: Option<u32>,
+}
+
+#[derive(clap::Args, Debug)]
+struct StatusArgs {
+    #[arg(
+        long,
+        help = "Only include this competition year (defaults to every year in the workspace)"
+    )]
+    year: Option<u32>,
+
+    #[arg(
+        long,
+        help = "Fail if any day's crate, data and answers are out of step, such as encrypted data without a crate"
+    )]
+    strict: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let opts = Opts::parse();
+
+    let _loaded_env = dotenvy::dotenv().ok();
+
+    run(opts, Path::new("."), client, keys)
+}
+
+/// Runs a command against the repository at `root`. The session and data keys are only asked
+/// for by the commands that use them.
+fn run<H: Http>(
+    opts: Opts,
+    root: &Path,
+    client: impl FnOnce(&http::Politeness) -> anyhow::Result<H>,
+    keys: impl FnOnce() -> anyhow::Result<crypto::Keys>,
+) -> anyhow::Result<()> {
+    let data_dir = root.join("data");
+    let politeness = http::Politeness {
+        user_agent: opts.user_agent.clone().unwrap_or_else(|| {
+            http::default_user_agent(std::env::var("AOC_CONTACT").ok().as_deref())
+        }),
+        min_interval: Duration::from_secs(opts.min_interval),
+        state_dir: root.join(STATE_DIR),
+    };
+    let http = || client(&politeness);
+    let template_dir = opts
+        .templates
+        .clone()
+        .unwrap_or_else(|| root.join(TEMPLATE_DIR));
+    let day_builder = |day: &DayArgs, langs: LangArgs| {
+        DayBuilder::new(day, langs.langs, &opts.base_url, root, &template_dir)
+    };
+
+    match opts.command {
+        Command::Fetch(args) => fetch(
+            &args,
+            &day_builder(&args.day, args.langs.clone()),
+            &http()?,
+            keys,
+        ),
+        Command::Readme(args) => {
+            let day = day_builder(&args.day, args.langs);
+            let instructions = InstructionArgs {
+                force_download: true,
+                keep_instructions: args.keep_instructions,
+            };
+            let examples = day.write_instruction_files(&http()?, &instructions)?;
+            day.fill_rs_part_2(&examples)
+        }
+        Command::Scaffold(args) => {
+            let day = day_builder(&args.day, args.langs);
+            day.add_templates(&day.languages()?, args.overwrite)
+        }
+        Command::Decrypt(args) => {
+            let decrypted = crypto::decrypt_data(&keys()?, &data_dir, args.year, &args.days)?;
+            println!(
+                "Decrypted {} file(s), {} already up to date",
+                decrypted.written, decrypted.up_to_date
+            );
+            Ok(())
         }
+        Command::Encrypt(args) => {
+            let encrypted = crypto::encrypt_data(&keys()?, &data_dir, args.year, &args.days)?;
+            println!(
+                "Encrypted {} file(s), {} already up to date",
+                encrypted.written, encrypted.up_to_date
+            );
+            Ok(())
+        }
+        Command::RotateKey => {
+            let Ok(new_key) = std::env::var("AOC_NEW_AES_KEY") else {
+                anyhow::bail!("No 'AOC_NEW_AES_KEY' set");
+            };
+            let rotated = crypto::rotate(&keys()?, &crypto::Keys::new(new_key), &data_dir)?;
+            println!("Re-encrypted {rotated} file(s); set 'AOC_AES_KEY' to the new passphrase");
+            Ok(())
+        }
+        Command::Submit(args) => submit_answer(&http()?, &opts.base_url, root, &args),
+        Command::Record(args) => record_answer(root, &args),
+        Command::Leaderboard(args) => leaderboard(&http()?, &opts.base_url, &args),
+        Command::Status(args) => status(root, &template_dir, &args),
     }
 }
 
-fn main() -> anyhow::Result<()> {
-    let opts = {
-        let mut opts = Opts::parse();
-        if opts.part_2 {
-            opts.force_download = true;
-            opts.no_data = true;
-            opts.skip_templates = true;
-        }
-        opts
+fn leaderboard(http: &impl Http, base_url: &str, args: &LeaderboardArgs) -> anyhow::Result<()> {
+    let Some(id) = args
+        .id
+        .clone()
+        .or_else(|| std::env::var("AOC_LEADERBOARD_ID").ok())
+    else {
+        anyhow::bail!("No '--id' given and no 'AOC_LEADERBOARD_ID' set");
     };
 
-    let _loaded_env = dotenvy::dotenv().ok();
+    let leaderboard =
+        leaderboard::Leaderboard::parse(&http.get(&leaderboard::url(base_url, args.year, &id))?)?;
+    print!("{}", leaderboard.table());
+    if let Some(day) = args.day.or_else(|| leaderboard.last_day()) {
+        println!();
+        print!("{}", leaderboard.times(day)?);
+    }
+    Ok(())
+}
 
+fn status(root: &Path, template_dir: &Path, args: &StatusArgs) -> anyhow::Result<()> {
+    let langs = template::available(template_dir);
+    let years = match args.year {
+        Some(year) => BTreeSet::from([year]),
+        None => status::years(root, &langs)?,
+    };
+    let mut days = Vec::new();
+    for year in years {
+        days.extend(status::scan(root, year, &langs)?);
+    }
+    print!("{}", status::table(&days, &langs));
+
+    let problems = days
+        .iter()
+        .flat_map(status::DayStatus::problems)
+        .collect::<Vec<_>>();
+    if problems.is_empty() {
+        return Ok(());
+    }
+    if args.strict {
+        anyhow::bail!(
+            "{} problem(s):\n  {}",
+            problems.len(),
+            problems.join("\n  ")
+        );
+    }
+    println!();
+    for problem in problems {
+        println!("{problem}");
+    }
+    Ok(())
+}
+
+fn client(politeness: &http::Politeness) -> anyhow::Result<http::Client> {
     let Ok(aoc_token) = std::env::var("AOC_SESSION_TOKEN") else {
         anyhow::bail!("No 'AOC_SESSION_TOKEN' set");
     };
+    http::Client::new(aoc_token, politeness)
+}
+
+fn keys() -> anyhow::Result<crypto::Keys> {
     let Ok(key) = std::env::var("AOC_AES_KEY") else {
         anyhow::bail!("No 'AOC_AES_KEY' set");
     };
-    let day_url = format!("https://adventofcode.com/{}/day/{}", opts.year, opts.day);
+    Ok(crypto::Keys::new(key))
+}
 
-    if opts.decrypt_data {
-        return decrypt_data(key.as_bytes());
+/// Downloads the day's input and instructions, then scaffolds every language.
+fn fetch(
+    args: &FetchArgs,
+    day: &DayBuilder,
+    http: &impl Http,
+    keys: impl FnOnce() -> anyhow::Result<crypto::Keys>,
+) -> anyhow::Result<()> {
+    // Check the templates before downloading anything, so a broken one stops the whole fetch.
+    let languages = if args.skip_templates {
+        Vec::new()
+    } else {
+        day.languages()?
+    };
+
+    if !args.no_data {
+        day.write_data_file(http, &keys()?)?;
     }
+    let _examples = day.write_instruction_files(http, &args.instructions)?;
+    day.add_templates(&languages, args.overwrite)?;
 
-    let day = DayBuilder::new(opts, aoc_token, &key, day_url);
+    Ok(())
+}
 
-    day.write_data_file()?;
-    day.write_instruction_files()?;
+fn submit_answer(
+    http: &impl Http,
+    base_url: &str,
+    root: &Path,
+    args: &AnswerArgs,
+) -> anyhow::Result<()> {
+    let mut history = submit::History::load(submit::history_path(root))?;
+    let attempt = submit::Attempt {
+        year: args.day.year,
+        day: args.day.day,
+        part: args.part,
+        answer: args.answer.clone(),
+    };
 
-    for &lang in &day.opts.langs {
-        match lang {
-            Lang::Ts => day.add_ts_template()?,
-            Lang::Go => day.add_go_template()?,
-            Lang::Rs => day.add_rs_template()?,
+    let outcome = match submit::submit(http, &args.day.url(base_url), &mut history, attempt)? {
+        submit::Submission::Submitted(outcome) => {
+            println!("Answer is {outcome}");
+            outcome
+        }
+        submit::Submission::Skipped(outcome) => {
+            println!("Not submitted, a previous attempt shows the answer is {outcome}");
+            outcome
         }
+    };
+
+    if outcome == submit::Outcome::Correct {
+        record_answer(root, args)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a correct answer into every language the day was scaffolded in.
+fn record_answer(root: &Path, args: &AnswerArgs) -> anyhow::Result<()> {
+    let changed =
+        record::record_answer(root, args.day.year, args.day.day, args.part, &args.answer)?;
+    if changed.is_empty() {
+        println!("Answer already recorded");
+    }
+    for file in changed {
+        println!("Recorded the answer in {}", file.display());
     }
 
     Ok(())
 }
 
 struct DayBuilder {
-    opts: Opts,
-    aoc_token: String,
-    aoc_aes_key: Aes256Gcm,
-    base_url: String,
+    day: u32,
+    year: u32,
+    langs: Vec<String>,
+    day_url: String,
+    /// Repository root that every file is written relative to.
+    root: PathBuf,
     package_name: String,
     display_name: String,
+    /// Where templates that replace or add to the built-in ones are looked for.
+    template_dir: PathBuf,
 }
 
 impl DayBuilder {
-    fn new(opts: Opts, aoc_token: String, data_key: &str, base_url: String) -> Self {
-        let key = Key::<Aes256Gcm>::from_slice(data_key.as_bytes());
-        let aoc_aes_key = Aes256Gcm::new(key);
-
-        let package_name = format!("day_{:02}", opts.day);
-        let display_name = format!("Day {:02}", opts.day);
-
+    fn new(
+        day: &DayArgs,
+        langs: Vec<String>,
+        base_url: &str,
+        root: &Path,
+        template_dir: &Path,
+    ) -> Self {
         Self {
-            opts,
-            aoc_token,
-            aoc_aes_key,
-            base_url,
-            package_name,
-            display_name,
+            day: day.day,
+            year: day.year,
+            langs,
+            day_url: day.url(base_url),
+            root: root.to_path_buf(),
+            package_name: package_name(day.year, day.day),
+            display_name: format!("{} Day {:02}", day.year, day.day),
+            template_dir: template_dir.to_path_buf(),
         }
     }
 
-    fn write_data_file(&self) -> anyhow::Result<()> {
-        if self.opts.no_data {
-            return Ok(());
-        }
-
-        let data_dir = Path::new("data");
-        fs::create_dir_all(data_dir)?;
+    fn write_data_file(&self, http: &impl Http, keys: &crypto::Keys) -> anyhow::Result<()> {
+        let data_dir = self.root.join("data");
+        fs::create_dir_all(&data_dir)?;
 
         let data_file = data_dir.join(format!("{}.txt", self.package_name));
         if data_file.exists() {
             return Ok(());
         }
 
-        let data = self.get_from_aoc(Some("input"))?;
+        let data = http.get(&format!("{}/input", self.day_url))?;
         fs::write(data_file, &data)?;
 
-        let mut nonce = [0_u8; 12];
-        OsRng.fill_bytes(&mut nonce);
-
-        let nonce = Nonce::from(nonce);
-
-        let ciphertext = self.aoc_aes_key.encrypt(&nonce, data.as_bytes())?;
-
-        let encoded = base64::engine::general_purpose::STANDARD.encode(
-            nonce
-                .iter()
-                .chain(ciphertext.iter())
-                .copied()
-                .collect::<Vec<u8>>(),
-        );
+        let encoded = keys.encrypt(data.as_bytes())?;
 
         fs::write(
             data_dir.join(format!("{}.enc.txt", self.package_name)),
@@ -190,19 +553,25 @@ impl DayBuilder {
         Ok(())
     }
 
-    fn write_instruction_files(&self) -> anyhow::Result<()> {
-        let instruction_file = Path::new("instructions.html");
-
-        let instructions_html = if !self.opts.force_download && instruction_file.exists() {
-            let instructions = fs::read_to_string(instruction_file)?;
-            if !self.opts.keep_instructions {
-                fs::remove_file(instruction_file)?;
+    /// Writes the READMEs and example files, returning the examples found in the instructions.
+    /// Only the part of each README generated from the instructions is replaced.
+    fn write_instruction_files(
+        &self,
+        http: &impl Http,
+        args: &InstructionArgs,
+    ) -> anyhow::Result<examples::Examples> {
+        let instruction_file = self.root.join("instructions.html");
+
+        let instructions_html = if !args.force_download && instruction_file.exists() {
+            let instructions = fs::read_to_string(&instruction_file)?;
+            if !args.keep_instructions {
+                fs::remove_file(&instruction_file)?;
             }
             instructions
         } else {
-            let instructions = self.get_from_aoc(None)?;
-            if self.opts.keep_instructions {
-                fs::write(instruction_file, &instructions)?;
+            let instructions = http.get(&self.day_url)?;
+            if args.keep_instructions {
+                fs::write(&instruction_file, &instructions)?;
             }
             instructions
         };
@@ -215,420 +584,781 @@ impl DayBuilder {
             anyhow::bail!("Failed to extract parts from instructions page")
         };
 
-        let mut readme_contents = String::new();
-        for part in parts {
-            let mut iter = part.chars().peekable();
-            while iter.peek().is_some() {
-                let out = recursive_parse_instructions(&self.base_url, &mut iter, false)?;
-                readme_contents.push_str(&out);
-            }
-            readme_contents.push('\n');
-        }
+        let mut readme_contents = parts
+            .iter()
+            .map(|part| markdown::to_markdown(part, &self.day_url))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        readme_contents.push('\n');
 
-        for lang in &self.opts.langs {
-            let dir = Path::new(&lang.to_string()).join(&self.package_name);
+        for lang in &self.langs {
+            let dir = self.root.join(lang).join(&self.package_name);
             fs::create_dir_all(&dir)?;
-            fs::write(dir.join("README.md"), readme_contents.as_str())?;
+            let file = dir.join("README.md");
+            let existing = fs::read_to_string(&file).ok();
+            let (readme, refresh) = readme::refresh(existing.as_deref(), &readme_contents);
+            if refresh != readme::Refresh::Unchanged {
+                fs::write(&file, readme)?;
+            }
+            println!("{}: {refresh}", file.display());
         }
 
-        Ok(())
+        let examples = examples::Examples::from_parts(&parts);
+        self.write_example_files(&examples)?;
+        Ok(examples)
+    }
+
+    /// `data/examples/yYYYY_day_XX`, which holds the day's sample inputs as `example_N.txt`.
+    fn examples_dir(&self) -> PathBuf {
+        self.root
+            .join("data")
+            .join("examples")
+            .join(&self.package_name)
     }
 
-    fn add_go_template(&self) -> anyhow::Result<()> {
-        if self.opts.skip_templates {
+    fn write_example_files(&self, examples: &examples::Examples) -> anyhow::Result<()> {
+        if examples.inputs.is_empty() {
             return Ok(());
         }
 
-        let dir = Path::new("go").join(&self.package_name);
-
-        MainGo {
-            package_name: &self.package_name,
+        let dir = self.examples_dir();
+        fs::create_dir_all(&dir)?;
+        for (number, example) in (1_u32..).zip(&examples.inputs) {
+            fs::write(dir.join(format!("example_{number}.txt")), example)?;
         }
-        .write(&dir)?;
-
-        MainTestGo {}.write(&dir)?;
+        fs::write(
+            dir.join("answers.txt"),
+            examples.answers(self.year, self.day),
+        )?;
 
         Ok(())
     }
 
-    fn add_ts_template(&self) -> anyhow::Result<()> {
-        if self.opts.skip_templates {
-            return Ok(());
+    /// The examples written by [`Self::write_example_files`]. There are none until the
+    /// instructions have been downloaded.
+    fn read_example_files(&self) -> anyhow::Result<examples::Examples> {
+        let dir = self.examples_dir();
+        let mut inputs = Vec::new();
+        for number in 1_u32.. {
+            let file = dir.join(format!("example_{number}.txt"));
+            if !file.exists() {
+                break;
+            }
+            inputs.push(fs::read_to_string(file)?);
         }
 
-        let dir = Path::new("ts").join(&self.package_name);
+        let answers_file = dir.join("answers.txt");
+        let answers = if answers_file.exists() {
+            fs::read_to_string(answers_file)?
+        } else {
+            String::new()
+        };
 
-        MainTs {}.write(&dir)?;
+        Ok(examples::Examples::from_files(inputs, &answers))
+    }
 
-        DayTs {
-            package_name: &self.package_name,
+    /// Fills in the part 2 example answer in a Rust solution scaffolded before part 2 was
+    /// unlocked.
+    fn fill_rs_part_2(&self, examples: &examples::Examples) -> anyhow::Result<()> {
+        let lib_file = self
+            .root
+            .join("rs")
+            .join(&self.package_name)
+            .join("src")
+            .join("lib.rs");
+        if !self.langs.iter().any(|lang| lang == "rs") || !lib_file.exists() {
+            return Ok(());
         }
-        .write(&dir)?;
 
-        DayTestTs {}.write(&dir)?;
-
-        DayBenchTs {}.write(&dir)?;
+        let lib = fs::read_to_string(&lib_file)?;
+        if let Some(filled) = examples::fill_part_2(&lib, examples) {
+            fs::write(&lib_file, filled)?;
+            println!(
+                "Filled in the part 2 example answer in {}",
+                lib_file.display()
+            );
+        }
 
         Ok(())
     }
 
-    fn add_rs_template(&self) -> anyhow::Result<()> {
-        if self.opts.skip_templates {
-            return Ok(());
-        }
-
-        let dir = Path::new("rs").join(&self.package_name);
-
-        fs::create_dir_all(dir.join("src").join("bin"))?;
-        fs::create_dir_all(dir.join("benches"))?;
+    /// Loads and checks the templates of every language.
+    fn languages(&self) -> anyhow::Result<Vec<template::Language>> {
+        self.langs
+            .iter()
+            .map(|lang| template::Language::load(lang, &self.template_dir))
+            .collect()
+    }
 
-        CargoRs {
-            crate_name: &self.package_name,
-        }
-        .write(&dir)?;
+    /// Renders the templates of each language into `<lang>/yYYYY_day_XX`, registering the Rust
+    /// solution with the runner. Files that already exist hold the day's solution, so they are
+    /// kept unless `overwrite` is set.
+    fn add_templates(
+        &self,
+        languages: &[template::Language],
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        let values = self.template_values()?;
+
+        for language in languages {
+            let dir = self.root.join(&language.name).join(&self.package_name);
+            for (path, contents) in language.render(&values) {
+                let file = dir.join(path);
+                if !overwrite && file.exists() {
+                    println!("{}: kept, as it already exists", file.display());
+                    continue;
+                }
+                if let Some(parent) = file.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(file, contents)?;
+            }
 
-        LibRs {
-            crate_name: &self.package_name,
+            if language.name == "rs" {
+                self.register_with_runner()?;
+            }
         }
-        .write(&dir)?;
 
-        MainRs {
-            crate_name: &self.package_name,
-        }
-        .write(&dir)?;
+        Ok(())
+    }
 
-        BenchRs {
-            crate_name: &self.package_name,
-            display_name: &self.display_name,
+    fn template_values(&self) -> anyhow::Result<template::Values> {
+        let examples = self.read_example_files()?;
+
+        let mut values = template::Values::default();
+        for (name, value) in [
+            ("crate_name", self.package_name.clone()),
+            ("display_name", self.display_name.clone()),
+            ("day", self.day.to_string()),
+            ("year", self.year.to_string()),
+            ("part_1_example", examples.number(1).to_string()),
+            ("part_1_expected", examples.expected(1)),
+            ("part_1_expected_type", examples.expected_type(1).to_owned()),
+            ("part_1_type", examples.answer_type(1).to_owned()),
+            ("part_2_example", examples.number(2).to_string()),
+            ("part_2_expected", examples.expected(2)),
+            ("part_2_expected_type", examples.expected_type(2).to_owned()),
+            ("part_2_type", examples.answer_type(2).to_owned()),
+        ] {
+            let _prev = values.variables.insert(name, value);
         }
-        .write(&dir)?;
 
-        Ok(())
+        // Without any examples yet, there is still one to fill in by hand.
+        let inputs = if examples.inputs.is_empty() {
+            vec![String::new()]
+        } else {
+            examples.inputs
+        };
+        let items = (1_usize..)
+            .zip(inputs)
+            .map(|(number, input)| {
+                BTreeMap::from([
+                    ("number", number.to_string()),
+                    ("rust_input", examples::rust_literal(&input)),
+                    ("input", input),
+                ])
+            })
+            .collect();
+        let _prev = values.sections.insert("examples", items);
+
+        Ok(values)
     }
 
-    fn get_from_aoc(&self, path: Option<&str>) -> Result<String, Error> {
-        let url = path.map_or_else(
-            || self.base_url.clone(),
-            |url_path| format!("{}/{}", self.base_url, url_path),
-        );
-
-        let client = reqwest::blocking::Client::new();
-        let req = client
-            .get(url)
-            .header("Cookie", format!("session={}", self.aoc_token))
-            .build()?;
+    fn register_with_runner(&self) -> anyhow::Result<()> {
+        let runner_dir = self.root.join("rs").join("aoc");
+
+        let manifest_file = runner_dir.join("Cargo.toml");
+        let manifest = fs::read_to_string(&manifest_file)?;
+        let Some(manifest) = insert_sorted_line(
+            &manifest,
+            &format!("{0} = {{ path = \"../{0}\" }}", self.package_name),
+            |line| {
+                line.split_once(' ')
+                    .is_some_and(|(name, _)| parse_package_name(name).is_some())
+            },
+        ) else {
+            anyhow::bail!("No day dependencies found in {manifest_file:?}");
+        };
+        fs::write(manifest_file, manifest)?;
+
+        let days_file = runner_dir.join("src").join("days.rs");
+        let days = fs::read_to_string(&days_file)?;
+        let Some(days) =
+            insert_sorted_line(&days, &format!("    {},", self.package_name), |line| {
+                line.strip_prefix("    ")
+                    .and_then(|entry| entry.strip_suffix(','))
+                    .is_some_and(|name| parse_package_name(name).is_some())
+            })
+        else {
+            anyhow::bail!("No days found in {days_file:?}");
+        };
+        fs::write(days_file, days)?;
 
-        client.execute(req)?.text()
+        Ok(())
     }
 }
 
-trait Template {
-    fn write(self, base: impl AsRef<Path>) -> anyhow::Result<()>;
-}
+/// Inserts `new_line` into the contiguous, sorted run of lines matching `is_entry`,
+/// returning `None` if no such run exists.
+fn insert_sorted_line(
+    contents: &str,
+    new_line: &str,
+    is_entry: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let lines = contents.lines().collect::<Vec<_>>();
+    let first = lines.iter().position(|line| is_entry(line))?;
+    let last = lines.iter().rposition(|line| is_entry(line))?;
+
+    let mut entries = lines.get(first..=last)?.to_vec();
+    if !entries.contains(&new_line) {
+        entries.push(new_line);
+        entries.sort_unstable();
+    }
 
-#[derive(TemplateOnce)]
-#[template(path = "rs/src/lib.stpl.rs")]
-struct LibRs<'a> {
-    crate_name: &'a str,
+    let mut output = lines
+        .get(..first)?
+        .iter()
+        .chain(&entries)
+        .chain(lines.get(last + 1..)?)
+        .copied()
+        .collect::<Vec<_>>()
+        .join("\n");
+    output.push('\n');
+    Some(output)
 }
 
-impl Template for LibRs<'_> {
-    fn write(self, base: impl AsRef<Path>) -> anyhow::Result<()> {
-        fs::write(base.as_ref().join("src").join("lib.rs"), {
-            let mut output = self.render_once()?;
-            output.push('\n');
-            output
-        })?;
-        Ok(())
+#[cfg(test)]
+mod fetch_day_tests {
+    use super::*;
+    use http::Fixtures;
+
+    const BASE_URL: &str = "https://aoc.test";
+    const KEY: &str = "a passphrase of any length";
+
+    /// A fresh repository root containing just the runner files that scaffolding updates.
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("fetch_day_tests_{name}"));
+        if root.exists() {
+            fs::remove_dir_all(&root).unwrap();
+        }
+
+        let runner_dir = root.join("rs").join("aoc");
+        fs::create_dir_all(runner_dir.join("src")).unwrap();
+        fs::write(
+            runner_dir.join("Cargo.toml"),
+            include_str!("../../rs/aoc/Cargo.toml"),
+        )
+        .unwrap();
+        fs::write(
+            runner_dir.join("src").join("days.rs"),
+            include_str!("../../rs/aoc/src/days.rs"),
+        )
+        .unwrap();
+
+        root
     }
-}
 
-#[derive(TemplateOnce)]
-#[template(path = "rs/Cargo.stpl.toml")]
-struct CargoRs<'a> {
-    crate_name: &'a str,
-}
+    fn fixtures(day_url: &str) -> Fixtures {
+        let fixtures_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        Fixtures::default()
+            .with_file(day_url, fixtures_dir.join("day.html"))
+            .unwrap()
+            .with_file(&format!("{day_url}/input"), fixtures_dir.join("input.txt"))
+            .unwrap()
+    }
 
-impl Template for CargoRs<'_> {
-    fn write(self, base: impl AsRef<Path>) -> anyhow::Result<()> {
-        fs::write(base.as_ref().join("Cargo.toml"), {
-            let mut output = self.render_once()?;
-            output.push('\n');
-            output
-        })?;
-        Ok(())
+    fn run_command(root: &Path, fixtures: &Fixtures, args: &[&str]) -> anyhow::Result<()> {
+        let opts = Opts::try_parse_from(["fetch_day", "--base-url", BASE_URL].iter().chain(args))?;
+        run(
+            opts,
+            root,
+            |_| Ok(fixtures),
+            || Ok(crypto::Keys::new(KEY.to_owned())),
+        )
     }
-}
 
-#[derive(TemplateOnce)]
-#[template(path = "rs/src/bin/main.stpl.rs")]
-struct MainRs<'a> {
-    crate_name: &'a str,
-}
+    #[test]
+    fn fetch_encrypt_and_scaffold() {
+        let root = temp_root("fetch_encrypt_and_scaffold");
+        let fixtures = fixtures(&format!("{BASE_URL}/2024/day/17"));
+
+        run_command(&root, &fixtures, &["fetch", "--year", "2024", "-d", "17"]).unwrap();
+
+        assert_eq!(
+            vec![
+                format!("GET {BASE_URL}/2024/day/17/input"),
+                format!("GET {BASE_URL}/2024/day/17"),
+            ],
+            fixtures.requests()
+        );
 
-impl Template for MainRs<'_> {
-    fn write(self, base: impl AsRef<Path>) -> anyhow::Result<()> {
-        let Some(day) = base.as_ref().file_name().and_then(|fname| fname.to_str()) else {
-            anyhow::bail!(
-                "Invalid base path for 'main.rs' generation: {:?}",
-                base.as_ref()
+        let input = include_str!("../fixtures/input.txt");
+        let data_file = root.join("data").join("y2024_day_17.txt");
+        assert_eq!(input, fs::read_to_string(&data_file).unwrap());
+
+        fs::remove_file(&data_file).unwrap();
+        run_command(&root, &fixtures, &["decrypt", "17"]).unwrap();
+        assert_eq!(input, fs::read_to_string(&data_file).unwrap());
+
+        for lang in ["go", "rs", "ts"] {
+            assert_eq!(
+                readme::refresh(None, include_str!("../fixtures/day.md")).0,
+                fs::read_to_string(root.join(lang).join("y2024_day_17").join("README.md")).unwrap(),
+                "Unexpected {lang} README"
             );
-        };
+        }
 
-        fs::write(
-            base.as_ref()
-                .join("src")
-                .join("bin")
-                .join(format!("{day}.rs")),
-            {
-                let mut output = self.render_once()?;
-                output.push('\n');
-                output
-            },
-        )?;
-        Ok(())
-    }
-}
+        for file in [
+            "go/y2024_day_17/main.go",
+            "go/y2024_day_17/main_test.go",
+            "ts/y2024_day_17/day.ts",
+            "ts/y2024_day_17/day.test.ts",
+            "rs/y2024_day_17/Cargo.toml",
+            "rs/y2024_day_17/src/lib.rs",
+            "rs/y2024_day_17/benches/bench.rs",
+        ] {
+            assert!(root.join(file).exists(), "Missing {file}");
+        }
 
-#[derive(TemplateOnce)]
-#[template(path = "rs/benches/bench.stpl.rs")]
-struct BenchRs<'a> {
-    crate_name: &'a str,
-    display_name: &'a str,
-}
+        let manifest = fs::read_to_string(root.join("rs/aoc/Cargo.toml")).unwrap();
+        assert!(
+            manifest.contains(
+                "y2024_day_16 = { path = \"../y2024_day_16\" }\ny2024_day_17 = { path = \"../y2024_day_17\" }\ny2024_day_18"
+            ),
+            "y2024_day_17 not registered in:\n{manifest}"
+        );
+        let days = fs::read_to_string(root.join("rs/aoc/src/days.rs")).unwrap();
+        assert!(
+            days.contains("    y2024_day_16,\n    y2024_day_17,\n    y2024_day_18,"),
+            "y2024_day_17 not registered in:\n{days}"
+        );
 
-impl Template for BenchRs<'_> {
-    fn write(self, base: impl AsRef<Path>) -> anyhow::Result<()> {
-        fs::write(base.as_ref().join("benches").join("bench.rs"), {
-            let mut output = self.render_once()?;
-            output.push('\n');
-            output
-        })?;
-        Ok(())
+        assert_eq!(
+            "3   4\n4   3\n2   5\n",
+            fs::read_to_string(root.join("data/examples/y2024_day_17/example_1.txt")).unwrap()
+        );
+        let lib = fs::read_to_string(root.join("rs/y2024_day_17/src/lib.rs")).unwrap();
+        assert!(
+            lib.contains("fn example_1() -> (&'static str, Input) {\n        (\n            \"3   4\n4   3\n2   5\",\n"),
+            "Example missing from:\n{lib}"
+        );
+        assert!(
+            !lib.contains("fn example_2"),
+            "Unexpected example in:\n{lib}"
+        );
     }
-}
 
-#[derive(TemplateOnce)]
-#[template(path = "go/main.stpl.go")]
-struct MainGo<'a> {
-    package_name: &'a str,
-}
+    #[test]
+    fn readme_only_updates_readmes_and_examples() {
+        let root = temp_root("readme_only_updates_readmes_and_examples");
+        let fixtures = fixtures(&format!("{BASE_URL}/2024/day/17"));
+
+        run_command(
+            &root,
+            &fixtures,
+            &["readme", "--year", "2024", "-d", "17", "-l", "rs"],
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![format!("GET {BASE_URL}/2024/day/17")],
+            fixtures.requests()
+        );
+        assert_eq!(
+            readme::refresh(None, include_str!("../fixtures/day.md")).0,
+            fs::read_to_string(root.join("rs/y2024_day_17/README.md")).unwrap()
+        );
+        assert!(
+            root.join("data/examples/y2024_day_17/example_1.txt")
+                .exists(),
+            "Examples should be refreshed with the README"
+        );
+        assert!(
+            !root.join("data/y2024_day_17.txt").exists()
+                && !root.join("rs/y2024_day_17/src").exists(),
+            "Only the README and examples should be written"
+        );
+    }
 
-impl Template for MainGo<'_> {
-    fn write(self, base: impl AsRef<Path>) -> anyhow::Result<()> {
-        fs::write(base.as_ref().join("main.go"), {
-            let mut output = self.render_once()?;
-            output.push('\n');
-            output
-        })?;
-        Ok(())
+    #[test]
+    fn readme_adds_part_2_and_keeps_notes() {
+        let root = temp_root("readme_adds_part_2_and_keeps_notes");
+        let day_url = format!("{BASE_URL}/2024/day/17");
+        let args = ["readme", "--year", "2024", "-d", "17", "-l", "rs"];
+
+        run_command(&root, &fixtures(&day_url), &args).unwrap();
+        run_command(
+            &root,
+            &fixtures(&day_url),
+            &["scaffold", "--year", "2024", "-d", "17", "-l", "rs"],
+        )
+        .unwrap();
+        let readme_file = root.join("rs/y2024_day_17/README.md");
+        let mut readme = fs::read_to_string(&readme_file).unwrap();
+        readme.push_str("\n## Notes\n\nSort both lists first.\n");
+        fs::write(&readme_file, &readme).unwrap();
+
+        let part_2 = Fixtures::default()
+            .with_file(&day_url, "fixtures/day_part_2.html")
+            .unwrap();
+        run_command(&root, &part_2, &args).unwrap();
+
+        let refreshed = fs::read_to_string(&readme_file).unwrap();
+        assert!(
+            readme::has_part_2(&refreshed)
+                && refreshed.ends_with("\n## Notes\n\nSort both lists first.\n"),
+            "Part 2 missing or notes lost:\n{refreshed}"
+        );
+        let lib = fs::read_to_string(root.join("rs/y2024_day_17/src/lib.rs")).unwrap();
+        assert!(
+            lib.contains("data: example_2().1,\n                expected: 6,"),
+            "Part 2 example not filled in:\n{lib}"
+        );
+
+        run_command(&root, &part_2, &args).unwrap();
+        assert_eq!(refreshed, fs::read_to_string(&readme_file).unwrap());
     }
-}
 
-#[derive(TemplateOnce)]
-#[template(path = "go/main_test.stpl.go")]
-struct MainTestGo {}
-
-impl Template for MainTestGo {
-    fn write(self, base: impl AsRef<Path>) -> anyhow::Result<()> {
-        fs::write(base.as_ref().join("main_test.go"), {
-            let mut output = self.render_once()?;
-            output.push('\n');
-            output
-        })?;
-        Ok(())
+    #[test]
+    fn fetch_without_data_needs_no_key() {
+        let root = temp_root("fetch_without_data_needs_no_key");
+        let fixtures = fixtures(&format!("{BASE_URL}/2024/day/17"));
+        let opts = Opts::try_parse_from([
+            "fetch_day",
+            "--base-url",
+            BASE_URL,
+            "fetch",
+            "--year",
+            "2024",
+            "-d",
+            "17",
+            "--no-data",
+        ])
+        .unwrap();
+
+        run(
+            opts,
+            &root,
+            |_| Ok(&fixtures),
+            || anyhow::bail!("No 'AOC_AES_KEY' set"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![format!("GET {BASE_URL}/2024/day/17")],
+            fixtures.requests()
+        );
+        assert!(!root.join("data/y2024_day_17.enc.txt").exists());
+        assert!(root.join("rs/y2024_day_17/src/lib.rs").exists());
     }
-}
 
-#[derive(TemplateOnce)]
-#[template(path = "ts/main.stpl.ts")]
-struct MainTs {}
-
-impl Template for MainTs {
-    fn write(self, base: impl AsRef<Path>) -> anyhow::Result<()> {
-        fs::write(base.as_ref().join("main.ts"), {
-            let mut output = self.render_once()?;
-            output.push('\n');
-            output
-        })?;
-        Ok(())
+    #[test]
+    fn scaffold_needs_no_network() {
+        let root = temp_root("scaffold_needs_no_network");
+        let fixtures = Fixtures::default();
+
+        run_command(
+            &root,
+            &fixtures,
+            &["scaffold", "--year", "2024", "-d", "17", "-l", "rs"],
+        )
+        .unwrap();
+
+        assert_eq!(Vec::<String>::new(), fixtures.requests());
+        assert!(
+            root.join("rs/y2024_day_17/src/lib.rs").exists(),
+            "Missing the template"
+        );
+        assert!(
+            !root.join("rs/y2024_day_17/README.md").exists() && !root.join("go").exists(),
+            "Only the Rust templates should be written"
+        );
     }
-}
 
-#[derive(TemplateOnce)]
-#[template(path = "ts/day.stpl.ts")]
-struct DayTs<'a> {
-    package_name: &'a str,
-}
+    #[test]
+    fn scaffold_keeps_existing_files() {
+        let root = temp_root("scaffold_keeps_existing_files");
+        let args = ["scaffold", "--year", "2024", "-d", "17", "-l", "rs"];
+        let lib_file = root.join("rs/y2024_day_17/src/lib.rs");
+
+        run_command(&root, &Fixtures::default(), &args).unwrap();
+        let mut lib = fs::read_to_string(&lib_file).unwrap();
+        lib.push_str(
+            "
+// Solved.
+",
+        );
+        fs::write(&lib_file, &lib).unwrap();
+        fs::remove_file(root.join("rs/y2024_day_17/benches/bench.rs")).unwrap();
 
-impl Template for DayTs<'_> {
-    fn write(self, base: impl AsRef<Path>) -> anyhow::Result<()> {
-        fs::write(base.as_ref().join("day.ts"), {
-            let mut output = self.render_once()?;
-            output.push('\n');
-            output
-        })?;
-        Ok(())
+        run_command(&root, &Fixtures::default(), &args).unwrap();
+
+        assert_eq!(lib, fs::read_to_string(&lib_file).unwrap());
+        assert!(
+            root.join("rs/y2024_day_17/benches/bench.rs").exists(),
+            "Missing files should still be written"
+        );
+
+        run_command(
+            &root,
+            &Fixtures::default(),
+            &[&args[..], &["--overwrite"]].concat(),
+        )
+        .unwrap();
+        assert!(
+            !fs::read_to_string(&lib_file)
+                .unwrap()
+                .contains("// Solved."),
+            "--overwrite should replace the existing lib.rs"
+        );
     }
-}
 
-#[derive(TemplateOnce)]
-#[template(path = "ts/day.bench.stpl.ts")]
-struct DayBenchTs {}
-
-impl Template for DayBenchTs {
-    fn write(self, base: impl AsRef<Path>) -> anyhow::Result<()> {
-        fs::write(base.as_ref().join("day.bench.ts"), {
-            let mut output = self.render_once()?;
-            output.push('\n');
-            output
-        })?;
-        Ok(())
+    #[test]
+    fn scaffold_uses_custom_templates() {
+        let root = temp_root("scaffold_uses_custom_templates");
+        let dir = root.join("templates/py");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("manifest.txt"),
+            "variables crate_name display_name examples\nfile solution.py {{ crate_name }}.py\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("solution.py"),
+            "# {{ display_name }}\n{{# examples }}\nEXAMPLE_{{ number }} = \"\"\"{{ input }}\"\"\"\n{{/ examples }}\n",
+        )
+        .unwrap();
+
+        run_command(
+            &root,
+            &Fixtures::default(),
+            &["scaffold", "--year", "2024", "-d", "17", "-l", "py"],
+        )
+        .unwrap();
+
+        assert_eq!(
+            "# 2024 Day 17\nEXAMPLE_1 = \"\"\"\"\"\"\n",
+            fs::read_to_string(root.join("py/y2024_day_17/y2024_day_17.py")).unwrap()
+        );
     }
-}
 
-#[derive(TemplateOnce)]
-#[template(path = "ts/day.test.stpl.ts")]
-struct DayTestTs {}
-
-impl Template for DayTestTs {
-    fn write(self, base: impl AsRef<Path>) -> anyhow::Result<()> {
-        fs::write(base.as_ref().join("day.test.ts"), {
-            let mut output = self.render_once()?;
-            output.push('\n');
-            output
-        })?;
-        Ok(())
+    #[test]
+    fn invalid_templates_stop_fetch() {
+        let root = temp_root("invalid_templates_stop_fetch");
+        let dir = root.join("templates/rs");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("manifest.txt"),
+            "variables crate_name\nfile lib.rs lib.rs\n",
+        )
+        .unwrap();
+        fs::write(dir.join("lib.rs"), "// {{ crate_name }}: {{ answer }}\n").unwrap();
+        let fixtures = Fixtures::default();
+
+        let err = run_command(
+            &root,
+            &fixtures,
+            &["fetch", "--year", "2024", "-d", "1", "-l", "go,rs"],
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            "Invalid rs templates:\n  lib.rs uses 'answer', which manifest.txt does not list",
+            err.to_string()
+        );
+        assert_eq!(Vec::<String>::new(), fixtures.requests());
+        assert!(
+            !root.join("go").exists()
+                && !root.join("rs/y2024_day_01").exists()
+                && !root.join("data").exists(),
+            "Nothing should be written"
+        );
     }
-}
 
-fn recursive_parse_instructions<'a>(
-    url: &'a str,
-    input: &mut Peekable<Chars<'a>>,
-    is_preformatted: bool,
-) -> anyhow::Result<String> {
-    let whole_tag = input.take_while(|char| char.ne(&'>')).collect::<String>();
-
-    let raw_tag = whole_tag
-        .chars()
-        .take_while(|&char| char.ne(&' '))
-        .collect::<String>();
-
-    let mut tag = raw_tag.trim();
-    if let Some(stripped_tag) = tag.strip_prefix('<') {
-        tag = stripped_tag;
-    }
-
-    let mut pre = is_preformatted;
-    let mut output = String::new();
-
-    match tag {
-        "h2" => output.push_str("\n## "),
-        "em" if is_preformatted => output.push_str("<b>"),
-        "code" if is_preformatted => output.push_str("<code>"),
-        "em" => output.push_str("**"),
-        "code" => output.push('`'),
-        "pre" => {
-            output.push_str("\n\n<pre>");
-            pre = true;
-        }
-        "p" => output.push_str("\n\n"),
-        "ul" => output.push('\n'),
-        "li" => output.push_str("  - "),
-        "a" => output.push('['),
-        "span" => {}
-        "" if input.peek().is_none() => {}
-        _ => {
-            anyhow::bail!("unknown tag: {}", tag)
-        }
-    }
-
-    loop {
-        match match input.next() {
-            Some(char) => char,
-            None => return Ok(output),
-        } {
-            '<' => {
-                if input.peek() == Some(&'/') {
-                    let _taken = input.take_while(|char| char.ne(&'>')).collect::<String>();
-                    break;
-                }
-                output.push_str(&recursive_parse_instructions(url, input, pre)?);
-            }
-            '>' => break,
-            char => {
-                output.push(char);
-            }
-        }
+    #[test]
+    fn strict_status_fails_on_missing_crate() {
+        let root = temp_root("strict_status_fails_on_missing_crate");
+        fs::create_dir_all(root.join("data")).unwrap();
+        fs::write(root.join("data/y2024_day_17.enc.txt"), "").unwrap();
+        fs::write(root.join("data/y2023_day_05.enc.txt"), "").unwrap();
+        let fixtures = Fixtures::default();
+
+        run_command(&root, &fixtures, &["status"]).unwrap();
+        let every_year = run_command(&root, &fixtures, &["status", "--strict"]).unwrap_err();
+
+        assert_eq!(
+            "2 problem(s):\n  \
+             2023 day 05 has encrypted data but no rs/y2023_day_05 crate\n  \
+             2024 day 17 has encrypted data but no rs/y2024_day_17 crate",
+            every_year.to_string()
+        );
+
+        let err =
+            run_command(&root, &fixtures, &["status", "--year", "2024", "--strict"]).unwrap_err();
+
+        assert_eq!(
+            "1 problem(s):\n  2024 day 17 has encrypted data but no rs/y2024_day_17 crate",
+            err.to_string(),
+            "--year should only include that year"
+        );
+    }
+
+    #[test]
+    fn leaderboard_is_fetched() {
+        let root = temp_root("leaderboard_is_fetched");
+        let url = format!("{BASE_URL}/2024/leaderboard/private/view/1001.json");
+        let fixtures = Fixtures::default()
+            .with_file(&url, "fixtures/leaderboard.json")
+            .unwrap();
+
+        run_command(
+            &root,
+            &fixtures,
+            &["leaderboard", "--id", "1001", "--year", "2024", "-d", "2"],
+        )
+        .unwrap();
+
+        assert_eq!(vec![format!("GET {url}")], fixtures.requests());
+    }
+
+    #[test]
+    fn correct_answer_is_recorded() {
+        let root = temp_root("correct_answer_is_recorded");
+        let fixtures = Fixtures::default().with_body(
+            &format!("{BASE_URL}/2024/day/18/answer"),
+            "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>",
+        );
+
+        run_command(
+            &root,
+            &fixtures,
+            &["scaffold", "--year", "2024", "-d", "18"],
+        )
+        .unwrap();
+        run_command(
+            &root,
+            &fixtures,
+            &["submit", "--year", "2024", "-d", "18", "-p", "2", "30,12"],
+        )
+        .unwrap();
+        run_command(
+            &root,
+            &fixtures,
+            &["record", "--year", "2024", "-d", "18", "374"],
+        )
+        .unwrap();
+
+        let go = fs::read_to_string(root.join("go/y2024_day_18/main.go")).unwrap();
+        assert!(
+            go.contains("\tpart1Solution = 374\n\tpart2Solution = \"30,12\"\n"),
+            "Answers missing from:\n{go}"
+        );
+        let ts = fs::read_to_string(root.join("ts/y2024_day_18/day.ts")).unwrap();
+        assert!(
+            ts.starts_with(
+                "export const part1Solution = 374;\nexport const part2Solution = \"30,12\";\n"
+            ),
+            "Answers missing from:\n{ts}"
+        );
+        assert_eq!(
+            "2024 18 1 default 374\n2024 18 2 default 30,12\n",
+            fs::read_to_string(root.join("data/answers.txt")).unwrap()
+        );
     }
 
-    match tag {
-        "h2" if output.contains(" --- Day") => {
-            output = output.replace("\n## ", "# [");
-            write!(&mut output, "]({url})")?;
+    mod parse_package_name {
+        use super::*;
+
+        struct Case {
+            name: &'static str,
+            expected: Option<(u32, u32)>,
         }
-        "em" if is_preformatted => output.push_str("</b>"),
-        "code" if is_preformatted => output.push_str("</code>"),
-        "em" => output.push_str("**"),
-        "code" => output.push('`'),
-        "pre" => output.push_str("</pre>"),
-        "a" => {
-            let Some(link) = Regex::new(r#"href="(.+?)""#)?
-                .captures(&whole_tag)
-                .and_then(|caps| caps.get(1).map(|cap| cap.as_str()))
-            else {
-                anyhow::bail!("Failed to find link following anchor")
-            };
-            write!(&mut output, "]({link})")?;
+
+        #[test]
+        fn year_and_day() {
+            assert_eq!("y2024_day_07", package_name(2024, 7));
+            run(&Case {
+                name: "y2024_day_07",
+                expected: Some((2024, 7)),
+            });
         }
-        "p" | "span" | "h2" | "ul" | "li" => {}
-        _ => {
-            anyhow::bail!("unknown tag: {}", tag)
+
+        #[test]
+        fn without_year() {
+            run(&Case {
+                name: "day_07",
+                expected: None,
+            });
         }
-    }
 
-    let mut output = output.trim_end().to_owned();
+        #[test]
+        fn other_crate() {
+            run(&Case {
+                name: "bench_gate",
+                expected: None,
+            });
+        }
 
-    // hack to ensure emphasised code blocks have the correct operation order
-    if output.starts_with('`') && output.contains("**") {
-        output = format!("**{}**", &output.replace("**", ""));
+        fn run(test: &Case) {
+            assert_eq!(test.expected, parse_package_name(test.name));
+        }
     }
 
-    if !is_preformatted {
-        output = output.replace("&gt;", ">");
-        output = output.replace("&lt;", "<");
-    }
+    mod opts {
+        use super::*;
 
-    Ok(output)
-}
+        struct Case {
+            args: &'static [&'static str],
+            expected: Option<clap::error::ErrorKind>,
+        }
 
-fn decrypt_data(key: &[u8]) -> anyhow::Result<()> {
-    let key = Key::<Aes256Gcm>::from_slice(key);
-    let cipher = Aes256Gcm::new(key);
-
-    let dir = PathBuf::from("./data");
-    let dir_iter = fs::read_dir(&dir)?;
-    for file in dir_iter {
-        let file = file?;
-        let file_name = file.file_name();
-        let Some(file_name) = file_name.to_str() else {
-            anyhow::bail!("Invalid file name for decryption: {:?}", file.path());
-        };
-        if !file_name.ends_with(".enc.txt") {
-            continue;
+        #[test]
+        fn flags_of_another_command() {
+            run(&Case {
+                args: &["decrypt", "--no-data"],
+                expected: Some(clap::error::ErrorKind::UnknownArgument),
+            });
         }
 
-        let contents = fs::read_to_string(file.path())?;
+        #[test]
+        fn removed_flags() {
+            run(&Case {
+                args: &["fetch", "--decrypt-data"],
+                expected: Some(clap::error::ErrorKind::UnknownArgument),
+            });
+        }
 
-        let decoded = base64::engine::general_purpose::STANDARD.decode(contents)?;
+        #[test]
+        fn missing_answer() {
+            run(&Case {
+                args: &["submit", "-p", "2"],
+                expected: Some(clap::error::ErrorKind::MissingRequiredArgument),
+            });
+        }
 
-        let (nonce, ciphertext) = decoded.split_at(12);
-        let nonce = nonce.iter().copied().collect::<Nonce<_>>();
+        #[test]
+        fn days_to_decrypt() {
+            run(&Case {
+                args: &["decrypt", "4,16", "25"],
+                expected: None,
+            });
+        }
 
-        let decrypted = cipher.decrypt(&nonce, ciphertext)?;
+        #[test]
+        fn day_out_of_range() {
+            run(&Case {
+                args: &["encrypt", "26"],
+                expected: Some(clap::error::ErrorKind::ValueValidation),
+            });
+        }
 
-        fs::write(dir.join(file_name.replace(".enc.txt", ".txt")), decrypted)?;
+        fn run(test: &Case) {
+            assert_eq!(
+                test.expected,
+                Opts::try_parse_from(core::iter::once(&"fetch_day").chain(test.args))
+                    .err()
+                    .map(|err| err.kind())
+            );
+        }
     }
-
-    Ok(())
 }
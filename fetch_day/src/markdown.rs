@@ -0,0 +1,625 @@
+use regex::Regex;
+
+/// Converts the HTML of a puzzle `<article>` into Markdown, resolving relative links against the
+/// page at `page_url`.
+///
+/// Unknown tags are dropped, keeping their content, so that new markup on the site never stops a
+/// README from being written.
+pub(crate) fn to_markdown(html: &str, page_url: &str) -> String {
+    let nodes = parse(html);
+    let renderer = Renderer { page_url };
+    normalise(&renderer.render(&nodes, Context::default()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Node {
+    /// Text exactly as it appears in the HTML, with its entities still escaped.
+    Text(String),
+    Element(Element),
+}
+
+impl Node {
+    /// The decoded text of the node and everything in it, without any markup.
+    pub(crate) fn text(&self) -> String {
+        match self {
+            Self::Text(text) => decode_entities(text),
+            Self::Element(element) => element.children.iter().map(Self::text).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Element {
+    pub(crate) tag: String,
+    attrs: Vec<(String, String)>,
+    pub(crate) children: Vec<Node>,
+}
+
+impl Element {
+    fn new(tag: &str) -> Self {
+        Self {
+            tag: tag.to_owned(),
+            attrs: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(attr, _)| attr == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Elements that never have content or a closing tag.
+const VOID_TAGS: [&str; 6] = ["br", "hr", "img", "input", "meta", "wbr"];
+
+/// Builds a tree from lenient HTML: closing tags without a matching open tag are ignored, and
+/// elements left open are closed by their parent's closing tag.
+pub(crate) fn parse(html: &str) -> Vec<Node> {
+    let mut stack = vec![Element::new("")];
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        let (text, tag) = rest.split_at(start);
+        push_text(&mut stack, text);
+
+        if let Some(comment) = tag.strip_prefix("<!--") {
+            rest = comment
+                .find("-->")
+                .and_then(|end| comment.get(end + 3..))
+                .unwrap_or_default();
+            continue;
+        }
+
+        let starts_tag = tag
+            .chars()
+            .nth(1)
+            .is_some_and(|char| char.is_ascii_alphabetic() || matches!(char, '/' | '!' | '?'));
+        if !starts_tag {
+            // A stray `<` that does not start a tag.
+            push_text(&mut stack, "<");
+            rest = tag.get(1..).unwrap_or_default();
+            continue;
+        }
+
+        let Some(end) = tag_end(tag) else {
+            push_text(&mut stack, tag);
+            rest = "";
+            break;
+        };
+        let (tag, after) = tag.split_at(end + 1);
+        rest = after;
+
+        let inner = tag.trim_start_matches('<').trim_end_matches('>');
+        match inner.strip_prefix('/') {
+            Some(name) => close(&mut stack, &name.trim().to_lowercase()),
+            None if inner.starts_with(|char: char| char.is_ascii_alphabetic()) => {
+                let element = open(inner);
+                if VOID_TAGS.contains(&element.tag.as_str()) || inner.ends_with('/') {
+                    append(&mut stack, Node::Element(element));
+                } else {
+                    stack.push(element);
+                }
+            }
+            // Declarations such as `<!DOCTYPE html>` have no content.
+            None => {}
+        }
+    }
+    push_text(&mut stack, rest);
+
+    while stack.len() > 1 {
+        close_top(&mut stack);
+    }
+    stack.pop().map(|root| root.children).unwrap_or_default()
+}
+
+/// The index of the `>` that ends the tag at the start of `tag`, skipping any inside quotes.
+fn tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (idx, char) in tag.char_indices() {
+        match (quote, char) {
+            (None, '"' | '\'') => quote = Some(char),
+            (Some(open), _) if open == char => quote = None,
+            (None, '>') => return Some(idx),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn open(inner: &str) -> Element {
+    let inner = inner.trim_end_matches('/');
+    let name_end = inner
+        .find(|char: char| char.is_whitespace())
+        .unwrap_or(inner.len());
+    let (name, attrs) = inner.split_at(name_end);
+
+    let mut element = Element::new(&name.to_lowercase());
+    if let Ok(attr) =
+        Regex::new(r#"([A-Za-z_:][-A-Za-z0-9_:.]*)(?:\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+)))?"#)
+    {
+        element.attrs = attr
+            .captures_iter(attrs)
+            .filter_map(|caps| {
+                let attr_name = caps.get(1)?.as_str().to_lowercase();
+                let value = caps
+                    .get(2)
+                    .or_else(|| caps.get(3))
+                    .or_else(|| caps.get(4))
+                    .map_or_else(String::new, |value| decode_entities(value.as_str()));
+                Some((attr_name, value))
+            })
+            .collect();
+    }
+    element
+}
+
+fn push_text(stack: &mut [Element], text: &str) {
+    if !text.is_empty() {
+        append(stack, Node::Text(text.to_owned()));
+    }
+}
+
+fn append(stack: &mut [Element], node: Node) {
+    if let Some(parent) = stack.last_mut() {
+        parent.children.push(node);
+    }
+}
+
+fn close(stack: &mut Vec<Element>, tag: &str) {
+    let Some(depth) = stack.iter().skip(1).rposition(|element| element.tag == tag) else {
+        return;
+    };
+    while stack.len() > depth + 1 {
+        close_top(stack);
+    }
+}
+
+fn close_top(stack: &mut Vec<Element>) {
+    if let Some(element) = stack.pop() {
+        append(stack, Node::Element(element));
+    }
+}
+
+/// Replaces the named and numeric character references the site uses.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        let (before, entity) = rest.split_at(start);
+        decoded.push_str(before);
+
+        let replacement = entity
+            .get(1..)
+            .and_then(|entity| entity.split_once(';'))
+            .filter(|(name, _)| name.len() <= 8)
+            .and_then(|(name, after)| Some((decode_entity(name)?, after)));
+        if let Some((char, after)) = replacement {
+            decoded.push(char);
+            rest = after;
+        } else {
+            decoded.push('&');
+            rest = entity.get(1..).unwrap_or_default();
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Resolves `href` the way a browser on `page_url` would.
+fn resolve_link(page_url: &str, href: &str) -> String {
+    if href.contains("://") || href.starts_with("mailto:") {
+        return href.to_owned();
+    }
+    if let Some(authority) = href.strip_prefix("//") {
+        return format!("https://{authority}");
+    }
+    if href.starts_with('#') {
+        return format!("{page_url}{href}");
+    }
+
+    let host_start = page_url.find("://").map_or(0, |scheme| scheme + 3);
+    if href.starts_with('/') {
+        let origin_end = page_url
+            .get(host_start..)
+            .and_then(|host| host.find('/'))
+            .map_or(page_url.len(), |path| host_start + path);
+        return format!("{}{href}", page_url.get(..origin_end).unwrap_or(page_url));
+    }
+
+    let dir_end = page_url
+        .rfind('/')
+        .filter(|&slash| slash >= host_start)
+        .map_or(page_url.len(), |slash| slash + 1);
+    let dir = page_url.get(..dir_end).unwrap_or(page_url);
+    if dir.ends_with('/') {
+        format!("{dir}{href}")
+    } else {
+        format!("{dir}/{href}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Context {
+    /// Inside `<pre>`, where the output stays HTML.
+    preformatted: bool,
+    /// How many lists the current node is nested in.
+    list_depth: usize,
+}
+
+struct Renderer<'a> {
+    page_url: &'a str,
+}
+
+impl Renderer<'_> {
+    fn render(&self, nodes: &[Node], context: Context) -> String {
+        nodes
+            .iter()
+            .map(|node| match node {
+                Node::Text(text) if context.preformatted => text.clone(),
+                Node::Text(text) => decode_entities(text),
+                Node::Element(element) if context.preformatted => {
+                    self.render_preformatted(element, context)
+                }
+                Node::Element(element) => self.render_element(element, context),
+            })
+            .collect()
+    }
+
+    /// Keeps the emphasis inside `<pre>` as HTML, since Markdown does not apply there.
+    fn render_preformatted(&self, element: &Element, context: Context) -> String {
+        let content = self.render(&element.children, context);
+        match element.tag.as_str() {
+            "em" | "strong" | "b" => format!("<b>{content}</b>"),
+            "code" | "s" | "sup" | "sub" => format!("<{0}>{content}</{0}>", element.tag),
+            "br" => String::from("\n"),
+            _ => content,
+        }
+    }
+
+    fn render_element(&self, element: &Element, context: Context) -> String {
+        let content = || self.render(&element.children, context);
+
+        match element.tag.as_str() {
+            "script" | "style" | "head" | "title" => String::new(),
+            "pre" => {
+                let preformatted = Context {
+                    preformatted: true,
+                    ..context
+                };
+                format!(
+                    "\n\n<pre>{}</pre>\n\n",
+                    self.render(&element.children, preformatted)
+                )
+            }
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => self.heading(element, &content()),
+            "p" | "div" | "article" | "section" | "blockquote" => format!("\n\n{}\n\n", content()),
+            "ul" | "ol" => self.list(element, context),
+            "li" => format!("\n{}", content().trim()),
+            "em" | "strong" | "b" | "i" => wrap("**", &content()),
+            "s" | "del" | "strike" => wrap("~~", &content()),
+            "code" => code(&content()),
+            "sup" | "sub" => format!("<{0}>{1}</{0}>", element.tag, content()),
+            "br" => String::from("<br>"),
+            "hr" => String::from("\n\n---\n\n"),
+            "a" => element.attr("href").map_or_else(content, |href| {
+                format!("[{}]({})", content(), resolve_link(self.page_url, href))
+            }),
+            "span" => element.attr("title").map_or_else(content, |title| {
+                format!(
+                    "<span title=\"{}\">{}</span>",
+                    title.replace('&', "&amp;").replace('"', "&quot;"),
+                    content()
+                )
+            }),
+            _ => content(),
+        }
+    }
+
+    /// The day's title links back to the puzzle; any other heading is kept at its level.
+    fn heading(&self, element: &Element, content: &str) -> String {
+        let content = content.trim();
+        if content.starts_with("--- Day ") {
+            return format!("\n\n# [{content}]({})\n\n", self.page_url);
+        }
+
+        let level = element
+            .tag
+            .strip_prefix('h')
+            .and_then(|level| level.parse().ok())
+            .unwrap_or(2);
+        format!("\n\n{} {content}\n\n", "#".repeat(level))
+    }
+
+    fn list(&self, element: &Element, context: Context) -> String {
+        let nested = Context {
+            list_depth: context.list_depth + 1,
+            ..context
+        };
+        let indent = "  ".repeat(nested.list_depth);
+
+        let mut number = element
+            .attr("start")
+            .and_then(|start| start.parse::<usize>().ok())
+            .unwrap_or(1);
+        let mut items = Vec::new();
+        for child in &element.children {
+            let item = match child {
+                Node::Element(item) if item.tag == "li" => item,
+                Node::Text(text) if text.trim().is_empty() => continue,
+                Node::Text(_) | Node::Element(_) => {
+                    items.push(self.render(core::slice::from_ref(child), nested));
+                    continue;
+                }
+            };
+
+            let marker = if element.tag == "ol" {
+                number += 1;
+                format!("{}.", number - 1)
+            } else {
+                String::from("-")
+            };
+            // Blank lines would end the list, so an item's blocks are kept on adjacent lines.
+            let content = self.render(&item.children, nested);
+            let content = content
+                .lines()
+                .map(str::trim_end)
+                .filter(|line| !line.trim().is_empty())
+                .collect::<Vec<_>>()
+                .join("\n");
+            items.push(format!("{indent}{marker} {}", content.trim_start()));
+        }
+
+        if context.list_depth == 0 {
+            format!("\n\n{}\n\n", items.join("\n"))
+        } else {
+            format!("\n{}", items.join("\n"))
+        }
+    }
+}
+
+/// Wraps inline content in a Markdown delimiter, leaving surrounding whitespace outside it.
+fn wrap(delimiter: &str, content: &str) -> String {
+    let trimmed = content.trim();
+    if trimmed.is_empty() {
+        return content.to_owned();
+    }
+
+    let leading = content.len() - content.trim_start().len();
+    let trailing = content.trim_end().len();
+    format!(
+        "{}{delimiter}{trimmed}{delimiter}{}",
+        content.get(..leading).unwrap_or_default(),
+        content.get(trailing..).unwrap_or_default()
+    )
+}
+
+/// Inline code, hoisting any emphasis inside it outside the backticks where Markdown applies it.
+fn code(content: &str) -> String {
+    let emphasised = content.contains("**");
+    let content = content.replace("**", "");
+
+    let fence = if content.contains('`') { "``" } else { "`" };
+    let padding = if content.starts_with('`') || content.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+    let code = format!("{fence}{padding}{content}{padding}{fence}");
+
+    if emphasised {
+        format!("**{code}**")
+    } else {
+        code
+    }
+}
+
+/// Trims trailing whitespace and collapses blank lines, except inside `<pre>` blocks.
+fn normalise(markdown: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    let mut preformatted = false;
+
+    for line in markdown.lines() {
+        if preformatted {
+            lines.push(line);
+        } else {
+            let line = line.trim_end();
+            let previous_blank = lines.last().is_none_or(|previous| previous.is_empty());
+            if !(line.is_empty() && previous_blank) {
+                lines.push(line);
+            }
+        }
+
+        if line.contains("<pre>") {
+            preformatted = true;
+        }
+        if line.contains("</pre>") {
+            preformatted = false;
+        }
+    }
+
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        let _blank = lines.pop();
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod markdown_tests {
+    use super::*;
+
+    const DAY_URL: &str = "https://adventofcode.com/2024/day/7";
+
+    mod to_markdown {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn title_and_paragraphs() {
+            run(&Case {
+                input: "<h2>--- Day 7: Bridge Repair ---</h2><p>The Historians take you to a familiar <a href=\"/2022/day/9\">rope bridge</a>.</p>\n<p>For example:</p>",
+                expected: "# [--- Day 7: Bridge Repair ---](https://adventofcode.com/2024/day/7)
+
+The Historians take you to a familiar [rope bridge](https://adventofcode.com/2022/day/9).
+
+For example:",
+            });
+        }
+
+        #[test]
+        fn part_two_heading() {
+            run(&Case {
+                input: "<h2 id=\"part2\">--- Part Two ---</h2><p>The engineers seem concerned.</p>",
+                expected: "## --- Part Two ---\n\nThe engineers seem concerned.",
+            });
+        }
+
+        #[test]
+        fn entities() {
+            run(&Case {
+                input: "<p>&quot;Fish &amp; chips&quot; &lt;3 &gt; &#39;nothing&#x27; &unknown; &amp</p>",
+                expected: "\"Fish & chips\" <3 > 'nothing' &unknown; &amp",
+            });
+        }
+
+        #[test]
+        fn preformatted() {
+            run(&Case {
+                input: "<pre><code>....#<em>X</em>\n\n&lt;-&gt;  \n</code></pre>",
+                expected: "<pre><code>....#<b>X</b>\n\n&lt;-&gt;  \n</code></pre>",
+            });
+        }
+
+        #[test]
+        fn emphasised_code() {
+            run(&Case {
+                input: "<p>a total of <code><em>11</em></code> and <em><code>3</code></em>, or <code>a`b</code></p>",
+                expected: "a total of **`11`** and **`3`**, or ``a`b``",
+            });
+        }
+
+        #[test]
+        fn inline_tags() {
+            run(&Case {
+                input: "<p>It's <s>lost</s> found<br/>on 10<sup>th</sup> <span title=\"Or &quot;maybe&quot; not.\">Tuesday</span> in <span class=\"x\">May</span>.</p>",
+                expected: "It's ~~lost~~ found<br>on 10<sup>th</sup> <span title=\"Or &quot;maybe&quot; not.\">Tuesday</span> in May.",
+            });
+        }
+
+        #[test]
+        fn emphasis_keeps_surrounding_spaces_outside() {
+            run(&Case {
+                input: "<p>is<em> safe </em>now</p>",
+                expected: "is **safe** now",
+            });
+        }
+
+        #[test]
+        fn nested_lists() {
+            run(&Case {
+                input: "<p>Rules:</p>\n<ul>\n<li>First\n<ol>\n<li>one</li>\n<li>two<ul><li>deep</li></ul></li>\n</ol>\n</li>\n<li>Second</li>\n</ul>\n<p>After.</p>",
+                expected: "Rules:
+
+  - First
+    1. one
+    2. two
+      - deep
+  - Second
+
+After.",
+            });
+        }
+
+        #[test]
+        fn relative_links() {
+            run(&Case {
+                input: "<p><a href=\"4\">device</a>, <a href=\"#part2\">above</a>, <a href=\"https://en.wikipedia.org/wiki/Bridge\" target=\"_blank\">bridge</a>, <a>nowhere</a></p>",
+                expected: "[device](https://adventofcode.com/2024/day/4), [above](https://adventofcode.com/2024/day/7#part2), [bridge](https://en.wikipedia.org/wiki/Bridge), nowhere",
+            });
+        }
+
+        #[test]
+        fn unknown_tags_keep_content() {
+            run(&Case {
+                input: "<p><blink>Flashing</blink> <i>and</i> <!-- hidden --><script>alert(1)</script>unclosed <em>emphasis</p><p>Stray</span> close &amp; a < b</p>",
+                expected: "Flashing **and** unclosed **emphasis**\n\nStray close & a < b",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, to_markdown(test.input, DAY_URL));
+        }
+    }
+
+    mod resolve_link {
+        use super::*;
+
+        struct Case {
+            href: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn root_relative() {
+            run(&Case {
+                href: "/2015/day/19",
+                expected: "https://adventofcode.com/2015/day/19",
+            });
+        }
+
+        #[test]
+        fn sibling() {
+            run(&Case {
+                href: "7/input",
+                expected: "https://adventofcode.com/2024/day/7/input",
+            });
+        }
+
+        #[test]
+        fn protocol_relative() {
+            run(&Case {
+                href: "//example.com/x",
+                expected: "https://example.com/x",
+            });
+        }
+
+        #[test]
+        fn absolute() {
+            run(&Case {
+                href: "http://example.com",
+                expected: "http://example.com",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, resolve_link(DAY_URL, test.href));
+        }
+    }
+}
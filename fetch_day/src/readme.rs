@@ -0,0 +1,146 @@
+use core::fmt;
+
+/// Opens the part of a README that `fetch_day` generated from the instructions. Anything outside
+/// the markers is left alone when the README is refreshed.
+const START_MARKER: &str = "<!-- fetch_day: instructions start -->";
+
+/// Closes the part of a README generated from the instructions.
+const END_MARKER: &str = "<!-- fetch_day: instructions end -->";
+
+/// What refreshing a README did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Refresh {
+    Created,
+    AddedPart2,
+    Updated,
+    Unchanged,
+}
+
+impl fmt::Display for Refresh {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Created => "created",
+            Self::AddedPart2 => "added part 2",
+            Self::Updated => "updated",
+            Self::Unchanged => "unchanged",
+        })
+    }
+}
+
+/// Whether a README includes the instructions for part 2.
+pub(crate) fn has_part_2(readme: &str) -> bool {
+    readme.contains("--- Part Two ---")
+}
+
+/// The README with its generated part replaced by `instructions`, and what changed.
+///
+/// A README without markers was written before they were added, so all of it was generated and
+/// all of it is replaced.
+pub(crate) fn refresh(existing: Option<&str>, instructions: &str) -> (String, Refresh) {
+    let generated = format!("{START_MARKER}\n{}\n{END_MARKER}", instructions.trim_end());
+    let Some(existing) = existing else {
+        return (format!("{generated}\n"), Refresh::Created);
+    };
+
+    let (before, previous, after) = split(existing).unwrap_or(("", existing, "\n"));
+    let refreshed = format!("{before}{generated}{after}");
+
+    let outcome = if refreshed == existing {
+        Refresh::Unchanged
+    } else if has_part_2(instructions) && !has_part_2(previous) {
+        Refresh::AddedPart2
+    } else {
+        Refresh::Updated
+    };
+    (refreshed, outcome)
+}
+
+/// The README before the start marker, between the markers, and after the end marker.
+fn split(readme: &str) -> Option<(&str, &str, &str)> {
+    let (before, rest) = readme.split_once(START_MARKER)?;
+    let (generated, after) = rest.split_once(END_MARKER)?;
+    Some((before, generated, after))
+}
+
+#[cfg(test)]
+mod readme_tests {
+    use super::*;
+
+    mod refresh {
+        use super::*;
+
+        const PART_1: &str = "# --- Day 1 ---\n\nPart 1.\n";
+        const PART_2: &str = "# --- Day 1 ---\n\nPart 1.\n\n# --- Part Two ---\n\nPart 2.\n";
+
+        struct Case {
+            existing: Option<&'static str>,
+            instructions: &'static str,
+            expected: (&'static str, Refresh),
+        }
+
+        #[test]
+        fn created() {
+            run(&Case {
+                existing: None,
+                instructions: PART_1,
+                expected: (
+                    "<!-- fetch_day: instructions start -->\n# --- Day 1 ---\n\nPart 1.\n<!-- fetch_day: instructions end -->\n",
+                    Refresh::Created,
+                ),
+            });
+        }
+
+        #[test]
+        fn keeps_notes_and_adds_part_2() {
+            run(&Case {
+                existing: Some("My notes.\n\n<!-- fetch_day: instructions start -->\n# --- Day 1 ---\n\nPart 1.\n<!-- fetch_day: instructions end -->\n\n## Approach\n\nA hash map.\n"),
+                instructions: PART_2,
+                expected: (
+                    "My notes.\n\n<!-- fetch_day: instructions start -->\n# --- Day 1 ---\n\nPart 1.\n\n# --- Part Two ---\n\nPart 2.\n<!-- fetch_day: instructions end -->\n\n## Approach\n\nA hash map.\n",
+                    Refresh::AddedPart2,
+                ),
+            });
+        }
+
+        #[test]
+        fn unchanged() {
+            run(&Case {
+                existing: Some("<!-- fetch_day: instructions start -->\n# --- Day 1 ---\n\nPart 1.\n<!-- fetch_day: instructions end -->\n\nNotes.\n"),
+                instructions: PART_1,
+                expected: (
+                    "<!-- fetch_day: instructions start -->\n# --- Day 1 ---\n\nPart 1.\n<!-- fetch_day: instructions end -->\n\nNotes.\n",
+                    Refresh::Unchanged,
+                ),
+            });
+        }
+
+        #[test]
+        fn updated() {
+            run(&Case {
+                existing: Some("<!-- fetch_day: instructions start -->\n# --- Day 1 ---\n\nPart 1.\n\n# --- Part Two ---\n\nOld part 2.\n<!-- fetch_day: instructions end -->\n"),
+                instructions: PART_2,
+                expected: (
+                    "<!-- fetch_day: instructions start -->\n# --- Day 1 ---\n\nPart 1.\n\n# --- Part Two ---\n\nPart 2.\n<!-- fetch_day: instructions end -->\n",
+                    Refresh::Updated,
+                ),
+            });
+        }
+
+        #[test]
+        fn without_markers() {
+            run(&Case {
+                existing: Some(PART_1),
+                instructions: PART_2,
+                expected: (
+                    "<!-- fetch_day: instructions start -->\n# --- Day 1 ---\n\nPart 1.\n\n# --- Part Two ---\n\nPart 2.\n<!-- fetch_day: instructions end -->\n",
+                    Refresh::AddedPart2,
+                ),
+            });
+        }
+
+        fn run(test: &Case) {
+            let (readme, outcome) = refresh(test.existing, test.instructions);
+            assert_eq!(test.expected, (readme.as_str(), outcome));
+        }
+    }
+}
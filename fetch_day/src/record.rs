@@ -0,0 +1,422 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+use crate::examples;
+
+/// Where the Rust solutions' known answers are kept, relative to the repository root.
+pub(crate) const ANSWERS_FILE: &str = "data/answers.txt";
+
+/// Writes a confirmed `answer` into every language a day was scaffolded in: the
+/// `partNSolution` constants of the Go and TypeScript solutions, and the answers registry for
+/// the Rust solution, along with its `PART_N` constant if it still has one. Returns the files
+/// that were changed.
+pub(crate) fn record_answer(
+    root: &Path,
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let package_name = crate::package_name(year, day);
+    let constant = format!("part{part}Solution");
+    let mut changed = Vec::new();
+
+    for file in [
+        root.join("go").join(&package_name).join("main.go"),
+        root.join("ts").join(&package_name).join("day.ts"),
+    ] {
+        if !file.exists() {
+            continue;
+        }
+        let source = fs::read_to_string(&file)?;
+        let Some(updated) = set_constant(&source, &constant, &literal(answer)) else {
+            anyhow::bail!("No '{constant}' constant found in {}", file.display());
+        };
+        if updated != source {
+            fs::write(&file, updated)?;
+            changed.push(file);
+        }
+    }
+
+    let rs_dir = root.join("rs").join(&package_name);
+    let lib_file = rs_dir.join("src").join("lib.rs");
+    if lib_file.exists() {
+        let source = fs::read_to_string(&lib_file)?;
+        if let Some(updated) = set_rust_constant(&source, part, answer) {
+            if updated != source {
+                fs::write(&lib_file, updated)?;
+                changed.push(lib_file);
+            }
+        }
+    }
+
+    if rs_dir.exists() {
+        let file = root.join(ANSWERS_FILE);
+        let registry = if file.exists() {
+            fs::read_to_string(&file)?
+        } else {
+            String::new()
+        };
+        let updated = set_registry_answer(&registry, year, day, part, answer);
+        if updated != registry {
+            if let Some(dir) = file.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&file, updated)?;
+            changed.push(file);
+        }
+    }
+
+    Ok(changed)
+}
+
+/// Writes `answer` as a Go or TypeScript literal: numbers as they are and anything else as a
+/// string.
+fn literal(answer: &str) -> String {
+    if !answer.is_empty() && answer.bytes().all(|byte| byte.is_ascii_digit()) {
+        answer.to_owned()
+    } else {
+        format!("{answer:?}")
+    }
+}
+
+/// Replaces the value of the constant `name`, keeping the rest of its line as it was, such as
+/// `\tpart1Solution = 0` in Go or `export const part1Solution = 0;` in TypeScript. `None` if
+/// there is no such constant.
+fn set_constant(source: &str, name: &str, literal: &str) -> Option<String> {
+    let pattern = Regex::new(&format!(
+        r"(?m)^(?P<head>[ \t]*(?:export\s+)?(?:const\s+)?{}\s*=\s*)[^;\n]*?(?P<tail>;?[ \t]*)$",
+        regex::escape(name)
+    ))
+    .ok()?;
+    let captures = pattern.captures(source)?;
+    let (line, head, tail) = (
+        captures.get(0)?,
+        captures.name("head")?,
+        captures.name("tail")?,
+    );
+
+    Some(format!(
+        "{}{}{literal}{}{}",
+        source.get(..line.start())?,
+        head.as_str(),
+        tail.as_str(),
+        source.get(line.end()..)?
+    ))
+}
+
+/// Replaces the value of `pub const PART_N`, written with its digits grouped, or as a `&str` if
+/// the answer is not a number. `None` if there is no such constant.
+fn set_rust_constant(source: &str, part: u8, answer: &str) -> Option<String> {
+    let pattern = Regex::new(&format!(
+        r"(?m)^(?P<head>[ \t]*pub const PART_{part}: )(?P<ty>[^=]+?) = [^;\n]*;"
+    ))
+    .ok()?;
+    let captures = pattern.captures(source)?;
+    let (line, head, ty) = (
+        captures.get(0)?,
+        captures.name("head")?,
+        captures.name("ty")?,
+    );
+
+    let (ty, value) = match examples::rust_number(answer) {
+        // Keep any other numeric type the constant was declared with.
+        Some(number) if ty.as_str() == "&str" => (String::from("usize"), number),
+        Some(number) => (ty.as_str().to_owned(), number),
+        None => (String::from("&str"), format!("{answer:?}")),
+    };
+
+    Some(format!(
+        "{}{}{ty} = {value};{}",
+        source.get(..line.start())?,
+        head.as_str(),
+        source.get(line.end()..)?
+    ))
+}
+
+/// Sets the answer to `part` of `day` of `year` for the default input in the answers registry,
+/// replacing the existing answer or adding a line in order of year, day and part.
+fn set_registry_answer(registry: &str, year: u32, day: u32, part: u8, answer: &str) -> String {
+    let key_of = |line: &str| {
+        let mut fields = line.split_whitespace();
+        let line_year = fields.next()?.parse::<u32>().ok()?;
+        let line_day = fields.next()?.parse::<u32>().ok()?;
+        let line_part = fields.next()?.parse::<u8>().ok()?;
+        let input = fields.next()?;
+        Some((line_year, line_day, line_part, input == "default"))
+    };
+
+    let mut lines = registry.lines().map(str::to_owned).collect::<Vec<_>>();
+    let line = format!("{year} {day:02} {part} default {answer}");
+
+    let existing = lines.iter().position(|other| {
+        !other.starts_with('#') && key_of(other) == Some((year, day, part, true))
+    });
+    if let Some(index) = existing {
+        if let Some(replaced) = lines.get_mut(index) {
+            *replaced = line;
+        }
+    } else {
+        let index = lines
+            .iter()
+            .rposition(|other| {
+                !other.starts_with('#')
+                    && key_of(other).is_some_and(|(line_year, line_day, line_part, _)| {
+                        (line_year, line_day, line_part) <= (year, day, part)
+                    })
+            })
+            .map(|index| index + 1)
+            .or_else(|| lines.iter().position(|other| key_of(other).is_some()))
+            .unwrap_or(lines.len());
+        lines.insert(index, line);
+    }
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    updated
+}
+
+#[cfg(test)]
+mod record_tests {
+    use super::*;
+
+    mod set_constant {
+        use super::*;
+
+        struct Case<'c> {
+            source: &'c str,
+            name: &'c str,
+            literal: &'c str,
+            expected: Option<&'c str>,
+        }
+
+        #[test]
+        fn go() {
+            run(&Case {
+                source: "const (\n\tpart1Solution = 0\n\tpart2Solution = 0\n)\n",
+                name: "part2Solution",
+                literal: "1921",
+                expected: Some("const (\n\tpart1Solution = 0\n\tpart2Solution = 1921\n)\n"),
+            });
+        }
+
+        #[test]
+        fn typescript() {
+            run(&Case {
+                source: "export const part1Solution = 0;\nexport const part2Solution = 0;\n",
+                name: "part1Solution",
+                literal: "\"30,12\"",
+                expected: Some(
+                    "export const part1Solution = \"30,12\";\nexport const part2Solution = 0;\n",
+                ),
+            });
+        }
+
+        #[test]
+        fn replaces_earlier_answer() {
+            run(&Case {
+                source: "const part1Solution = 2530\n",
+                name: "part1Solution",
+                literal: "2578",
+                expected: Some("const part1Solution = 2578\n"),
+            });
+        }
+
+        #[test]
+        fn missing() {
+            run(&Case {
+                source: "const answer = 0\n",
+                name: "part1Solution",
+                literal: "1",
+                expected: None,
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(
+                test.expected.map(str::to_owned),
+                set_constant(test.source, test.name, test.literal)
+            );
+        }
+    }
+
+    mod set_rust_constant {
+        use super::*;
+
+        struct Case<'c> {
+            source: &'c str,
+            part: u8,
+            answer: &'c str,
+            expected: Option<&'c str>,
+        }
+
+        #[test]
+        fn grouped_number() {
+            run(&Case {
+                source: "pub const PART_1: usize = 0;\npub const PART_2: usize = 0;\n",
+                part: 1,
+                answer: "2264607",
+                expected: Some(
+                    "pub const PART_1: usize = 2_264_607;\npub const PART_2: usize = 0;\n",
+                ),
+            });
+        }
+
+        #[test]
+        fn keeps_numeric_type() {
+            run(&Case {
+                source: "pub const PART_2: u64 = 0;\n",
+                part: 2,
+                answer: "110365987435001",
+                expected: Some("pub const PART_2: u64 = 110_365_987_435_001;\n"),
+            });
+        }
+
+        #[test]
+        fn text() {
+            run(&Case {
+                source: "pub const PART_2: usize = 0;\n",
+                part: 2,
+                answer: "30,12",
+                expected: Some("pub const PART_2: &str = \"30,12\";\n"),
+            });
+        }
+
+        #[test]
+        fn number_after_text() {
+            run(&Case {
+                source: "pub const PART_1: &str = \"\";\n",
+                part: 1,
+                answer: "374",
+                expected: Some("pub const PART_1: usize = 374;\n"),
+            });
+        }
+
+        #[test]
+        fn missing() {
+            run(&Case {
+                source: "pub const DAY: u8 = 1;\n",
+                part: 1,
+                answer: "374",
+                expected: None,
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(
+                test.expected.map(str::to_owned),
+                set_rust_constant(test.source, test.part, test.answer)
+            );
+        }
+    }
+
+    mod set_registry_answer {
+        use super::*;
+
+        const REGISTRY: &str = "# <year> <day> <part> <input> <answer>\n\n2024 01 1 default 11\n2024 01 2 default 31\n2024 03 1 default 161\n";
+
+        struct Case<'c> {
+            registry: &'c str,
+            year: u32,
+            day: u32,
+            part: u8,
+            answer: &'c str,
+            expected: &'c str,
+        }
+
+        #[test]
+        fn replaces() {
+            run(&Case {
+                registry: REGISTRY,
+                year: 2024,
+                day: 1,
+                part: 2,
+                answer: "32",
+                expected: "# <year> <day> <part> <input> <answer>\n\n2024 01 1 default 11\n2024 01 2 default 32\n2024 03 1 default 161\n",
+            });
+        }
+
+        #[test]
+        fn inserts_in_order() {
+            run(&Case {
+                registry: REGISTRY,
+                year: 2024,
+                day: 2,
+                part: 1,
+                answer: "2",
+                expected: "# <year> <day> <part> <input> <answer>\n\n2024 01 1 default 11\n2024 01 2 default 31\n2024 02 1 default 2\n2024 03 1 default 161\n",
+            });
+        }
+
+        #[test]
+        fn inserts_first() {
+            run(&Case {
+                registry: "# <year> <day> <part> <input> <answer>\n\n2024 03 1 default 161\n",
+                year: 2024,
+                day: 1,
+                part: 1,
+                answer: "11",
+                expected: "# <year> <day> <part> <input> <answer>\n\n2024 01 1 default 11\n2024 03 1 default 161\n",
+            });
+        }
+
+        #[test]
+        fn text_answer() {
+            run(&Case {
+                registry: REGISTRY,
+                year: 2024,
+                day: 18,
+                part: 2,
+                answer: "30,12",
+                expected: "# <year> <day> <part> <input> <answer>\n\n2024 01 1 default 11\n2024 01 2 default 31\n2024 03 1 default 161\n2024 18 2 default 30,12\n",
+            });
+        }
+
+        #[test]
+        fn keeps_named_inputs() {
+            run(&Case {
+                registry: "2024 01 1 alice 5\n",
+                year: 2024,
+                day: 1,
+                part: 1,
+                answer: "11",
+                expected: "2024 01 1 alice 5\n2024 01 1 default 11\n",
+            });
+        }
+
+        #[test]
+        fn keeps_other_years() {
+            run(&Case {
+                registry: "2024 01 1 default 2264607\n",
+                year: 2023,
+                day: 1,
+                part: 1,
+                answer: "999",
+                expected: "2023 01 1 default 999\n2024 01 1 default 2264607\n",
+            });
+        }
+
+        #[test]
+        fn empty() {
+            run(&Case {
+                registry: "",
+                year: 2024,
+                day: 1,
+                part: 1,
+                answer: "11",
+                expected: "2024 01 1 default 11\n",
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(
+                test.expected,
+                set_registry_answer(test.registry, test.year, test.day, test.part, test.answer)
+            );
+        }
+    }
+}
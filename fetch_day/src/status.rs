@@ -0,0 +1,354 @@
+use core::fmt::Write as _;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+
+use crate::{readme, record::ANSWERS_FILE};
+
+/// How far a day's instructions have been downloaded into its READMEs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Readme {
+    #[default]
+    Missing,
+    Part1,
+    Part2,
+}
+
+/// What the workspace has of one day.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct DayStatus {
+    pub(crate) year: u32,
+    pub(crate) day: u32,
+    /// The languages with a `<lang>/yYYYY_day_XX` directory.
+    pub(crate) langs: BTreeSet<String>,
+    pub(crate) plain_data: bool,
+    pub(crate) encrypted_data: bool,
+    /// The furthest part found in any of the day's READMEs.
+    pub(crate) readme: Readme,
+    /// The parts with a known answer for the default input of this year's day.
+    pub(crate) answers: BTreeSet<u8>,
+}
+
+impl DayStatus {
+    /// Whatever is out of step between the day's crate, data and answers.
+    pub(crate) fn problems(&self) -> Vec<String> {
+        let (year, day) = (self.year, self.day);
+        let has_crate = self.langs.contains("rs");
+        let mut problems = Vec::new();
+
+        if self.encrypted_data && !has_crate {
+            problems.push(format!(
+                "{year} day {day:02} has encrypted data but no rs/{} crate",
+                crate::package_name(year, day)
+            ));
+        }
+        if has_crate && !self.encrypted_data {
+            problems.push(format!(
+                "{year} day {day:02} has a crate but no encrypted data to commit"
+            ));
+        }
+        if self.plain_data && !self.encrypted_data {
+            problems.push(format!(
+                "{year} day {day:02} has data that was never encrypted"
+            ));
+        }
+        if !self.answers.is_empty() && !has_crate {
+            problems.push(format!(
+                "{year} day {day:02} has known answers but no crate to check them"
+            ));
+        }
+        problems
+    }
+}
+
+/// Every year with a day in one of `langs` or in `data`, whether as a `yYYYY_day_XX` directory,
+/// its data or its encrypted data.
+pub(crate) fn years(root: &Path, langs: &BTreeSet<String>) -> anyhow::Result<BTreeSet<u32>> {
+    let mut years = BTreeSet::new();
+    for dir in langs.iter().map(String::as_str).chain(["data"]) {
+        let dir = root.join(dir);
+        if !dir.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(dir)? {
+            let name = entry?.file_name();
+            let name = name.to_string_lossy();
+            let package_name = name
+                .strip_suffix(".enc.txt")
+                .or_else(|| name.strip_suffix(".txt"))
+                .unwrap_or(&name);
+            if let Some((year, _day)) = crate::parse_package_name(package_name) {
+                let _new = years.insert(year);
+            }
+        }
+    }
+    Ok(years)
+}
+
+/// What each of the 25 days of `year` has in `langs`, in `data` and in the answers registry, including
+/// the days that have nothing at all.
+pub(crate) fn scan(
+    root: &Path,
+    year: u32,
+    langs: &BTreeSet<String>,
+) -> anyhow::Result<Vec<DayStatus>> {
+    let answers = known_answers(root, year)?;
+    let data_dir = root.join("data");
+
+    let mut days = Vec::new();
+    for day in 1..=25 {
+        let package_name = crate::package_name(year, day);
+        let mut status = DayStatus {
+            year,
+            day,
+            plain_data: data_dir.join(format!("{package_name}.txt")).exists(),
+            encrypted_data: data_dir.join(format!("{package_name}.enc.txt")).exists(),
+            answers: answers.get(&day).cloned().unwrap_or_default(),
+            ..DayStatus::default()
+        };
+
+        for lang in langs {
+            let dir = root.join(lang).join(&package_name);
+            if !dir.is_dir() {
+                continue;
+            }
+            let _new = status.langs.insert(lang.clone());
+
+            let readme_file = dir.join("README.md");
+            let readme = if !readme_file.exists() {
+                Readme::Missing
+            } else if readme::has_part_2(&fs::read_to_string(&readme_file)?) {
+                Readme::Part2
+            } else {
+                Readme::Part1
+            };
+            status.readme = status.readme.max(readme);
+        }
+
+        days.push(status);
+    }
+    Ok(days)
+}
+
+/// The parts of each day of `year` with an answer for the default input in the answers registry.
+fn known_answers(root: &Path, year: u32) -> anyhow::Result<BTreeMap<u32, BTreeSet<u8>>> {
+    let file = root.join(ANSWERS_FILE);
+    if !file.exists() {
+        return Ok(BTreeMap::new());
+    }
+
+    let mut answers = BTreeMap::<u32, BTreeSet<u8>>::new();
+    for line in fs::read_to_string(file)?.lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        if let (Some(line_year), Some(day), Some(part), Some("default")) = (
+            fields
+                .next()
+                .and_then(|line_year| line_year.parse::<u32>().ok()),
+            fields.next().and_then(|day| day.parse().ok()),
+            fields.next().and_then(|part| part.parse().ok()),
+            fields.next(),
+        ) {
+            if line_year == year {
+                let _new = answers.entry(day).or_default().insert(part);
+            }
+        }
+    }
+    Ok(answers)
+}
+
+/// A table of every day against each of `langs`, along with its data, README and answers.
+pub(crate) fn table(days: &[DayStatus], langs: &BTreeSet<String>) -> String {
+    let mut table = String::from("Year  Day   ");
+    for lang in langs {
+        _ = write!(table, "{lang:<4}");
+    }
+    table.push_str("Data        README  Answers\n");
+
+    for status in days {
+        _ = write!(table, "{}  {:02}    ", status.year, status.day);
+        for lang in langs {
+            let cell = if status.langs.contains(lang) {
+                "x"
+            } else {
+                "-"
+            };
+            _ = write!(table, "{cell:<4}");
+        }
+
+        let data = match (status.plain_data, status.encrypted_data) {
+            (true, true) => "both",
+            (false, true) => "encrypted",
+            (true, false) => "plaintext",
+            (false, false) => "-",
+        };
+        let readme = match status.readme {
+            Readme::Missing => "-",
+            Readme::Part1 => "part 1",
+            Readme::Part2 => "part 2",
+        };
+        let answers = if status.answers.is_empty() {
+            String::from("-")
+        } else {
+            status
+                .answers
+                .iter()
+                .map(u8::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        _ = writeln!(table, "{data:<10}  {readme:<6}  {answers}");
+    }
+    table
+}
+
+#[cfg(test)]
+mod status_tests {
+    use super::*;
+
+    fn langs() -> BTreeSet<String> {
+        ["go", "rs", "ts"].map(String::from).into()
+    }
+
+    #[test]
+    fn scans_workspace() {
+        let root = std::env::temp_dir().join("fetch_day_status_tests");
+        if root.exists() {
+            fs::remove_dir_all(&root).unwrap();
+        }
+        for dir in [
+            "rs/y2024_day_01",
+            "go/y2024_day_01",
+            "ts/y2024_day_02",
+            "data",
+        ] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("rs/y2024_day_01/README.md"), "## --- Day 1 ---\n").unwrap();
+        fs::write(
+            root.join("go/y2024_day_01/README.md"),
+            "## --- Day 1 ---\n\n## --- Part Two ---\n",
+        )
+        .unwrap();
+        fs::write(root.join("ts/y2024_day_02/README.md"), "## --- Day 2 ---\n").unwrap();
+        for file in [
+            "y2024_day_01.txt",
+            "y2024_day_01.enc.txt",
+            "y2024_day_03.enc.txt",
+            "y2023_day_04.enc.txt",
+        ] {
+            fs::write(root.join("data").join(file), "").unwrap();
+        }
+        fs::write(
+            root.join(ANSWERS_FILE),
+            "# <year> <day> <part> <input> <answer>\n2024 01 1 default 11\n2024 01 2 default 31\n2024 01 2 alice 5\n2024 03 1 alice 7\n2023 04 1 default 13\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            BTreeSet::from([2023, 2024]),
+            years(&root, &langs()).unwrap()
+        );
+
+        let mut days_2024 = scan(&root, 2024, &langs()).unwrap();
+
+        assert_eq!(25, days_2024.len());
+        days_2024.truncate(4);
+        assert_eq!(
+            "Year  Day   go  rs  ts  Data        README  Answers\n\
+             2024  01    x   x   -   both        part 2  1, 2\n\
+             2024  02    -   -   x   -           part 1  -\n\
+             2024  03    -   -   -   encrypted   -       -\n\
+             2024  04    -   -   -   -           -       -\n",
+            table(&days_2024, &langs())
+        );
+
+        let mut days_2023 = scan(&root, 2023, &langs()).unwrap();
+
+        days_2023.truncate(4);
+        assert_eq!(
+            "Year  Day   go  rs  ts  Data        README  Answers\n\
+             2023  01    -   -   -   -           -       -\n\
+             2023  02    -   -   -   -           -       -\n\
+             2023  03    -   -   -   -           -       -\n\
+             2023  04    -   -   -   encrypted   -       1\n",
+            table(&days_2023, &langs())
+        );
+    }
+
+    mod problems {
+        use super::*;
+
+        struct Case {
+            status: DayStatus,
+            expected: &'static [&'static str],
+        }
+
+        #[test]
+        fn complete() {
+            run(&Case {
+                status: DayStatus {
+                    year: 2024,
+                    day: 1,
+                    langs: langs(),
+                    encrypted_data: true,
+                    answers: [1, 2].into(),
+                    ..DayStatus::default()
+                },
+                expected: &[],
+            });
+        }
+
+        #[test]
+        fn missing_crate() {
+            run(&Case {
+                status: DayStatus {
+                    year: 2024,
+                    day: 17,
+                    langs: ["go"].map(String::from).into(),
+                    encrypted_data: true,
+                    ..DayStatus::default()
+                },
+                expected: &["2024 day 17 has encrypted data but no rs/y2024_day_17 crate"],
+            });
+        }
+
+        #[test]
+        fn unencrypted_data() {
+            run(&Case {
+                status: DayStatus {
+                    year: 2024,
+                    day: 4,
+                    langs: ["rs"].map(String::from).into(),
+                    plain_data: true,
+                    ..DayStatus::default()
+                },
+                expected: &[
+                    "2024 day 04 has a crate but no encrypted data to commit",
+                    "2024 day 04 has data that was never encrypted",
+                ],
+            });
+        }
+
+        #[test]
+        fn answers_without_crate() {
+            run(&Case {
+                status: DayStatus {
+                    year: 2024,
+                    day: 19,
+                    answers: [1].into(),
+                    ..DayStatus::default()
+                },
+                expected: &["2024 day 19 has known answers but no crate to check them"],
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.status.problems());
+        }
+    }
+}
@@ -0,0 +1,558 @@
+use core::{fmt::Display, time::Duration};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+use crate::http::Http;
+
+/// Where every submitted answer is recorded, relative to the state directory.
+pub(crate) const HISTORY_FILE: &str = "submissions.txt";
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Wrong,
+    /// Another answer was submitted too recently; nothing was checked.
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part has already been solved, or part 1 has not been yet.
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Reads the outcome from the page the `/answer` endpoint responds with.
+    pub(crate) fn parse(page: &str) -> anyhow::Result<Self> {
+        let article = Regex::new("(?s)<article.*?>(.+?)</article>")?
+            .captures(page)
+            .and_then(|caps| caps.get(1))
+            .map_or(page, |article| article.as_str());
+
+        if article.contains("That's the right answer") {
+            return Ok(Self::Correct);
+        }
+        if article.contains("You don't seem to be solving the right level") {
+            return Ok(Self::AlreadySolved);
+        }
+        if article.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait")?
+                .captures(article)
+                .map_or(Duration::ZERO, |caps| {
+                    let number = |idx| {
+                        caps.get(idx)
+                            .and_then(|number| number.as_str().parse::<u64>().ok())
+                            .unwrap_or_default()
+                    };
+                    Duration::from_secs(number(1) * 60 + number(2))
+                });
+            return Ok(Self::RateLimited { wait });
+        }
+        if article.contains("That's not the right answer") {
+            return Ok(if article.contains("your answer is too high") {
+                Self::TooHigh
+            } else if article.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            });
+        }
+
+        let text = Regex::new("<[^>]*>")?.replace_all(article, "");
+        anyhow::bail!(
+            "Unrecognised response to the answer: {}",
+            text.split_whitespace().collect::<Vec<_>>().join(" ")
+        )
+    }
+
+    const fn token(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+            Self::Wrong => "wrong",
+            Self::RateLimited { .. } => "rate-limited",
+            Self::AlreadySolved => "already-solved",
+        }
+    }
+
+    fn from_token(token: &str) -> Option<Self> {
+        match token {
+            "correct" => Some(Self::Correct),
+            "too-high" => Some(Self::TooHigh),
+            "too-low" => Some(Self::TooLow),
+            "wrong" => Some(Self::Wrong),
+            "rate-limited" => Some(Self::RateLimited {
+                wait: Duration::ZERO,
+            }),
+            "already-solved" => Some(Self::AlreadySolved),
+            _ => None,
+        }
+    }
+
+    /// Whether the site checked the answer, so that submitting it again would not change anything.
+    const fn is_verdict(self) -> bool {
+        matches!(
+            self,
+            Self::Correct | Self::TooHigh | Self::TooLow | Self::Wrong
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Correct => formatter.write_str("correct"),
+            Self::TooHigh => formatter.write_str("wrong, too high"),
+            Self::TooLow => formatter.write_str("wrong, too low"),
+            Self::Wrong => formatter.write_str("wrong"),
+            Self::RateLimited { wait } => {
+                write!(
+                    formatter,
+                    "not checked, wait {}s before submitting again",
+                    wait.as_secs()
+                )
+            }
+            Self::AlreadySolved => {
+                formatter.write_str("not checked, the part is already solved or not yet unlocked")
+            }
+        }
+    }
+}
+
+/// One answer submitted for one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Attempt {
+    pub(crate) year: u32,
+    pub(crate) day: u32,
+    pub(crate) part: u8,
+    pub(crate) answer: String,
+}
+
+impl Attempt {
+    const fn is_same_part(&self, other: &Self) -> bool {
+        self.year == other.year && self.day == other.day && self.part == other.part
+    }
+}
+
+/// Every answer submitted so far, stored one per line as
+/// `<timestamp> <year> <day> <part> <outcome> <answer>`.
+#[derive(Debug)]
+pub(crate) struct History {
+    path: PathBuf,
+    attempts: Vec<(Attempt, Outcome)>,
+}
+
+impl History {
+    /// Loads the history at `path`, which is empty if the file does not exist yet.
+    pub(crate) fn load(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        let attempts = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                Self::parse_line(line)
+                    .ok_or_else(|| anyhow::anyhow!("Invalid line in {}: '{line}'", path.display()))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Self { path, attempts })
+    }
+
+    fn parse_line(line: &str) -> Option<(Attempt, Outcome)> {
+        let mut fields = line.splitn(6, ' ');
+        let _timestamp = fields.next()?;
+        let year = fields.next()?.parse().ok()?;
+        let day = fields.next()?.parse().ok()?;
+        let part = fields.next()?.parse().ok()?;
+        let outcome = Outcome::from_token(fields.next()?)?;
+        let answer = fields.next()?.to_owned();
+
+        Some((
+            Attempt {
+                year,
+                day,
+                part,
+                answer,
+            },
+            outcome,
+        ))
+    }
+
+    /// The outcome the site already gave for this answer, or one implied by a correct answer or
+    /// a "too high"/"too low" hint for the same part.
+    pub(crate) fn known_outcome(&self, attempt: &Attempt) -> Option<Outcome> {
+        let verdicts = self
+            .attempts
+            .iter()
+            .filter(|(previous, outcome)| previous.is_same_part(attempt) && outcome.is_verdict());
+
+        let answer = attempt.answer.parse::<i128>().ok();
+        let mut known = None;
+        for (previous, outcome) in verdicts {
+            if previous.answer == attempt.answer {
+                return Some(*outcome);
+            }
+
+            let bound = previous.answer.parse::<i128>().ok();
+            known = known.or(match (outcome, answer, bound) {
+                (Outcome::Correct, _, _) => Some(Outcome::AlreadySolved),
+                (Outcome::TooHigh, Some(answer), Some(bound)) if answer >= bound => {
+                    Some(Outcome::TooHigh)
+                }
+                (Outcome::TooLow, Some(answer), Some(bound)) if answer <= bound => {
+                    Some(Outcome::TooLow)
+                }
+                _ => None,
+            });
+        }
+
+        known
+    }
+
+    /// Appends the attempt to the history file.
+    pub(crate) fn record(&mut self, attempt: Attempt, outcome: Outcome) -> anyhow::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(
+            file,
+            "{} {} {} {} {} {}",
+            chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ"),
+            attempt.year,
+            attempt.day,
+            attempt.part,
+            outcome.token(),
+            attempt.answer
+        )?;
+
+        self.attempts.push((attempt, outcome));
+        Ok(())
+    }
+}
+
+/// Whether an answer was sent to the site, or held back because the history already decides it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Submission {
+    Submitted(Outcome),
+    Skipped(Outcome),
+}
+
+/// Submits `attempt` to the day's `/answer` endpoint unless the history already knows how it
+/// would turn out, recording whatever the site says.
+pub(crate) fn submit(
+    http: &impl Http,
+    day_url: &str,
+    history: &mut History,
+    attempt: Attempt,
+) -> anyhow::Result<Submission> {
+    if let Some(outcome) = history.known_outcome(&attempt) {
+        return Ok(Submission::Skipped(outcome));
+    }
+
+    let page = http.post_form(
+        &format!("{day_url}/answer"),
+        &[
+            ("level", &attempt.part.to_string()),
+            ("answer", &attempt.answer),
+        ],
+    )?;
+
+    let outcome = Outcome::parse(&page)?;
+    history.record(attempt, outcome)?;
+
+    Ok(Submission::Submitted(outcome))
+}
+
+/// The default history file under `root`.
+pub(crate) fn history_path(root: &Path) -> PathBuf {
+    root.join(crate::STATE_DIR).join(HISTORY_FILE)
+}
+
+#[cfg(test)]
+mod submit_tests {
+    use super::*;
+    use crate::http::{
+        http_tests::{politeness, response, stub_server},
+        Client, Fixtures,
+    };
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    fn attempt(part: u8, answer: &str) -> Attempt {
+        Attempt {
+            year: 2024,
+            day: 1,
+            part,
+            answer: answer.to_owned(),
+        }
+    }
+
+    fn temp_history(name: &str) -> History {
+        let path = std::env::temp_dir()
+            .join("fetch_day_submit_tests")
+            .join(name)
+            .join("submissions.txt");
+        if path.exists() {
+            fs::remove_file(&path).unwrap();
+        }
+        History::load(path).unwrap()
+    }
+
+    mod parse {
+        use super::*;
+
+        struct Case {
+            input: String,
+            expected: Outcome,
+        }
+
+        #[test]
+        fn correct() {
+            run(&Case {
+                input: page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."),
+                expected: Outcome::Correct,
+            });
+        }
+
+        #[test]
+        fn too_high() {
+            run(&Case {
+                input: page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data."),
+                expected: Outcome::TooHigh,
+            });
+        }
+
+        #[test]
+        fn too_low() {
+            run(&Case {
+                input: page("That's not the right answer; your answer is too low."),
+                expected: Outcome::TooLow,
+            });
+        }
+
+        #[test]
+        fn wrong() {
+            run(&Case {
+                input: page("That's not the right answer.  If you're stuck, make sure you're using the full input data."),
+                expected: Outcome::Wrong,
+            });
+        }
+
+        #[test]
+        fn rate_limited_seconds() {
+            run(&Case {
+                input: page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait."),
+                expected: Outcome::RateLimited {
+                    wait: Duration::from_secs(37),
+                },
+            });
+        }
+
+        #[test]
+        fn rate_limited_minutes() {
+            run(&Case {
+                input: page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 2s left to wait."),
+                expected: Outcome::RateLimited {
+                    wait: Duration::from_secs(242),
+                },
+            });
+        }
+
+        #[test]
+        fn already_solved() {
+            run(&Case {
+                input: page(
+                    "You don't seem to be solving the right level.  Did you already complete it?",
+                ),
+                expected: Outcome::AlreadySolved,
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, Outcome::parse(&test.input).unwrap());
+        }
+
+        #[test]
+        fn unrecognised() {
+            assert_eq!(
+                "Unrecognised response to the answer: Please log in.",
+                Outcome::parse(&page("<em>Please</em> log in."))
+                    .unwrap_err()
+                    .to_string()
+            );
+        }
+    }
+
+    mod known_outcome {
+        use super::*;
+
+        struct Case {
+            attempt: Attempt,
+            expected: Option<Outcome>,
+        }
+
+        #[test]
+        fn same_wrong_answer() {
+            run(&Case {
+                attempt: attempt(1, "banana"),
+                expected: Some(Outcome::Wrong),
+            });
+        }
+
+        #[test]
+        fn above_too_high() {
+            run(&Case {
+                attempt: attempt(1, "600"),
+                expected: Some(Outcome::TooHigh),
+            });
+        }
+
+        #[test]
+        fn below_too_low() {
+            run(&Case {
+                attempt: attempt(1, "100"),
+                expected: Some(Outcome::TooLow),
+            });
+        }
+
+        #[test]
+        fn between_bounds() {
+            run(&Case {
+                attempt: attempt(1, "300"),
+                expected: None,
+            });
+        }
+
+        #[test]
+        fn rate_limited_is_retried() {
+            run(&Case {
+                attempt: attempt(1, "301"),
+                expected: None,
+            });
+        }
+
+        #[test]
+        fn solved_part() {
+            run(&Case {
+                attempt: attempt(2, "7"),
+                expected: Some(Outcome::AlreadySolved),
+            });
+        }
+
+        fn run(test: &Case) {
+            let mut history = temp_history("known_outcome");
+            history.attempts = vec![
+                (attempt(1, "500"), Outcome::TooHigh),
+                (attempt(1, "200"), Outcome::TooLow),
+                (attempt(1, "banana"), Outcome::Wrong),
+                (
+                    attempt(1, "301"),
+                    Outcome::RateLimited {
+                        wait: Duration::ZERO,
+                    },
+                ),
+                (attempt(2, "42"), Outcome::Correct),
+            ];
+
+            assert_eq!(test.expected, history.known_outcome(&test.attempt));
+        }
+    }
+
+    mod submit {
+        use super::*;
+
+        const DAY_URL: &str = "https://adventofcode.com/2024/day/1";
+
+        #[test]
+        fn posts_and_records() {
+            let (url, server) = stub_server(vec![response(
+                "200 OK",
+                &[],
+                &page("That's not the right answer; your answer is too low."),
+            )]);
+            let mut history = temp_history("posts_and_records");
+
+            let submission = submit(
+                &Client::new(String::from("secret"), &politeness("posts_and_records")).unwrap(),
+                &url,
+                &mut history,
+                attempt(2, "1234"),
+            )
+            .unwrap();
+            let request = server.join().unwrap().remove(0);
+
+            assert_eq!(Submission::Submitted(Outcome::TooLow), submission);
+            assert!(
+                request.starts_with("POST /answer HTTP/1.1\r\n"),
+                "Unexpected request: {request}"
+            );
+            assert!(
+                request.contains("cookie: session=secret\r\n"),
+                "Missing session cookie: {request}"
+            );
+            assert!(
+                request.ends_with("\r\n\r\nlevel=2&answer=1234"),
+                "Unexpected form: {request}"
+            );
+
+            let reloaded = History::load(history.path.clone()).unwrap();
+            assert_eq!(
+                vec![(attempt(2, "1234"), Outcome::TooLow)],
+                reloaded.attempts
+            );
+        }
+
+        #[test]
+        fn skips_known_answer() {
+            let mut history = temp_history("skips_known_answer");
+            history.record(attempt(1, "99"), Outcome::Wrong).unwrap();
+
+            let fixtures = Fixtures::default();
+
+            assert_eq!(
+                Submission::Skipped(Outcome::Wrong),
+                submit(&fixtures, DAY_URL, &mut history, attempt(1, "99")).unwrap()
+            );
+            assert_eq!(Vec::<String>::new(), fixtures.requests());
+        }
+
+        #[test]
+        fn posts_through_http() {
+            let fixtures = Fixtures::default().with_body(
+                &format!("{DAY_URL}/answer"),
+                &page("That's the right answer!"),
+            );
+            let mut history = temp_history("posts_through_http");
+
+            assert_eq!(
+                Submission::Submitted(Outcome::Correct),
+                submit(&fixtures, DAY_URL, &mut history, attempt(1, "17")).unwrap()
+            );
+            assert_eq!(
+                vec![format!("POST {DAY_URL}/answer level=1&answer=17")],
+                fixtures.requests()
+            );
+        }
+    }
+}
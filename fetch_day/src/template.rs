@@ -0,0 +1,637 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Lists a language's templates and the variables they use, in the language's directory.
+///
+/// Each non-empty line not starting with `#` is one of:
+///
+/// - `variables <name>...`: variables the templates use, each of which must be one of
+///   [`VARIABLES`] or [`SECTIONS`].
+/// - `file <template> <output>`: renders `<template>` to `<output>` in the day's directory. The
+///   output path may use variables too.
+pub(crate) const MANIFEST_FILE: &str = "manifest.txt";
+
+/// The variables every template can use.
+pub(crate) const VARIABLES: [&str; 12] = [
+    "crate_name",
+    "display_name",
+    "day",
+    "year",
+    "part_1_example",
+    "part_1_expected",
+    "part_1_expected_type",
+    "part_1_type",
+    "part_2_example",
+    "part_2_expected",
+    "part_2_expected_type",
+    "part_2_type",
+];
+
+/// Sections that repeat for each item, along with the variables each item has.
+pub(crate) const SECTIONS: [(&str, &[&str]); 1] =
+    [("examples", &["number", "input", "rust_input"])];
+
+/// The Go, Rust and TypeScript templates, used for any language without a template directory of
+/// its own.
+const BUILT_IN: [(&str, &str, &str); 13] = [
+    (
+        "go",
+        MANIFEST_FILE,
+        include_str!("../templates/go/manifest.txt"),
+    ),
+    (
+        "go",
+        "main.stpl.go",
+        include_str!("../templates/go/main.stpl.go"),
+    ),
+    (
+        "go",
+        "main_test.stpl.go",
+        include_str!("../templates/go/main_test.stpl.go"),
+    ),
+    (
+        "rs",
+        MANIFEST_FILE,
+        include_str!("../templates/rs/manifest.txt"),
+    ),
+    (
+        "rs",
+        "Cargo.stpl.toml",
+        include_str!("../templates/rs/Cargo.stpl.toml"),
+    ),
+    (
+        "rs",
+        "build.stpl.rs",
+        include_str!("../templates/rs/build.stpl.rs"),
+    ),
+    (
+        "rs",
+        "src/lib.stpl.rs",
+        include_str!("../templates/rs/src/lib.stpl.rs"),
+    ),
+    (
+        "rs",
+        "benches/bench.stpl.rs",
+        include_str!("../templates/rs/benches/bench.stpl.rs"),
+    ),
+    (
+        "ts",
+        MANIFEST_FILE,
+        include_str!("../templates/ts/manifest.txt"),
+    ),
+    (
+        "ts",
+        "main.stpl.ts",
+        include_str!("../templates/ts/main.stpl.ts"),
+    ),
+    (
+        "ts",
+        "day.stpl.ts",
+        include_str!("../templates/ts/day.stpl.ts"),
+    ),
+    (
+        "ts",
+        "day.test.stpl.ts",
+        include_str!("../templates/ts/day.test.stpl.ts"),
+    ),
+    (
+        "ts",
+        "day.bench.stpl.ts",
+        include_str!("../templates/ts/day.bench.stpl.ts"),
+    ),
+];
+
+/// The values to render templates with.
+#[derive(Debug, Default)]
+pub(crate) struct Values {
+    pub(crate) variables: BTreeMap<&'static str, String>,
+    /// The items of each section, in order.
+    pub(crate) sections: BTreeMap<&'static str, Vec<BTreeMap<&'static str, String>>>,
+}
+
+/// A language's templates, checked against its manifest.
+#[derive(Debug)]
+pub(crate) struct Language {
+    pub(crate) name: String,
+    files: Vec<TemplateFile>,
+}
+
+/// Where the files of a language's templates are read from.
+enum Source {
+    Dir(PathBuf),
+    BuiltIn,
+}
+
+impl Source {
+    fn read(&self, lang: &str, file: &str) -> anyhow::Result<String> {
+        match self {
+            Self::Dir(dir) => fs::read_to_string(dir.join(file)).map_err(|err| {
+                anyhow::anyhow!("Failed to read {}: {err}", dir.join(file).display())
+            }),
+            Self::BuiltIn => BUILT_IN
+                .iter()
+                .find(|(name, path, _)| *name == lang && *path == file)
+                .map(|(_, _, contents)| (*contents).to_owned())
+                .ok_or_else(|| anyhow::anyhow!("No built-in {lang} template '{file}'")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TemplateFile {
+    output: Vec<Token>,
+    template: Vec<Token>,
+}
+
+impl Language {
+    /// Loads the templates for `name` from `template_dir/<name>`, or the built-in ones if there
+    /// is no such directory.
+    ///
+    /// Every template is parsed and checked against the manifest before anything is rendered, so
+    /// that a template using a variable the manifest does not list, or a manifest listing a
+    /// variable that `fetch_day` does not provide, is reported before any file is written.
+    pub(crate) fn load(name: &str, template_dir: &Path) -> anyhow::Result<Self> {
+        let dir = template_dir.join(name);
+        let source = if dir.is_dir() {
+            Source::Dir(dir)
+        } else if BUILT_IN.iter().any(|(lang, _, _)| *lang == name) {
+            Source::BuiltIn
+        } else {
+            anyhow::bail!(
+                "No templates for '{name}' in {}; available: {}",
+                template_dir.display(),
+                available(template_dir)
+                    .into_iter()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        };
+        let read = |file: &str| source.read(name, file);
+
+        let manifest = Manifest::parse(&read(MANIFEST_FILE)?)
+            .map_err(|err| anyhow::anyhow!("Invalid {name} {MANIFEST_FILE}: {err}"))?;
+
+        let mut problems = manifest
+            .variables
+            .iter()
+            .filter(|variable| !is_provided(variable))
+            .map(|variable| {
+                format!(
+                    "{MANIFEST_FILE} lists '{variable}', which is not one of: {}",
+                    provided().join(", ")
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut files = Vec::new();
+        for (template_name, output) in &manifest.files {
+            let file = TemplateFile {
+                output: parse(output).map_err(|err| {
+                    anyhow::anyhow!("Invalid {name} output path '{output}': {err}")
+                })?,
+                template: parse(&read(template_name)?).map_err(|err| {
+                    anyhow::anyhow!("Invalid {name} template {template_name}: {err}")
+                })?,
+            };
+            for used in file.variables() {
+                if !manifest.variables.contains(&used) {
+                    problems.push(format!(
+                        "{template_name} uses '{used}', which {MANIFEST_FILE} does not list"
+                    ));
+                }
+            }
+            files.push(file);
+        }
+
+        if !problems.is_empty() {
+            anyhow::bail!("Invalid {name} templates:\n  {}", problems.join("\n  "));
+        }
+
+        Ok(Self {
+            name: name.to_owned(),
+            files,
+        })
+    }
+
+    /// Every file the templates render to, relative to the day's directory, with its contents.
+    pub(crate) fn render(&self, values: &Values) -> Vec<(PathBuf, String)> {
+        self.files
+            .iter()
+            .map(|file| {
+                (
+                    PathBuf::from(render(&file.output, values, None)),
+                    render(&file.template, values, None),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Every language with templates, built in or in `template_dir`.
+pub(crate) fn available(template_dir: &Path) -> BTreeSet<String> {
+    let mut languages = BUILT_IN
+        .iter()
+        .map(|(lang, _, _)| (*lang).to_owned())
+        .collect::<BTreeSet<_>>();
+    if let Ok(entries) = fs::read_dir(template_dir) {
+        languages.extend(
+            entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().join(MANIFEST_FILE).exists())
+                .filter_map(|entry| entry.file_name().into_string().ok()),
+        );
+    }
+    languages
+}
+
+fn provided() -> Vec<&'static str> {
+    VARIABLES
+        .iter()
+        .copied()
+        .chain(SECTIONS.iter().map(|(section, _)| *section))
+        .collect()
+}
+
+fn is_provided(variable: &str) -> bool {
+    provided().contains(&variable)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Manifest {
+    variables: BTreeSet<String>,
+    /// Each template and the path it renders to.
+    files: Vec<(String, String)>,
+}
+
+impl Manifest {
+    fn parse(contents: &str) -> Result<Self, String> {
+        let mut manifest = Self::default();
+        for (number, line) in (1_usize..).zip(contents.lines()) {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(names) = line.strip_prefix("variables ") {
+                manifest
+                    .variables
+                    .extend(names.split_whitespace().map(str::to_owned));
+                continue;
+            }
+            // The output path is the rest of the line, as its variables may contain spaces.
+            let Some((template, output)) = line
+                .strip_prefix("file ")
+                .and_then(|file| file.trim_start().split_once(char::is_whitespace))
+            else {
+                return Err(format!(
+                    "line {number} should be 'variables <name>...' or 'file <template> <output>': {line}"
+                ));
+            };
+            manifest
+                .files
+                .push((template.to_owned(), output.trim().to_owned()));
+        }
+        Ok(manifest)
+    }
+}
+
+/// A parsed template: text, `{{ variable }}`s and `{{# section }}...{{/ section }}`s.
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    Text(String),
+    Variable(String),
+    Section(String, Vec<Self>),
+}
+
+impl TemplateFile {
+    /// Every top-level variable and section used, leaving out the variables of section items.
+    fn variables(&self) -> BTreeSet<String> {
+        let mut variables = BTreeSet::new();
+        collect_variables(&self.output, None, &mut variables);
+        collect_variables(&self.template, None, &mut variables);
+        variables
+    }
+}
+
+fn collect_variables(tokens: &[Token], section: Option<&str>, variables: &mut BTreeSet<String>) {
+    let item_variables = SECTIONS
+        .iter()
+        .find(|(name, _)| Some(*name) == section)
+        .map_or(&[][..], |(_, item)| *item);
+
+    for token in tokens {
+        match token {
+            Token::Text(_) => {}
+            Token::Variable(name) => {
+                if !item_variables.contains(&name.as_str()) {
+                    let _new = variables.insert(name.clone());
+                }
+            }
+            Token::Section(name, body) => {
+                let _new = variables.insert(name.clone());
+                collect_variables(body, Some(name), variables);
+            }
+        }
+    }
+}
+
+/// Parses a template. A section tag alone on its line takes the whole line with it, so that
+/// sections do not leave blank lines behind.
+fn parse(template: &str) -> Result<Vec<Token>, String> {
+    let mut stack: Vec<(String, Vec<Token>)> = vec![(String::new(), Vec::new())];
+    let mut rest = template;
+    let mut at_line_start = true;
+
+    while let Some(start) = rest.find("{{") {
+        let (mut text, tag) = rest.split_at(start);
+        let Some(end) = tag.find("}}") else {
+            return Err(format!("unclosed '{{{{' before: {}", first_line(tag)));
+        };
+        let inner = tag.get(2..end).unwrap_or_default().trim();
+        rest = tag.get(end + 2..).unwrap_or_default();
+
+        if inner.starts_with(['#', '/']) {
+            let line_start = text.rfind('\n').map(|newline| newline + 1);
+            let indent = text.get(line_start.unwrap_or(0)..).unwrap_or_default();
+            let rest_of_line = rest.split_once('\n').map_or(rest, |(line, _)| line);
+            if (line_start.is_some() || at_line_start)
+                && indent.trim().is_empty()
+                && rest_of_line.trim().is_empty()
+            {
+                text = text.get(..line_start.unwrap_or(0)).unwrap_or_default();
+                rest = rest.split_once('\n').map_or("", |(_, after)| after);
+            }
+        }
+        at_line_start = false;
+        if let Some((_, tokens)) = stack.last_mut() {
+            if !text.is_empty() {
+                tokens.push(Token::Text(text.to_owned()));
+            }
+        }
+
+        if let Some(name) = inner.strip_prefix('#') {
+            stack.push((name.trim().to_owned(), Vec::new()));
+            at_line_start = true;
+        } else if let Some(name) = inner.strip_prefix('/') {
+            let name = name.trim();
+            match stack.pop() {
+                Some((open, body)) if stack.last().is_some() && open == name => {
+                    if let Some((_, tokens)) = stack.last_mut() {
+                        tokens.push(Token::Section(open, body));
+                    }
+                }
+                _ => {
+                    return Err(format!(
+                        "'{{{{/ {name} }}}}' does not close an open section"
+                    ))
+                }
+            }
+            at_line_start = true;
+        } else if inner.is_empty() {
+            return Err(String::from("empty '{{ }}'"));
+        } else if let Some((_, tokens)) = stack.last_mut() {
+            tokens.push(Token::Variable(inner.to_owned()));
+        } else {
+            return Err(String::from("unbalanced sections"));
+        }
+    }
+
+    let Some((open, mut tokens)) = stack.pop() else {
+        return Err(String::from("unbalanced sections"));
+    };
+    if !stack.is_empty() {
+        return Err(format!("'{{{{# {open} }}}}' is never closed"));
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest.to_owned()));
+    }
+    Ok(tokens)
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or_default()
+}
+
+fn render(tokens: &[Token], values: &Values, item: Option<&BTreeMap<&str, String>>) -> String {
+    let mut output = String::new();
+    for token in tokens {
+        match token {
+            Token::Text(text) => output.push_str(text),
+            Token::Variable(name) => {
+                let value = item
+                    .and_then(|item| item.get(name.as_str()))
+                    .or_else(|| values.variables.get(name.as_str()));
+                output.push_str(value.map_or("", String::as_str));
+            }
+            Token::Section(name, body) => {
+                for section_item in values.sections.get(name.as_str()).into_iter().flatten() {
+                    output.push_str(&render(body, values, Some(section_item)));
+                }
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod template_tests {
+    use super::*;
+
+    mod parse_and_render {
+        use super::*;
+
+        struct Case {
+            template: &'static str,
+            expected: Result<&'static str, &'static str>,
+        }
+
+        #[test]
+        fn variables() {
+            run(&Case {
+                template: "package {{ crate_name }} // {{display_name}}\n",
+                expected: Ok("package day_04 // Day 04\n"),
+            });
+        }
+
+        #[test]
+        fn standalone_section_lines_are_dropped() {
+            run(&Case {
+                template: "a\n    {{# examples }}\n    {{ number }}: {{ input }}\n    {{/ examples }}\nb\n",
+                expected: Ok("a\n    1: x\n    2: y\nb\n"),
+            });
+        }
+
+        #[test]
+        fn inline_section() {
+            run(&Case {
+                template: "[{{# examples }}{{ number }},{{/ examples }}]\n",
+                expected: Ok("[1,2,]\n"),
+            });
+        }
+
+        #[test]
+        fn unclosed_section() {
+            run(&Case {
+                template: "{{# examples }}\n",
+                expected: Err("'{{# examples }}' is never closed"),
+            });
+        }
+
+        #[test]
+        fn mismatched_section() {
+            run(&Case {
+                template: "{{# examples }}{{/ inputs }}",
+                expected: Err("'{{/ inputs }}' does not close an open section"),
+            });
+        }
+
+        #[test]
+        fn unterminated_tag() {
+            run(&Case {
+                template: "{{ crate_name",
+                expected: Err("unclosed '{{' before: {{ crate_name"),
+            });
+        }
+
+        fn run(test: &Case) {
+            let values = Values {
+                variables: BTreeMap::from([
+                    ("crate_name", String::from("day_04")),
+                    ("display_name", String::from("Day 04")),
+                ]),
+                sections: BTreeMap::from([(
+                    "examples",
+                    vec![
+                        BTreeMap::from([
+                            ("number", String::from("1")),
+                            ("input", String::from("x")),
+                        ]),
+                        BTreeMap::from([
+                            ("number", String::from("2")),
+                            ("input", String::from("y")),
+                        ]),
+                    ],
+                )]),
+            };
+            assert_eq!(
+                test.expected.map(str::to_owned).map_err(str::to_owned),
+                parse(test.template).map(|tokens| render(&tokens, &values, None))
+            );
+        }
+    }
+
+    mod manifest {
+        use super::*;
+
+        struct Case {
+            contents: &'static str,
+            expected: Result<Manifest, &'static str>,
+        }
+
+        #[test]
+        fn valid() {
+            run(&Case {
+                contents: "# The Python templates\n\nvariables crate_name\nvariables day\nfile a.py {{ crate_name }}.py\n",
+                expected: Ok(Manifest {
+                    variables: BTreeSet::from([String::from("crate_name"), String::from("day")]),
+                    files: vec![(String::from("a.py"), String::from("{{ crate_name }}.py"))],
+                }),
+            });
+        }
+
+        #[test]
+        fn missing_output() {
+            run(&Case {
+                contents: "file a.py\n",
+                expected: Err(
+                    "line 1 should be 'variables <name>...' or 'file <template> <output>': file a.py",
+                ),
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                test.expected.as_ref().map_err(|err| *err),
+                Manifest::parse(test.contents)
+                    .as_ref()
+                    .map_err(String::as_str)
+            );
+        }
+    }
+
+    mod load {
+        use super::*;
+
+        struct Case {
+            name: &'static str,
+            manifest: &'static str,
+            template: &'static str,
+            expected: Result<(), &'static str>,
+        }
+
+        #[test]
+        fn valid() {
+            run(&Case {
+                name: "valid",
+                manifest: "variables day examples\nfile t.txt out.txt\n",
+                template: "{{ day }}{{# examples }}{{ number }}{{/ examples }}",
+                expected: Ok(()),
+            });
+        }
+
+        #[test]
+        fn reports_every_problem() {
+            run(&Case {
+                name: "reports_every_problem",
+                manifest: "variables day answer\nfile t.txt out.txt\n",
+                template: "{{ day }}{{ title }}",
+                expected: Err("Invalid reports_every_problem templates:\n  manifest.txt lists 'answer', which is not one of: crate_name, display_name, day, year, part_1_example, part_1_expected, part_1_expected_type, part_1_type, part_2_example, part_2_expected, part_2_expected_type, part_2_type, examples\n  t.txt uses 'title', which manifest.txt does not list"),
+            });
+        }
+
+        #[test]
+        fn missing_template() {
+            run(&Case {
+                name: "missing_template",
+                manifest: "variables day\nfile other.txt out.txt\n",
+                template: "",
+                expected: Err("Failed to read"),
+            });
+        }
+
+        fn run(test: &Case) {
+            let template_dir = std::env::temp_dir().join("fetch_day_template_tests");
+            let dir = template_dir.join(test.name);
+            if dir.exists() {
+                fs::remove_dir_all(&dir).unwrap();
+            }
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join(MANIFEST_FILE), test.manifest).unwrap();
+            fs::write(dir.join("t.txt"), test.template).unwrap();
+
+            match (test.expected, Language::load(test.name, &template_dir)) {
+                (Ok(()), Ok(_)) => {}
+                (Err(expected), Err(err)) => assert!(
+                    err.to_string().starts_with(expected),
+                    "Expected '{expected}', got '{err}'"
+                ),
+                #[expect(clippy::panic)]
+                (expected, actual) => panic!("Expected {expected:?}, got {actual:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn built_in_templates_are_valid() {
+        let template_dir = std::env::temp_dir().join("fetch_day_no_templates");
+        for lang in ["go", "rs", "ts"] {
+            assert!(
+                Language::load(lang, &template_dir).is_ok(),
+                "Invalid built-in {lang} templates"
+            );
+        }
+    }
+}
@@ -1,39 +1,46 @@
-use std::{fs::read_to_string, path::Path};
-
-pub const PART_1: usize = 0;
-pub const PART_2: usize = 0;
+use common::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("{{ crate_name }}.txt")
+}
 
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("<%= &self.crate_name %>.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("{{ crate_name }}.txt", name)
 }
 
-#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Input {}
 
-impl Input {
-    #[must_use]
-    pub fn from_data(data: &str) -> Self {
+impl Solution<'_> for Input {
+    const YEAR: u16 = {{ year }};
+    const DAY: u8 = {{ day }};
+
+    type Part1 = {{ part_1_type }};
+    type Part2 = {{ part_2_type }};
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let _source = Source::new(data);
+
         todo!()
     }
 
-    #[must_use]
-    pub fn part_1(&self) -> usize {
+    fn part_1(&self) -> {{ part_1_type }} {
         todo!()
     }
 
-    #[must_use]
-    pub fn part_2(&self) -> usize {
+    fn part_2(&self) -> {{ part_2_type }} {
         todo!()
     }
 }
 
 #[cfg(test)]
-mod <%= &self.crate_name %>_tests {
+mod {{ crate_name }}_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -44,14 +51,16 @@ mod <%= &self.crate_name %>_tests {
             input: &'c str,
             expected: Input,
         }
+        {{# examples }}
 
         #[test]
-        fn example() {
+        fn example_{{ number }}() {
             run(&Case {
-                input: super::example().0,
-                expected: super::example().1,
+                input: super::example_{{ number }}().0,
+                expected: super::example_{{ number }}().1,
             });
         }
+        {{/ examples }}
 
         fn run(test: &Case<'_>) {
             assert_eq!(test.expected, Input::from_data(test.input));
@@ -63,22 +72,23 @@ mod <%= &self.crate_name %>_tests {
 
         struct Case {
             data: Input,
-            expected: usize,
+            expected: {{ part_1_expected_type }},
         }
 
         #[test]
         fn example() {
             run(&Case {
-                data: super::example().1,
-                expected: todo!(),
+                data: example_{{ part_1_example }}().1,
+                expected: {{ part_1_expected }},
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
 
@@ -92,22 +102,23 @@ mod <%= &self.crate_name %>_tests {
 
         struct Case {
             data: Input,
-            expected: usize,
+            expected: {{ part_2_expected_type }},
         }
 
         #[test]
         fn example() {
             run(&Case {
-                data: super::example().1,
-                expected: todo!(),
+                data: example_{{ part_2_example }}().1,
+                expected: {{ part_2_expected }},
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
             });
         }
 
@@ -115,8 +126,19 @@ mod <%= &self.crate_name %>_tests {
             assert_eq!(test.expected, test.data.part_2());
         }
     }
+    {{# examples }}
+
+    fn example_{{ number }}() -> (&'static str, Input) {
+        (
+            {{ rust_input }},
+            Input {},
+        )
+    }
+    {{/ examples }}
 
-    fn example() -> (&'static str, Input) {
-        ("", Input {})
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
     }
 }
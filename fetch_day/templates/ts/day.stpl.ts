@@ -4,7 +4,7 @@ export const part2Solution = 0;
 export type Input = {};
 
 export function readData(): string {
-  return Deno.readTextFileSync("./data/<%= &self.package_name %>.txt")
+  return Deno.readTextFileSync("./data/{{ crate_name }}.txt")
     .trim();
 }
 
@@ -0,0 +1,60 @@
+use common::{
+    input::{InputSource, ReadError},
+    parse::ParseError,
+    Solution,
+};
+
+use crate::{solve, Parts, Report};
+
+/// A day crate wired into the runner.
+pub(crate) struct Day {
+    pub(crate) year: u16,
+    pub(crate) number: u8,
+    pub(crate) read_data: fn(&InputSource) -> Result<String, ReadError>,
+    pub(crate) solve: fn(&str, Parts) -> Result<Report, ParseError>,
+}
+
+impl Day {
+    /// The day as it is reported, such as `2024 Day 01`.
+    pub(crate) fn name(&self) -> String {
+        format!("{} Day {:02}", self.year, self.number)
+    }
+}
+
+macro_rules! days {
+    ($($krate:ident),* $(,)?) => {
+        #[expect(elided_lifetimes_in_paths)]
+        pub(crate) fn all() -> Vec<Day> {
+            vec![$(
+                Day {
+                    year: <$krate::Input as Solution>::YEAR,
+                    number: <$krate::Input as Solution>::DAY,
+                    read_data: $krate::read_data,
+                    solve: |data, parts| solve::<$krate::Input>(data, parts),
+                },
+            )*]
+        }
+    };
+}
+
+days! {
+    y2024_day_01,
+    y2024_day_02,
+    y2024_day_03,
+    y2024_day_04,
+    y2024_day_05,
+    y2024_day_06,
+    y2024_day_07,
+    y2024_day_08,
+    y2024_day_09,
+    y2024_day_10,
+    y2024_day_11,
+    y2024_day_12,
+    y2024_day_13,
+    y2024_day_14,
+    y2024_day_15,
+    y2024_day_16,
+    y2024_day_18,
+    y2024_day_20,
+    y2024_day_22,
+}
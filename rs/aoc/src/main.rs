@@ -0,0 +1,402 @@
+use core::{fmt::Display, ops::RangeInclusive, str::FromStr, time::Duration};
+use std::{collections::BTreeSet, fs, path::PathBuf, process::ExitCode, time::Instant};
+
+use anyhow::Context;
+use clap::Parser;
+use common::{
+    answers::{Answers, Verdict},
+    format_duration,
+    input::InputSource,
+    parse::ParseError,
+    Answer, Solution,
+};
+use timings::DayTimings;
+
+mod days;
+mod timings;
+
+#[derive(clap::Parser, Debug)]
+struct Opts {
+    #[arg(
+        value_name = "DAYS",
+        help = "Days to run, either single days ('16') or inclusive ranges ('1-5'). Runs every day if omitted"
+    )]
+    days: Vec<DayRange>,
+
+    #[arg(
+        short,
+        long,
+        help = "Only run the days of this year. Runs the days of every year if omitted"
+    )]
+    year: Option<u16>,
+
+    #[arg(
+        short,
+        long,
+        value_parser = clap::value_parser!(u8).range(1..=2),
+        help = "Only run the given part"
+    )]
+    part: Option<u8>,
+
+    #[arg(
+        long,
+        conflicts_with = "input",
+        help = "Directory containing the puzzle inputs [default: $AOC_DATA_DIR or ./data]"
+    )]
+    data_dir: Option<PathBuf>,
+
+    #[arg(
+        short,
+        long,
+        value_name = "FILE",
+        help = "Read the input for a single day from this file, or from stdin if '-'"
+    )]
+    input: Option<String>,
+
+    #[arg(
+        short,
+        long,
+        default_value_t = 1,
+        value_parser = clap::value_parser!(u32).range(1..),
+        help = "Solve each day this many times and report the median timings"
+    )]
+    samples: u32,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Write each day's timings to this file as JSON"
+    )]
+    json: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Write each day's timings to this file as CSV"
+    )]
+    csv: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DayRange(RangeInclusive<u8>);
+
+impl FromStr for DayRange {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (start, end) = value.split_once('-').unwrap_or((value, value));
+
+        let start = start.trim().parse::<u8>()?;
+        let end = end.trim().parse::<u8>()?;
+
+        if !(1..=25).contains(&start) || !(1..=25).contains(&end) {
+            anyhow::bail!("Days must be between 1 and 25: '{value}'");
+        }
+        if start > end {
+            anyhow::bail!("Day range must be ascending: '{value}'");
+        }
+
+        Ok(Self(start..=end))
+    }
+}
+
+impl Display for DayRange {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.0.start() == self.0.end() {
+            write!(formatter, "{}", self.0.start())
+        } else {
+            write!(formatter, "{}-{}", self.0.start(), self.0.end())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Parts {
+    part_1: bool,
+    part_2: bool,
+}
+
+impl Parts {
+    const fn from_opt(part: Option<u8>) -> Self {
+        match part {
+            Some(1) => Self {
+                part_1: true,
+                part_2: false,
+            },
+            Some(2) => Self {
+                part_1: false,
+                part_2: true,
+            },
+            _ => Self {
+                part_1: true,
+                part_2: true,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Report {
+    parse: Duration,
+    parts: Vec<PartReport>,
+}
+
+#[derive(Debug)]
+struct PartReport {
+    part: u8,
+    answer: Answer,
+    elapsed: Duration,
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    let opts = Opts::parse();
+    let parts = Parts::from_opt(opts.part);
+
+    let days = days::all();
+    let selected = select(&days, &opts.days, opts.year)?;
+
+    let data_dir = opts
+        .data_dir
+        .clone()
+        .map_or_else(|| InputSource::data_dir_or("./data"), InputSource::DataDir);
+    let answers = data_dir
+        .data_dir()
+        .map_or_else(|| Ok(Answers::default()), Answers::load)?;
+
+    let source = match opts.input.as_deref() {
+        Some(input) => {
+            if selected.len() != 1 {
+                anyhow::bail!(
+                    "--input requires exactly one day to be selected; use --year to pick one year's day"
+                );
+            }
+            InputSource::from(input)
+        }
+        None => data_dir,
+    };
+    let input_name = source.name();
+
+    let mut timings = Vec::with_capacity(selected.len());
+    let mut failures = 0_usize;
+
+    for day in selected.into_iter().filter_map(|idx| days.get(idx)) {
+        let data = match (day.read_data)(&source) {
+            Ok(data) => data,
+            Err(err) => {
+                println!("{}  {err}", day.name());
+                failures += 1;
+                continue;
+            }
+        };
+        let (report, day_timings) = match sample(day, &data, parts, opts.samples) {
+            Ok(sampled) => sampled,
+            Err(err) => {
+                println!("{}  invalid input: {err}", day.name());
+                failures += 1;
+                continue;
+            }
+        };
+        timings.push(day_timings);
+
+        println!(
+            "{}  parse   {:>10}",
+            day.name(),
+            format_duration(day_timings.parse)
+        );
+
+        for part in &report.parts {
+            let verdict = answers.check(
+                day.year,
+                day.number,
+                part.part,
+                input_name.as_deref(),
+                &part.answer,
+            );
+            let status = match verdict {
+                Verdict::Correct => String::new(),
+                Verdict::Incorrect { expected } => {
+                    failures += 1;
+                    format!("  MISMATCH (expected {expected})")
+                }
+                Verdict::Unverified | _ => String::from("  unverified"),
+            };
+
+            println!(
+                "             part {}  {:>10}  {}{status}",
+                part.part,
+                format_duration(day_timings.part(part.part).unwrap_or(part.elapsed)),
+                part.answer
+            );
+        }
+    }
+
+    println!(
+        "Total                {:>10}",
+        format_duration(timings.iter().map(DayTimings::total).sum())
+    );
+    if opts.samples > 1 && timings.len() > 1 {
+        print!("\n{}", timings::ranking(&timings));
+    }
+
+    write_timings(&opts, &timings)?;
+
+    if failures > 0 {
+        println!("{failures} result(s) failed to load, failed to parse, or did not match the known answers");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// The indices of the `days` in `ranges`, or of every day if there are none, keeping to `year`
+/// if one was given.
+fn select(
+    days: &[days::Day],
+    ranges: &[DayRange],
+    year: Option<u16>,
+) -> anyhow::Result<BTreeSet<usize>> {
+    let in_year = |day: &days::Day| year.is_none_or(|year| year == day.year);
+
+    if ranges.is_empty() {
+        let selected = (0..days.len())
+            .filter(|&idx| days.get(idx).is_some_and(in_year))
+            .collect::<BTreeSet<_>>();
+        if let (true, Some(year)) = (selected.is_empty(), year) {
+            anyhow::bail!("No solutions exist for {year}");
+        }
+        return Ok(selected);
+    }
+
+    let mut selected = BTreeSet::new();
+    for range in ranges {
+        let matching = days
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, day)| (in_year(day) && range.0.contains(&day.number)).then_some(idx))
+            .collect::<Vec<_>>();
+        if matching.is_empty() {
+            match year {
+                Some(year) => anyhow::bail!("No solutions exist for day(s) {range} of {year}"),
+                None => anyhow::bail!("No solutions exist for day(s) {range}"),
+            }
+        }
+        selected.extend(matching);
+    }
+    Ok(selected)
+}
+
+/// Solves a day `samples` times, returning the first run's report and the median timings.
+fn sample(
+    day: &days::Day,
+    data: &str,
+    parts: Parts,
+    samples: u32,
+) -> Result<(Report, DayTimings), ParseError> {
+    let mut reports = vec![(day.solve)(data, parts)?];
+    for _ in 1..samples {
+        reports.push((day.solve)(data, parts)?);
+    }
+
+    let timings = DayTimings::median(day.year, day.number, &reports);
+    let first = reports.swap_remove(0);
+    Ok((first, timings))
+}
+
+fn write_timings(opts: &Opts, timings: &[DayTimings]) -> anyhow::Result<()> {
+    if let Some(path) = &opts.json {
+        fs::write(path, timings::to_json(opts.samples, timings))
+            .with_context(|| format!("Could not write timings to '{}'", path.display()))?;
+    }
+    if let Some(path) = &opts.csv {
+        fs::write(path, timings::to_csv(timings))
+            .with_context(|| format!("Could not write timings to '{}'", path.display()))?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn solve<'d, S: Solution<'d>>(
+    data: &'d str,
+    parts: Parts,
+) -> Result<Report, ParseError> {
+    let parse_start = Instant::now();
+    let input = S::try_from_data(data)?;
+    let parse = parse_start.elapsed();
+
+    let mut reports = Vec::with_capacity(2);
+
+    if parts.part_1 {
+        let part_start = Instant::now();
+        let answer = input.part_1().into();
+        reports.push(PartReport {
+            part: 1,
+            answer,
+            elapsed: part_start.elapsed(),
+        });
+    }
+
+    if parts.part_2 {
+        let part_start = Instant::now();
+        let answer = input.part_2().into();
+        reports.push(PartReport {
+            part: 2,
+            answer,
+            elapsed: part_start.elapsed(),
+        });
+    }
+
+    Ok(Report {
+        parse,
+        parts: reports,
+    })
+}
+
+#[cfg(test)]
+mod aoc_tests {
+    use super::*;
+
+    mod day_range {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: Option<DayRange>,
+        }
+
+        #[test]
+        fn single_day() {
+            run(&Case {
+                input: "16",
+                expected: Some(DayRange(16..=16)),
+            });
+        }
+
+        #[test]
+        fn range() {
+            run(&Case {
+                input: "1-5",
+                expected: Some(DayRange(1..=5)),
+            });
+        }
+
+        #[test]
+        fn descending_range() {
+            run(&Case {
+                input: "5-1",
+                expected: None,
+            });
+        }
+
+        #[test]
+        fn out_of_bounds() {
+            run(&Case {
+                input: "26",
+                expected: None,
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.input.parse().ok());
+        }
+    }
+}
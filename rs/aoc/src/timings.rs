@@ -0,0 +1,294 @@
+use core::{fmt::Write, time::Duration};
+
+use common::format_duration;
+
+use crate::Report;
+
+/// The median time each phase of a day took across every sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DayTimings {
+    pub(crate) year: u16,
+    pub(crate) day: u8,
+    pub(crate) parse: Duration,
+    pub(crate) part_1: Option<Duration>,
+    pub(crate) part_2: Option<Duration>,
+}
+
+impl DayTimings {
+    pub(crate) fn median(year: u16, day: u8, samples: &[Report]) -> Self {
+        let part = |part: u8| {
+            median(
+                samples
+                    .iter()
+                    .flat_map(|sample| &sample.parts)
+                    .filter(|report| report.part == part)
+                    .map(|report| report.elapsed),
+            )
+        };
+
+        Self {
+            year,
+            day,
+            parse: median(samples.iter().map(|sample| sample.parse)).unwrap_or_default(),
+            part_1: part(1),
+            part_2: part(2),
+        }
+    }
+
+    pub(crate) const fn part(&self, part: u8) -> Option<Duration> {
+        match part {
+            1 => self.part_1,
+            2 => self.part_2,
+            _ => None,
+        }
+    }
+
+    pub(crate) fn total(&self) -> Duration {
+        self.parse + self.part_1.unwrap_or_default() + self.part_2.unwrap_or_default()
+    }
+}
+
+/// The lower median, so that it is always one of the measured durations.
+fn median(durations: impl Iterator<Item = Duration>) -> Option<Duration> {
+    let mut durations = durations.collect::<Vec<_>>();
+    durations.sort_unstable();
+    durations
+        .get(0_usize.midpoint(durations.len().checked_sub(1)?))
+        .copied()
+}
+
+fn days_total(days: &[DayTimings]) -> Duration {
+    days.iter().map(DayTimings::total).sum()
+}
+
+/// Every day's median timings in nanoseconds, with the total of every day. Parts that were not
+/// run are `null`.
+pub(crate) fn to_json(samples: u32, days: &[DayTimings]) -> String {
+    let nanos = |duration: Option<Duration>| {
+        duration.map_or_else(
+            || String::from("null"),
+            |duration| duration.as_nanos().to_string(),
+        )
+    };
+
+    let mut json = format!("{{\n  \"samples\": {samples},\n  \"days\": [");
+    for (idx, day) in days.iter().enumerate() {
+        let separator = if idx == 0 { "" } else { "," };
+        _ = write!(
+            json,
+            "{separator}\n    {{\"year\": {}, \"day\": {}, \"parse_ns\": {}, \"part_1_ns\": {}, \"part_2_ns\": {}, \"total_ns\": {}}}",
+            day.year,
+            day.day,
+            day.parse.as_nanos(),
+            nanos(day.part_1),
+            nanos(day.part_2),
+            day.total().as_nanos()
+        );
+    }
+    if !days.is_empty() {
+        json.push_str("\n  ");
+    }
+    _ = write!(
+        json,
+        "],\n  \"total_ns\": {}\n}}\n",
+        days_total(days).as_nanos()
+    );
+
+    json
+}
+
+/// One row of median timings in nanoseconds per day, followed by a `total` row for every day.
+/// Parts that were not run are left empty.
+pub(crate) fn to_csv(days: &[DayTimings]) -> String {
+    let nanos = |duration: Option<Duration>| {
+        duration.map_or_else(String::new, |duration| duration.as_nanos().to_string())
+    };
+
+    let mut csv = String::from("year,day,parse_ns,part_1_ns,part_2_ns,total_ns\n");
+    for day in days {
+        _ = writeln!(
+            csv,
+            "{},{},{},{},{},{}",
+            day.year,
+            day.day,
+            day.parse.as_nanos(),
+            nanos(day.part_1),
+            nanos(day.part_2),
+            day.total().as_nanos()
+        );
+    }
+    _ = writeln!(csv, "total,,,,,{}", days_total(days).as_nanos());
+
+    csv
+}
+
+/// The days ordered from slowest to fastest, with each one's share of their total.
+pub(crate) fn ranking(days: &[DayTimings]) -> String {
+    let total = days_total(days).as_secs_f64();
+
+    let mut ranked = days.to_vec();
+    ranked.sort_by_key(|day| core::cmp::Reverse(day.total()));
+
+    let mut table = String::from("Slowest days\n");
+    for day in ranked {
+        let share = if total > 0.0_f64 {
+            day.total().as_secs_f64() / total * 100.0_f64
+        } else {
+            0.0_f64
+        };
+        _ = writeln!(
+            table,
+            "{} Day {:02}  {:>10}  {share:>5.1}%",
+            day.year,
+            day.day,
+            format_duration(day.total())
+        );
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod timings_tests {
+    use super::*;
+    use crate::PartReport;
+
+    fn report(parse: u64, part_1: u64, part_2: Option<u64>) -> Report {
+        Report {
+            parse: Duration::from_micros(parse),
+            parts: [(1, Some(part_1)), (2, part_2)]
+                .into_iter()
+                .filter_map(|(part, elapsed)| {
+                    elapsed.map(|elapsed| PartReport {
+                        part,
+                        answer: common::Answer::Number(0),
+                        elapsed: Duration::from_micros(elapsed),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    fn days() -> Vec<DayTimings> {
+        vec![
+            DayTimings {
+                year: 2024,
+                day: 1,
+                parse: Duration::from_nanos(100),
+                part_1: Some(Duration::from_nanos(200)),
+                part_2: Some(Duration::from_nanos(300)),
+            },
+            DayTimings {
+                year: 2023,
+                day: 16,
+                parse: Duration::from_micros(1),
+                part_1: Some(Duration::from_nanos(8_400)),
+                part_2: None,
+            },
+        ]
+    }
+
+    mod median {
+        use super::*;
+
+        struct Case {
+            samples: Vec<Report>,
+            expected: DayTimings,
+        }
+
+        #[test]
+        fn odd_samples() {
+            run(&Case {
+                samples: vec![
+                    report(30, 5, Some(7)),
+                    report(10, 9, Some(1)),
+                    report(20, 1, Some(4)),
+                ],
+                expected: DayTimings {
+                    year: 2024,
+                    day: 4,
+                    parse: Duration::from_micros(20),
+                    part_1: Some(Duration::from_micros(5)),
+                    part_2: Some(Duration::from_micros(4)),
+                },
+            });
+        }
+
+        #[test]
+        fn even_samples_use_lower_median() {
+            run(&Case {
+                samples: vec![report(40, 2, None), report(10, 8, None)],
+                expected: DayTimings {
+                    year: 2024,
+                    day: 4,
+                    parse: Duration::from_micros(10),
+                    part_1: Some(Duration::from_micros(2)),
+                    part_2: None,
+                },
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, DayTimings::median(2024, 4, &test.samples));
+        }
+    }
+
+    mod to_json {
+        use super::*;
+
+        #[test]
+        fn days_and_total() {
+            assert_eq!(
+                "{
+  \"samples\": 10,
+  \"days\": [
+    {\"year\": 2024, \"day\": 1, \"parse_ns\": 100, \"part_1_ns\": 200, \"part_2_ns\": 300, \"total_ns\": 600},
+    {\"year\": 2023, \"day\": 16, \"parse_ns\": 1000, \"part_1_ns\": 8400, \"part_2_ns\": null, \"total_ns\": 9400}
+  ],
+  \"total_ns\": 10000
+}
+",
+                to_json(10, &days())
+            );
+        }
+
+        #[test]
+        fn no_days() {
+            assert_eq!(
+                "{\n  \"samples\": 1,\n  \"days\": [],\n  \"total_ns\": 0\n}\n",
+                to_json(1, &[])
+            );
+        }
+    }
+
+    mod to_csv {
+        use super::*;
+
+        #[test]
+        fn days_and_total() {
+            assert_eq!(
+                "year,day,parse_ns,part_1_ns,part_2_ns,total_ns
+2024,1,100,200,300,600
+2023,16,1000,8400,,9400
+total,,,,,10000
+",
+                to_csv(&days())
+            );
+        }
+    }
+
+    mod ranking {
+        use super::*;
+
+        #[test]
+        fn slowest_first() {
+            assert_eq!(
+                "Slowest days
+2023 Day 16      9.4 \u{b5}s   94.0%
+2024 Day 01      600 ns    6.0%
+",
+                ranking(&days())
+            );
+        }
+    }
+}
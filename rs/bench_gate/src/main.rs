@@ -0,0 +1,233 @@
+use core::{fmt::Write, time::Duration};
+use std::{collections::BTreeSet, fs, io, path::PathBuf, process::ExitCode};
+
+use anyhow::Context;
+use clap::Parser;
+use common::format_duration;
+
+mod results;
+
+use results::Results;
+
+#[derive(clap::Parser, Debug)]
+struct Opts {
+    #[arg(
+        long,
+        global = true,
+        default_value = "target/criterion",
+        help = "Directory criterion writes its results to"
+    )]
+    criterion_dir: PathBuf,
+
+    #[arg(
+        long,
+        global = true,
+        default_value = "bench_baseline.csv",
+        help = "Baseline file to compare against or refresh"
+    )]
+    baseline: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Compare the latest `cargo bench` results with the baseline, failing on any regression
+    Check {
+        #[arg(
+            short,
+            long,
+            default_value_t = 10.0,
+            help = "Percentage a phase may slow down by before it counts as a regression"
+        )]
+        threshold: f64,
+    },
+    /// Record the latest `cargo bench` results as the baseline for the days that were run
+    Refresh,
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    run(&Opts::parse())
+}
+
+fn run(opts: &Opts) -> anyhow::Result<ExitCode> {
+    let baseline = match fs::read_to_string(&opts.baseline) {
+        Ok(csv) => Some(
+            results::from_csv(&csv)
+                .with_context(|| format!("Invalid baseline '{}'", opts.baseline.display()))?,
+        ),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+
+    match opts.command {
+        Command::Check { threshold } => {
+            // Without a baseline there is nothing to gate on, so this fails before anything else
+            // rather than letting a missing file pass unnoticed.
+            let Some(baseline) = baseline else {
+                anyhow::bail!(
+                    "No baseline at '{}'; run `cargo bench --workspace --benches` and `bench_gate refresh`, then commit it",
+                    opts.baseline.display()
+                );
+            };
+
+            let current = read_current(opts)?;
+            let (table, regressions) = compare(&baseline, &current, threshold);
+            print!("{table}");
+
+            if regressions > 0 {
+                println!("{regressions} phase(s) regressed by more than {threshold}%");
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Refresh => {
+            let current = read_current(opts)?;
+            let days = current
+                .keys()
+                .map(|&(year, day, _)| (year, day))
+                .collect::<BTreeSet<_>>();
+
+            let mut refreshed = baseline.unwrap_or_default();
+            refreshed.retain(|&(year, day, _), _| !days.contains(&(year, day)));
+            refreshed.extend(&current);
+
+            fs::write(&opts.baseline, results::to_csv(&refreshed))?;
+            println!(
+                "Updated '{}' for {} day(s)",
+                opts.baseline.display(),
+                days.len()
+            );
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// The latest `cargo bench` results, which must include at least one day.
+fn read_current(opts: &Opts) -> anyhow::Result<Results> {
+    let current = results::read_criterion(&opts.criterion_dir)?;
+    if current.is_empty() {
+        anyhow::bail!(
+            "No day benchmarks found in '{}'",
+            opts.criterion_dir.display()
+        );
+    }
+    Ok(current)
+}
+
+/// A table of every phase that was benchmarked, and how many of them slowed down by more than
+/// `threshold` percent. Phases missing from the baseline are listed as new and never fail.
+fn compare(baseline: &Results, current: &Results, threshold: f64) -> (String, usize) {
+    let mut table = format!(
+        "{:<11}  {:<6}  {:>10}  {:>10}  {:>8}\n",
+        "Day", "Phase", "Baseline", "Current", "Change"
+    );
+    let mut regressions = 0_usize;
+
+    for (&(year, day, phase), &nanos) in current {
+        let (baseline, change, status) = match baseline.get(&(year, day, phase)) {
+            Some(&previous) => {
+                let change = (nanos - previous) / previous * 100.0_f64;
+                let status = if change > threshold {
+                    regressions += 1;
+                    "  REGRESSED"
+                } else {
+                    ""
+                };
+                (format_nanos(previous), format!("{change:+.1}%"), status)
+            }
+            None => (String::from("-"), String::from("-"), "  new"),
+        };
+
+        _ = writeln!(
+            table,
+            "{year} Day {day:02}  {:<6}  {baseline:>10}  {:>10}  {change:>8}{status}",
+            phase.name(),
+            format_nanos(nanos)
+        );
+    }
+
+    (table, regressions)
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format_duration(Duration::from_secs_f64(nanos / 1e9))
+}
+
+#[cfg(test)]
+mod bench_gate_tests {
+    use super::*;
+    use results::Phase;
+
+    #[test]
+    fn check_fails_without_baseline() {
+        let opts = Opts::parse_from([
+            "bench_gate",
+            "--baseline",
+            "missing_baseline.csv",
+            "--criterion-dir",
+            "missing_criterion",
+            "check",
+        ]);
+
+        assert_eq!(
+            "No baseline at 'missing_baseline.csv'; run `cargo bench --workspace --benches` and `bench_gate refresh`, then commit it",
+            run(&opts).unwrap_err().to_string()
+        );
+    }
+
+    mod compare {
+        use super::*;
+
+        struct Case {
+            baseline: Results,
+            current: Results,
+            expected: (&'static str, usize),
+        }
+
+        #[test]
+        fn within_threshold() {
+            run(&Case {
+                baseline: Results::from([((2024, 1, Phase::Part1), 1_000.0_f64)]),
+                current: Results::from([((2024, 1, Phase::Part1), 1_090.0_f64)]),
+                expected: (
+                    "Day          Phase     Baseline     Current    Change
+2024 Day 01  part 1      1.0 \u{b5}s      1.1 \u{b5}s     +9.0%
+",
+                    0,
+                ),
+            });
+        }
+
+        #[test]
+        fn regressions_and_new_phases() {
+            run(&Case {
+                baseline: Results::from([
+                    ((2024, 4, Phase::Parse), 2_000.0_f64),
+                    ((2024, 16, Phase::Part2), 2_000_000.0_f64),
+                ]),
+                current: Results::from([
+                    ((2023, 4, Phase::Parse), 1_000.0_f64),
+                    ((2024, 4, Phase::Parse), 1_500.0_f64),
+                    ((2024, 4, Phase::Total), 9_000.0_f64),
+                    ((2024, 16, Phase::Part2), 2_500_000.0_f64),
+                ]),
+                expected: (
+                    "Day          Phase     Baseline     Current    Change
+2023 Day 04  parse            -      1.0 \u{b5}s         -  new
+2024 Day 04  parse       2.0 \u{b5}s      1.5 \u{b5}s    -25.0%
+2024 Day 04  total            -      9.0 \u{b5}s         -  new
+2024 Day 16  part 2      2.0 ms      2.5 ms    +25.0%  REGRESSED
+",
+                    1,
+                ),
+            });
+        }
+
+        fn run(test: &Case) {
+            let (table, regressions) = compare(&test.baseline, &test.current, 10.0_f64);
+            assert_eq!(test.expected, (table.as_str(), regressions));
+        }
+    }
+}
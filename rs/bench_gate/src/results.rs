@@ -0,0 +1,272 @@
+use core::fmt::Write;
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+/// A phase of a day's criterion group, as benchmarked by each crate's `benches/bench.rs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum Phase {
+    Parse,
+    Part1,
+    Part2,
+    Total,
+}
+
+impl Phase {
+    pub(crate) const ALL: [Self; 4] = [Self::Parse, Self::Part1, Self::Part2, Self::Total];
+
+    /// The criterion benchmark id within the day's group.
+    const fn bench_id(self) -> &'static str {
+        match self {
+            Self::Parse => "parse contents",
+            Self::Part1 => "part 1",
+            Self::Part2 => "part 2",
+            Self::Total => "total",
+        }
+    }
+
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part 1",
+            Self::Part2 => "part 2",
+            Self::Total => "total",
+        }
+    }
+}
+
+/// Median benchmark times in nanoseconds, keyed by year, day and phase.
+pub(crate) type Results = BTreeMap<(u16, u8, Phase), f64>;
+
+/// Reads the latest results of every `YYYY Day XX` group from criterion's output directory.
+pub(crate) fn read_criterion(dir: &Path) -> anyhow::Result<Results> {
+    let mut results = Results::new();
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            anyhow::bail!(
+                "No criterion results in '{}'; run `cargo bench` first",
+                dir.display()
+            )
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    for entry in entries {
+        let group = entry?.file_name();
+        let Some((year, day)) = group.to_str().and_then(parse_group) else {
+            continue;
+        };
+
+        for phase in Phase::ALL {
+            let path = dir
+                .join(&group)
+                .join(phase.bench_id())
+                .join("new")
+                .join("estimates.json");
+            let estimates = match fs::read_to_string(&path) {
+                Ok(estimates) => estimates,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err.into()),
+            };
+            let Some(median) = median_estimate(&estimates) else {
+                anyhow::bail!("No median estimate in '{}'", path.display());
+            };
+            let _prev = results.insert((year, day, phase), median);
+        }
+    }
+
+    Ok(results)
+}
+
+/// The year and day of a criterion group named like `2024 Day 01`.
+fn parse_group(group: &str) -> Option<(u16, u8)> {
+    let (year, day) = group.split_once(" Day ")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// The median's point estimate from a criterion `estimates.json`.
+fn median_estimate(json: &str) -> Option<f64> {
+    let (_, median) = json.split_once("\"median\":")?;
+    let (_, estimate) = median.split_once("\"point_estimate\":")?;
+    let end = estimate.find([',', '}'])?;
+    estimate.get(..end)?.trim().parse().ok()
+}
+
+const CSV_HEADER: &str = "year,day,parse_ns,part_1_ns,part_2_ns,total_ns";
+
+/// Parses a baseline written by [`to_csv`]. Empty cells are phases that were not benchmarked.
+pub(crate) fn from_csv(csv: &str) -> anyhow::Result<Results> {
+    let mut lines = csv.lines().map(str::trim).filter(|line| !line.is_empty());
+    if lines.next() != Some(CSV_HEADER) {
+        anyhow::bail!("Expected the baseline to start with '{CSV_HEADER}'");
+    }
+
+    let mut results = Results::new();
+    for line in lines {
+        let mut cells = line.split(',');
+        let year = cells
+            .next()
+            .and_then(|year| year.parse::<u16>().ok())
+            .ok_or_else(|| anyhow::anyhow!("Invalid year in baseline row '{line}'"))?;
+        let day = cells
+            .next()
+            .and_then(|day| day.parse::<u8>().ok())
+            .ok_or_else(|| anyhow::anyhow!("Invalid day in baseline row '{line}'"))?;
+
+        for phase in Phase::ALL {
+            match cells.next() {
+                Some("") => {}
+                Some(cell) => {
+                    let nanos = cell
+                        .parse::<f64>()
+                        .map_err(|err| anyhow::anyhow!("Invalid time in '{line}': {err}"))?;
+                    let _prev = results.insert((year, day, phase), nanos);
+                }
+                None => anyhow::bail!("Missing {} time in baseline row '{line}'", phase.name()),
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// One row per day, with times rounded to the nanosecond.
+pub(crate) fn to_csv(results: &Results) -> String {
+    let mut csv = format!("{CSV_HEADER}\n");
+
+    let mut days = results
+        .keys()
+        .map(|&(year, day, _)| (year, day))
+        .collect::<Vec<_>>();
+    days.dedup();
+    for (year, day) in days {
+        _ = write!(csv, "{year},{day:02}");
+        for phase in Phase::ALL {
+            csv.push(',');
+            if let Some(nanos) = results.get(&(year, day, phase)) {
+                _ = write!(csv, "{nanos:.0}");
+            }
+        }
+        csv.push('\n');
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod results_tests {
+    use super::*;
+
+    mod median_estimate {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: Option<f64>,
+        }
+
+        #[test]
+        fn criterion_estimates() {
+            run(&Case {
+                input: r#"{"mean":{"confidence_interval":{"confidence_level":0.95,"lower_bound":17414.9,"upper_bound":18310.0},"point_estimate":17861.25,"standard_error":228.2},"median":{"confidence_interval":{"confidence_level":0.95,"lower_bound":16474.2,"upper_bound":18498.6},"point_estimate":17629.5,"standard_error":656.9},"median_abs_dev":{"confidence_interval":{"confidence_level":0.95,"lower_bound":2103.7,"upper_bound":3406.8},"point_estimate":3081.5,"standard_error":361.7}}"#,
+                expected: Some(17_629.5_f64),
+            });
+        }
+
+        #[test]
+        fn no_median() {
+            run(&Case {
+                input: r#"{"mean":{"point_estimate":17861.25}}"#,
+                expected: None,
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, median_estimate(test.input));
+        }
+    }
+
+    mod parse_group {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: Option<(u16, u8)>,
+        }
+
+        #[test]
+        fn year_and_day() {
+            run(&Case {
+                input: "2024 Day 01",
+                expected: Some((2024, 1)),
+            });
+        }
+
+        #[test]
+        fn without_year() {
+            run(&Case {
+                input: "Day 01",
+                expected: None,
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, parse_group(test.input));
+        }
+    }
+
+    mod csv {
+        use super::*;
+
+        fn results() -> Results {
+            Results::from([
+                ((2023, 16, Phase::Part2), 900.0_f64),
+                ((2024, 1, Phase::Parse), 1_200.0_f64),
+                ((2024, 1, Phase::Part1), 350.4_f64),
+                ((2024, 1, Phase::Part2), 800.6_f64),
+                ((2024, 1, Phase::Total), 2_400.0_f64),
+                ((2024, 16, Phase::Part1), 1_500_000.0_f64),
+            ])
+        }
+
+        const CSV: &str = "year,day,parse_ns,part_1_ns,part_2_ns,total_ns
+2023,16,,,900,
+2024,01,1200,350,801,2400
+2024,16,,1500000,,
+";
+
+        #[test]
+        fn write() {
+            assert_eq!(CSV, to_csv(&results()));
+        }
+
+        #[test]
+        fn read() {
+            let mut expected = results();
+            expected.extend([
+                ((2024, 1, Phase::Part1), 350.0_f64),
+                ((2024, 1, Phase::Part2), 801.0_f64),
+            ]);
+
+            assert_eq!(expected, from_csv(CSV).unwrap());
+        }
+
+        #[test]
+        fn missing_header() {
+            assert_eq!(
+                "Expected the baseline to start with 'year,day,parse_ns,part_1_ns,part_2_ns,total_ns'",
+                from_csv("2024,01,1,2,3,6").unwrap_err().to_string()
+            );
+        }
+
+        #[test]
+        fn short_row() {
+            assert_eq!(
+                "Missing part 2 time in baseline row '2024,01,1,2'",
+                from_csv(&format!("{CSV_HEADER}\n2024,01,1,2"))
+                    .unwrap_err()
+                    .to_string()
+            );
+        }
+    }
+}
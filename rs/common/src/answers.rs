@@ -0,0 +1,385 @@
+use core::fmt::Display;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Answer,
+};
+
+/// File in the data directory holding the known answers.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Known answers keyed by year, day, part and input name, loaded from [`ANSWERS_FILE`].
+///
+/// Each non-empty line not starting with `#` reads `<year> <day> <part> <input> <answer>`.
+/// Answers that parse as a number are stored as [`Answer::Number`], anything else as
+/// [`Answer::Text`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    known: BTreeMap<(u16, u8, u8, String), Answer>,
+}
+
+impl Answers {
+    /// Loads the registry from `dir`. A missing file is an empty registry, so every answer is
+    /// unverified.
+    pub fn load(dir: &Path) -> Result<Self, LoadError> {
+        let data = match InputSource::DataDir(dir.to_path_buf()).read(ANSWERS_FILE) {
+            Ok(data) => data,
+            Err(err) if err.is_not_found() => return Ok(Self::default()),
+            Err(err) => return Err(LoadError::Read(err)),
+        };
+
+        Self::try_from_data(&data).map_err(|source| LoadError::Parse {
+            path: dir.join(ANSWERS_FILE),
+            source,
+        })
+    }
+
+    pub fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
+        let known = data
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let end_of_line = line.get(line.len()..).unwrap_or_default();
+                let mut fields = line.splitn(5, ' ');
+                let mut field = |expected: &str| {
+                    fields
+                        .next()
+                        .filter(|field| !field.is_empty())
+                        .ok_or_else(|| source.error(end_of_line, expected))
+                };
+
+                let year = source.number(field("a year")?)?;
+                let day = source.number(field("a day")?)?;
+                let part = source.number(field("a part")?)?;
+                let input = field("an input name")?.to_owned();
+                let answer = field("an answer")?.trim();
+                let answer = answer
+                    .parse::<usize>()
+                    .map_or_else(|_err| Answer::from(answer.to_owned()), Answer::from);
+
+                Ok(((year, day, part, input), answer))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Self { known })
+    }
+
+    /// The known answer to `part` of `day` of `year` for the named input.
+    #[must_use]
+    pub fn get(&self, year: u16, day: u8, part: u8, input: &str) -> Option<Answer> {
+        self.known
+            .get(&(year, day, part, input.to_owned()))
+            .cloned()
+    }
+
+    /// Compares `answer` with the known answer, if there is one.
+    #[must_use]
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        input: Option<&str>,
+        answer: &Answer,
+    ) -> Verdict {
+        match input.and_then(|input| self.get(year, day, part, input)) {
+            Some(expected) if expected == *answer => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect { expected },
+            None => Verdict::Unverified,
+        }
+    }
+
+    /// Solves `input` and asserts that it parses and does not contradict the registry, naming
+    /// the input if it fails. Unknown answers pass.
+    #[track_caller]
+    pub fn assert_input<A: Into<Answer>>(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        input: &NamedInput,
+        solve: impl FnOnce(&str) -> Result<A, ParseError>,
+    ) {
+        let failure = match solve(&input.data) {
+            Err(err) => Some(format!("invalid input: {err}")),
+            Ok(answer) => {
+                let answer = answer.into();
+                match self.check(year, day, part, input.name.as_deref(), &answer) {
+                    Verdict::Incorrect { expected } => {
+                        Some(format!("expected {expected}, found {answer}"))
+                    }
+                    Verdict::Correct | Verdict::Unverified => None,
+                }
+            }
+        };
+
+        assert!(
+            failure.is_none(),
+            "input '{}' failed for {year} day {day:02} part {part}: {}",
+            input.name.as_deref().unwrap_or("stdin"),
+            failure.unwrap_or_default()
+        );
+    }
+}
+
+/// How an answer compares with the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        expected: Answer,
+    },
+    /// The registry has no answer for this year, day, part and input.
+    Unverified,
+}
+
+/// Asserts that `answer` matches `expected`, for tests and benches. Unknown answers pass, since
+/// they cannot be verified.
+#[track_caller]
+pub fn assert_known(expected: Option<&Answer>, answer: impl Into<Answer>) {
+    if let Some(expected) = expected {
+        assert_eq!(expected, &answer.into(), "Mismatched answer");
+    }
+}
+
+/// A failure to load the answer registry.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LoadError {
+    Read(ReadError),
+    Parse { path: PathBuf, source: ParseError },
+}
+
+impl Display for LoadError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Read(err) => err.fmt(formatter),
+            Self::Parse { path, source } => {
+                write!(
+                    formatter,
+                    "invalid answers file '{}': {source}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Read(err) => Some(err),
+            Self::Parse { source, .. } => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod answers_tests {
+    use super::*;
+
+    mod try_from_data {
+        use super::*;
+
+        type Known = (u16, u8, u8, &'static str, Answer);
+
+        struct Case {
+            input: &'static str,
+            expected: Result<Vec<Known>, &'static str>,
+        }
+
+        #[test]
+        fn numbers_and_text() {
+            run(&Case {
+                input: "# year day part input answer\n\n2024 01 1 default 2264607\n2024 18 2 alice 30,12",
+                expected: Ok(vec![
+                    (2024, 1, 1, "default", Answer::Number(2_264_607)),
+                    (2024, 18, 2, "alice", Answer::from("30,12")),
+                ]),
+            });
+        }
+
+        #[test]
+        fn missing_answer() {
+            run(&Case {
+                input: "2024 01 1 default",
+                expected: Err("line 1, column 18: expected an answer, found end of input"),
+            });
+        }
+
+        #[test]
+        fn invalid_day() {
+            run(&Case {
+                input: "2024 01 1 default 1\n2024 day 2 default 2",
+                expected: Err("line 2, column 6: expected an integer (u8), found 'day'"),
+            });
+        }
+
+        fn run(test: &Case) {
+            let expected = test
+                .expected
+                .clone()
+                .map(|known| Answers {
+                    known: known
+                        .into_iter()
+                        .map(|(year, day, part, input, answer)| {
+                            ((year, day, part, input.to_owned()), answer)
+                        })
+                        .collect(),
+                })
+                .map_err(str::to_owned);
+
+            assert_eq!(
+                expected,
+                Answers::try_from_data(test.input).map_err(|err| err.to_string())
+            );
+        }
+    }
+
+    mod check {
+        use super::*;
+
+        struct Case {
+            input: Option<&'static str>,
+            answer: Answer,
+            expected: Verdict,
+        }
+
+        #[test]
+        fn correct() {
+            run(&Case {
+                input: Some("default"),
+                answer: Answer::from("30,12"),
+                expected: Verdict::Correct,
+            });
+        }
+
+        #[test]
+        fn incorrect() {
+            run(&Case {
+                input: Some("default"),
+                answer: Answer::from("12,30"),
+                expected: Verdict::Incorrect {
+                    expected: Answer::from("30,12"),
+                },
+            });
+        }
+
+        #[test]
+        fn unknown_input() {
+            run(&Case {
+                input: Some("bob"),
+                answer: Answer::from("30,12"),
+                expected: Verdict::Unverified,
+            });
+        }
+
+        #[test]
+        fn unnamed_input() {
+            run(&Case {
+                input: None,
+                answer: Answer::from("30,12"),
+                expected: Verdict::Unverified,
+            });
+        }
+
+        fn run(test: &Case) {
+            let answers =
+                Answers::try_from_data("2023 18 2 default 12,30\n2024 18 2 default 30,12").unwrap();
+
+            assert_eq!(
+                test.expected,
+                answers.check(2024, 18, 2, test.input, &test.answer)
+            );
+        }
+    }
+
+    mod assert_input {
+        use super::*;
+
+        struct Case {
+            input: (&'static str, &'static str),
+            expected: Option<&'static str>,
+        }
+
+        #[test]
+        fn correct() {
+            run(&Case {
+                input: ("default", "1"),
+                expected: None,
+            });
+        }
+
+        #[test]
+        fn unverified() {
+            run(&Case {
+                input: ("carol", "9"),
+                expected: None,
+            });
+        }
+
+        #[test]
+        fn incorrect() {
+            run(&Case {
+                input: ("alice", "3"),
+                expected: Some("input 'alice' failed for 2024 day 01 part 1: expected 2, found 3"),
+            });
+        }
+
+        #[test]
+        fn invalid() {
+            run(&Case {
+                input: ("bob", "x"),
+                expected: Some(
+                    "input 'bob' failed for 2024 day 01 part 1: invalid input: line 1, column 1: expected an integer (usize), found 'x'",
+                ),
+            });
+        }
+
+        fn run(test: &Case) {
+            let answers = Answers::try_from_data("2024 01 1 default 1\n2024 01 1 alice 2").unwrap();
+            let (name, contents) = test.input;
+            let input = NamedInput {
+                name: Some(name.to_owned()),
+                data: contents.to_owned(),
+            };
+
+            let result = std::panic::catch_unwind(|| {
+                answers.assert_input(2024, 1, 1, &input, |data| {
+                    Source::new(data).number::<usize>(data)
+                });
+            });
+
+            assert_eq!(
+                test.expected,
+                result
+                    .err()
+                    .as_ref()
+                    .and_then(|panic| panic.downcast_ref::<String>())
+                    .map(String::as_str)
+            );
+        }
+    }
+
+    mod load {
+        use super::*;
+
+        #[test]
+        fn missing_file_is_empty() {
+            assert_eq!(
+                Answers::default(),
+                Answers::load(Path::new("missing_dir")).unwrap(),
+                "A missing registry should leave every answer unverified"
+            );
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use core::fmt::Write as _;
+use std::{env, fs, io, path::PathBuf};
+
+use crate::input::{InputSource, DATA_DIR_VAR, DEFAULT_INPUT};
+
+/// File in `OUT_DIR` that [`write_input_tests`] writes, for each part's tests to `include!`.
+pub const INPUT_TESTS_FILE: &str = "input_tests.rs";
+
+/// Writes one `#[test]` per input of a day to [`INPUT_TESTS_FILE`], for a day's build script.
+///
+/// Each test is named after its input, such as `actual_alice`, and calls an `actual` function
+/// taking the input's name, which the including module defines. The inputs are those that
+/// [`InputSource::input_names`] finds in the data directory, or in `default_data_dir` relative to
+/// the crate when [`DATA_DIR_VAR`] is not set. Without any, there is still a test of
+/// [`DEFAULT_INPUT`], so that the missing input is reported when the tests run.
+pub fn write_input_tests(default_data_dir: &str, file_name: &str) -> io::Result<()> {
+    let source = InputSource::data_dir_or(default_data_dir);
+    println!("cargo::rerun-if-env-changed={DATA_DIR_VAR}");
+    if let Some(dir) = source.data_dir() {
+        println!("cargo::rerun-if-changed={}", dir.display());
+    }
+
+    let names = source
+        .input_names(file_name)
+        .unwrap_or_else(|_| vec![DEFAULT_INPUT.to_owned()]);
+    let out_dir = env::var_os("OUT_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| io::Error::other("OUT_DIR is only set for build scripts"))?;
+    fs::write(out_dir.join(INPUT_TESTS_FILE), input_tests(&names))
+}
+
+fn input_tests(names: &[String]) -> String {
+    let mut tests = String::new();
+    for name in names {
+        let ident = name
+            .chars()
+            .map(|char| {
+                if char.is_ascii_alphanumeric() {
+                    char.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+        _ = write!(
+            tests,
+            "#[test]\nfn actual_{ident}() {{\n    actual(\"{}\");\n}}\n\n",
+            name.escape_default()
+        );
+    }
+    tests
+}
+
+#[cfg(test)]
+mod build_tests {
+    use super::*;
+
+    mod input_tests {
+        use super::*;
+
+        struct Case {
+            names: &'static [&'static str],
+            expected: &'static str,
+        }
+
+        #[test]
+        fn one_per_input() {
+            run(&Case {
+                names: &[DEFAULT_INPUT, "alice"],
+                expected: "#[test]\nfn actual_default() {\n    actual(\"default\");\n}\n\n#[test]\nfn actual_alice() {\n    actual(\"alice\");\n}\n\n",
+            });
+        }
+
+        #[test]
+        fn names_become_identifiers() {
+            run(&Case {
+                names: &["Bob-2"],
+                expected: "#[test]\nfn actual_bob_2() {\n    actual(\"Bob-2\");\n}\n\n",
+            });
+        }
+
+        fn run(test: &Case) {
+            let names = test
+                .names
+                .iter()
+                .map(|&name| name.to_owned())
+                .collect::<Vec<_>>();
+            assert_eq!(test.expected, input_tests(&names));
+        }
+    }
+}
@@ -0,0 +1,497 @@
+use crate::parse::{ParseError, Source};
+
+/// A cell in a grid, with `x` counting columns rightwards and `y` counting rows downwards from the
+/// top-left corner.
+///
+/// Positions order row-major: top to bottom, then left to right.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub y: usize,
+    pub x: usize,
+}
+
+impl Pos {
+    /// `(dx, dy)` offsets to the eight surrounding cells, clockwise from straight up.
+    pub const OFFSETS_8: [(isize, isize); 8] = [
+        (0, -1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+        (0, 1),
+        (-1, 1),
+        (-1, 0),
+        (-1, -1),
+    ];
+
+    #[must_use]
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { y, x }
+    }
+
+    /// The adjacent cell in `direction`, or `None` if that would leave the top or left edge.
+    #[must_use]
+    pub const fn step(self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.offset();
+        self.offset(dx, dy)
+    }
+
+    /// The cell `dx` columns and `dy` rows away, or `None` if that would leave the top or left
+    /// edge.
+    #[must_use]
+    pub const fn offset(self, dx: isize, dy: isize) -> Option<Self> {
+        match (self.x.checked_add_signed(dx), self.y.checked_add_signed(dy)) {
+            (Some(x), Some(y)) => Some(Self { y, x }),
+            _ => None,
+        }
+    }
+
+    /// The `(dx, dy)` offset that moves `self` onto `other`.
+    #[must_use]
+    pub fn offset_to(self, other: Self) -> Option<(isize, isize)> {
+        let dx = isize::try_from(other.x)
+            .ok()?
+            .checked_sub(isize::try_from(self.x).ok()?)?;
+        let dy = isize::try_from(other.y)
+            .ok()?
+            .checked_sub(isize::try_from(self.y).ok()?)?;
+        Some((dx, dy))
+    }
+
+    #[must_use]
+    pub const fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The orthogonally adjacent cells, in [`Direction::ALL`] order, that don't leave the top or
+    /// left edge.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(direction))
+    }
+
+    /// The orthogonally and diagonally adjacent cells, in [`Pos::OFFSETS_8`] order, that don't
+    /// leave the top or left edge.
+    pub fn neighbours_8(self) -> impl Iterator<Item = Self> {
+        Self::OFFSETS_8
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(dx, dy))
+    }
+}
+
+/// One of the four orthogonal directions, where up is towards row 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[expect(clippy::exhaustive_enums)]
+pub enum Direction {
+    #[default]
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// The `(dx, dy)` offset of a single step.
+    #[must_use]
+    pub const fn offset(self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Right => (1, 0),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+        }
+    }
+
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// Parses one of the arrows `^`, `>`, `v` and `<`.
+    #[must_use]
+    pub const fn from_arrow(arrow: char) -> Option<Self> {
+        match arrow {
+            '^' => Some(Self::Up),
+            '>' => Some(Self::Right),
+            'v' => Some(Self::Down),
+            '<' => Some(Self::Left),
+            _ => None,
+        }
+    }
+}
+
+/// A dense, rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    #[must_use]
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// A grid holding `rows` top to bottom, or `None` if they aren't all the same width.
+    #[must_use]
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a rectangular map with one cell per character, rejecting any character that `cell`
+    /// maps to `None` as not being one of `expected`.
+    pub fn parse(
+        source: &Source<'_>,
+        data: &str,
+        expected: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Self::parse_with_markers(source, data, expected, [], cell).map(|(grid, [])| grid)
+    }
+
+    /// Parses a map like [`Grid::parse`], also returning where each marker character appears.
+    ///
+    /// Each marker is paired with a description used to report it missing, and is still passed to
+    /// `cell` so the map can decide what lies under it. A marker appearing more than once is
+    /// located at its last appearance.
+    pub fn parse_with_markers<const N: usize>(
+        source: &Source<'_>,
+        data: &str,
+        expected: &str,
+        markers: [(char, &str); N],
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<(Self, [Pos; N]), ParseError> {
+        let mut found = [None; N];
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(data.len());
+
+        for (y, line) in data.lines().enumerate() {
+            let mut row_width = 0;
+            for (x, (idx, char)) in line.char_indices().enumerate() {
+                let value = cell(char).ok_or_else(|| {
+                    source.error(
+                        line.get(idx..idx + char.len_utf8()).unwrap_or_default(),
+                        expected,
+                    )
+                })?;
+                cells.push(value);
+
+                for (location, _) in found
+                    .iter_mut()
+                    .zip(markers)
+                    .filter(|&(_, (marker, _))| marker == char)
+                {
+                    *location = Some(Pos { y, x });
+                }
+                row_width = x + 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(source.error(line, format!("a row {width} cells wide")))
+                }
+                Some(_) => {}
+            }
+            height = y + 1;
+        }
+
+        let mut positions = [Pos::default(); N];
+        for ((position, location), (_, description)) in positions.iter_mut().zip(found).zip(markers)
+        {
+            *position = location.ok_or_else(|| {
+                source.error(data.get(data.len()..).unwrap_or_default(), description)
+            })?;
+        }
+
+        Ok((
+            Self {
+                width: width.unwrap_or_default(),
+                height,
+                cells,
+            },
+            positions,
+        ))
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub const fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    #[must_use]
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).and_then(|idx| self.cells.get(idx))
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).and_then(|idx| self.cells.get_mut(idx))
+    }
+
+    /// Every position in the grid, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos { y, x }))
+    }
+
+    /// Every cell alongside its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The orthogonally adjacent cells that lie inside the grid.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The orthogonally and diagonally adjacent cells that lie inside the grid.
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours_8()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    const fn index_of(&self, pos: Pos) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.y * self.width + pos.x)
+        } else {
+            None
+        }
+    }
+}
+
+/// Overwrites the cell at each position, ignoring positions outside the grid.
+impl<T> Extend<(Pos, T)> for Grid<T> {
+    fn extend<I: IntoIterator<Item = (Pos, T)>>(&mut self, iter: I) {
+        for (pos, value) in iter {
+            if let Some(cell) = self.get_mut(pos) {
+                *cell = value;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+
+    const MAP: &str = "#.S\n..#\nE.#";
+
+    fn parse_map(data: &str) -> Result<(Grid<bool>, [Pos; 2]), ParseError> {
+        Grid::parse_with_markers(
+            &Source::new(data),
+            data,
+            "one of '.', '#', 'S' or 'E'",
+            [('S', "a start tile 'S'"), ('E', "an end tile 'E'")],
+            |char| match char {
+                '#' => Some(true),
+                '.' | 'S' | 'E' => Some(false),
+                _ => None,
+            },
+        )
+    }
+
+    mod parse_with_markers {
+        use super::*;
+
+        #[test]
+        fn markers() {
+            let (grid, [start, end]) = parse_map(MAP).unwrap();
+
+            assert_eq!((3, 3), (grid.width(), grid.height()));
+            assert_eq!(Pos::new(2, 0), start);
+            assert_eq!(Pos::new(0, 2), end);
+            assert_eq!(Some(&true), grid.get(Pos::new(2, 1)));
+            assert_eq!(Some(&false), grid.get(start));
+        }
+
+        #[test]
+        fn unexpected_char() {
+            run(
+                "#.S\n.x#\nE.#",
+                "line 2, column 2: expected one of '.', '#', 'S' or 'E', found 'x'",
+            );
+        }
+
+        #[test]
+        fn ragged_row() {
+            run(
+                "#.S\n..\nE.#",
+                "line 2, column 1: expected a row 3 cells wide, found '..'",
+            );
+        }
+
+        #[test]
+        fn missing_marker() {
+            run(
+                "#.S\n..#\n..#",
+                "line 3, column 4: expected an end tile 'E', found end of input",
+            );
+        }
+
+        fn run(data: &str, expected: &str) {
+            assert_eq!(
+                Some(expected.to_owned()),
+                parse_map(data).err().map(|err| err.to_string())
+            );
+        }
+    }
+
+    mod from_rows {
+        use super::*;
+
+        #[test]
+        fn rectangular() {
+            let grid = Grid::from_rows(vec![vec![1_u8, 2, 3], vec![4, 5, 6]]).unwrap();
+            assert_eq!((3, 2), (grid.width(), grid.height()));
+            assert_eq!(Some(&4), grid.get(Pos::new(0, 1)));
+        }
+
+        #[test]
+        fn ragged() {
+            assert_eq!(None, Grid::from_rows(vec![vec![1_u8, 2, 3], vec![4, 5]]));
+        }
+    }
+
+    mod neighbours {
+        use super::*;
+
+        struct Case {
+            pos: Pos,
+            expected: Vec<Pos>,
+        }
+
+        #[test]
+        fn corner() {
+            run(&Case {
+                pos: Pos::new(0, 0),
+                expected: vec![Pos::new(1, 0), Pos::new(0, 1)],
+            });
+        }
+
+        #[test]
+        fn edge() {
+            run(&Case {
+                pos: Pos::new(2, 1),
+                expected: vec![Pos::new(2, 0), Pos::new(2, 2), Pos::new(1, 1)],
+            });
+        }
+
+        #[test]
+        fn middle() {
+            run(&Case {
+                pos: Pos::new(1, 1),
+                expected: vec![
+                    Pos::new(1, 0),
+                    Pos::new(2, 1),
+                    Pos::new(1, 2),
+                    Pos::new(0, 1),
+                ],
+            });
+        }
+
+        fn run(test: &Case) {
+            let grid = Grid::new(3, 3, ());
+            assert_eq!(test.expected, grid.neighbours(test.pos).collect::<Vec<_>>());
+        }
+    }
+
+    mod neighbours_8 {
+        use super::*;
+
+        #[test]
+        fn corner() {
+            let grid = Grid::new(3, 3, ());
+            assert_eq!(
+                vec![Pos::new(2, 1), Pos::new(1, 2), Pos::new(1, 1)],
+                grid.neighbours_8(Pos::new(2, 2)).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    mod direction {
+        use super::*;
+
+        #[test]
+        fn turns() {
+            for direction in Direction::ALL {
+                assert_eq!(direction, direction.turn_right().turn_left());
+                assert_eq!(direction.reverse(), direction.turn_right().turn_right());
+            }
+        }
+
+        #[test]
+        fn step() {
+            assert_eq!(Some(Pos::new(1, 0)), Pos::new(1, 1).step(Direction::Up));
+            assert_eq!(None, Pos::new(0, 1).step(Direction::Left));
+        }
+    }
+
+    #[test]
+    fn ordering_is_row_major() {
+        assert!(
+            Pos::new(5, 0) < Pos::new(0, 1),
+            "Rows should sort before columns"
+        );
+    }
+}
@@ -0,0 +1,448 @@
+use core::fmt::Display;
+use std::{
+    env,
+    ffi::OsString,
+    fs::{read_dir, read_to_string},
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable that overrides the default data directory.
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// Name of the input read from a data directory, as used in the answer registry.
+pub const DEFAULT_INPUT: &str = "default";
+
+/// A puzzle input and the name it is known by in the answer registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedInput {
+    /// `None` for input read from stdin.
+    pub name: Option<String>,
+    pub data: String,
+}
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InputSource {
+    /// A directory holding one input file per day, such as `data/y2024_day_01.txt`.
+    DataDir(PathBuf),
+    /// A single file, used whichever day is being solved.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// The directory named by [`DATA_DIR_VAR`] if it is set, otherwise `default`.
+    #[must_use]
+    pub fn data_dir_or(default: impl Into<PathBuf>) -> Self {
+        Self::data_dir_from(env::var_os(DATA_DIR_VAR), default)
+    }
+
+    fn data_dir_from(var: Option<OsString>, default: impl Into<PathBuf>) -> Self {
+        Self::DataDir(
+            var.filter(|dir| !dir.is_empty())
+                .map_or_else(|| default.into(), PathBuf::from),
+        )
+    }
+
+    /// The data directory this source reads from, if any.
+    #[must_use]
+    pub fn data_dir(&self) -> Option<&Path> {
+        match self {
+            Self::DataDir(dir) => Some(dir),
+            Self::File(_) | Self::Stdin => None,
+        }
+    }
+
+    /// Identifies the input in the answer registry: [`DEFAULT_INPUT`] for a data directory, the
+    /// file stem for a single file, and `None` for stdin.
+    #[must_use]
+    pub fn name(&self) -> Option<String> {
+        match self {
+            Self::DataDir(_) => Some(DEFAULT_INPUT.to_owned()),
+            Self::File(path) => path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned()),
+            Self::Stdin => None,
+        }
+    }
+
+    /// Reads the input, trimmed of surrounding whitespace. `file_name` is only used when reading
+    /// from a data directory.
+    pub fn read(&self, file_name: &str) -> Result<String, ReadError> {
+        let contents = match self {
+            Self::DataDir(dir) => {
+                let path = dir.join(file_name);
+                read_to_string(&path).map_err(|source| ReadError {
+                    path: Some(path),
+                    source,
+                })?
+            }
+            Self::File(path) => read_to_string(path).map_err(|source| ReadError {
+                path: Some(path.clone()),
+                source,
+            })?,
+            Self::Stdin => {
+                let mut contents = String::new();
+                _ = io::stdin()
+                    .read_to_string(&mut contents)
+                    .map_err(|source| ReadError { path: None, source })?;
+                contents
+            }
+        };
+
+        Ok(contents.trim().to_owned())
+    }
+
+    /// Reads every input for a day, sorted by name, as listed by [`Self::input_names`].
+    pub fn read_all(&self, file_name: &str) -> Result<Vec<NamedInput>, ReadError> {
+        self.input_names(file_name)?
+            .iter()
+            .map(|name| self.read_named(file_name, name))
+            .collect()
+    }
+
+    /// Names every input for a day, sorted. From a data directory that is `file_name`, named
+    /// [`DEFAULT_INPUT`], plus each `.txt` file in the directory named after its stem, so
+    /// `y2024_day_01.txt` is joined by `y2024_day_01/alice.txt` as `alice`. Either may be absent,
+    /// but not both. Files with a second extension, such as the encrypted `alice.enc.txt`, are
+    /// not inputs. Any other source has the one input, named as by [`Self::name`].
+    pub fn input_names(&self, file_name: &str) -> Result<Vec<String>, ReadError> {
+        let Self::DataDir(dir) = self else {
+            return Ok(vec![self.name().unwrap_or_else(|| String::from("stdin"))]);
+        };
+
+        let mut names = Vec::new();
+        let default_file = dir.join(file_name);
+        if default_file.is_file() {
+            names.push(DEFAULT_INPUT.to_owned());
+        }
+
+        let named_dir = Self::named_dir(dir, file_name);
+        if named_dir.is_dir() {
+            let entries = read_dir(&named_dir).map_err(|source| ReadError {
+                path: Some(named_dir.clone()),
+                source,
+            })?;
+            for entry in entries {
+                let path = entry
+                    .map_err(|source| ReadError {
+                        path: Some(named_dir.clone()),
+                        source,
+                    })?
+                    .path();
+                let is_input = path.extension().is_some_and(|extension| extension == "txt")
+                    && path
+                        .file_stem()
+                        .is_some_and(|stem| Path::new(stem).extension().is_none());
+                if let (true, Some(name)) = (is_input, Self::File(path).name()) {
+                    names.push(name);
+                }
+            }
+        }
+
+        if names.is_empty() {
+            return Err(ReadError {
+                path: Some(default_file),
+                source: io::ErrorKind::NotFound.into(),
+            });
+        }
+        names.sort_unstable();
+        Ok(names)
+    }
+
+    /// Reads the input `name` of a day, as listed by [`Self::input_names`]. Only a data directory
+    /// has more than one input, so any other source reads its own whatever the name.
+    pub fn read_named(&self, file_name: &str, name: &str) -> Result<NamedInput, ReadError> {
+        let source = match self {
+            Self::DataDir(dir) if name != DEFAULT_INPUT => {
+                Self::File(Self::named_dir(dir, file_name).join(format!("{name}.txt")))
+            }
+            Self::DataDir(_) | Self::File(_) | Self::Stdin => self.clone(),
+        };
+        Ok(NamedInput {
+            name: source.name(),
+            data: source.read(file_name)?,
+        })
+    }
+
+    /// The directory holding the named inputs, `y2024_day_01` for `y2024_day_01.txt`.
+    fn named_dir(dir: &Path, file_name: &str) -> PathBuf {
+        dir.join(Path::new(file_name).with_extension(""))
+    }
+}
+
+impl From<&str> for InputSource {
+    /// Interprets a command-line argument: `-` is stdin, anything else is a file path.
+    fn from(value: &str) -> Self {
+        if value == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(value))
+        }
+    }
+}
+
+/// A failure to read puzzle input, naming the file that could not be read.
+#[derive(Debug)]
+pub struct ReadError {
+    path: Option<PathBuf>,
+    source: io::Error,
+}
+
+impl ReadError {
+    pub(crate) fn is_not_found(&self) -> bool {
+        self.source.kind() == io::ErrorKind::NotFound
+    }
+
+    /// The file that could not be read, or `None` for stdin.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.path {
+            Some(path) => write!(
+                formatter,
+                "failed to read input file '{}': {}",
+                path.display(),
+                self.source
+            ),
+            None => write!(
+                formatter,
+                "failed to read input from stdin: {}",
+                self.source
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod input_tests {
+    use super::*;
+
+    mod data_dir_from {
+        use super::*;
+
+        struct Case {
+            var: Option<&'static str>,
+            expected: InputSource,
+        }
+
+        #[test]
+        fn unset() {
+            run(&Case {
+                var: None,
+                expected: InputSource::DataDir(PathBuf::from("./data")),
+            });
+        }
+
+        #[test]
+        fn empty() {
+            run(&Case {
+                var: Some(""),
+                expected: InputSource::DataDir(PathBuf::from("./data")),
+            });
+        }
+
+        #[test]
+        fn set() {
+            run(&Case {
+                var: Some("/tmp/inputs"),
+                expected: InputSource::DataDir(PathBuf::from("/tmp/inputs")),
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                test.expected,
+                InputSource::data_dir_from(test.var.map(OsString::from), "./data")
+            );
+        }
+    }
+
+    mod from {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: InputSource,
+        }
+
+        #[test]
+        fn stdin() {
+            run(&Case {
+                input: "-",
+                expected: InputSource::Stdin,
+            });
+        }
+
+        #[test]
+        fn file() {
+            run(&Case {
+                input: "data/example.txt",
+                expected: InputSource::File(PathBuf::from("data/example.txt")),
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, InputSource::from(test.input));
+        }
+    }
+
+    mod name {
+        use super::*;
+
+        struct Case {
+            input: InputSource,
+            expected: Option<&'static str>,
+        }
+
+        #[test]
+        fn data_dir() {
+            run(&Case {
+                input: InputSource::DataDir(PathBuf::from("./data")),
+                expected: Some(DEFAULT_INPUT),
+            });
+        }
+
+        #[test]
+        fn file() {
+            run(&Case {
+                input: InputSource::File(PathBuf::from("inputs/alice.txt")),
+                expected: Some("alice"),
+            });
+        }
+
+        #[test]
+        fn stdin() {
+            run(&Case {
+                input: InputSource::Stdin,
+                expected: None,
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.input.name().as_deref());
+        }
+    }
+
+    mod read {
+        use super::*;
+
+        #[test]
+        fn missing_file_is_named() {
+            let err = InputSource::DataDir(PathBuf::from("missing_dir"))
+                .read("day_01.txt")
+                .unwrap_err();
+
+            assert_eq!(
+                Some(Path::new("missing_dir/day_01.txt")),
+                err.path(),
+                "The error should carry the joined path"
+            );
+            assert!(
+                err.to_string()
+                    .starts_with("failed to read input file 'missing_dir/day_01.txt': "),
+                "Unexpected message: {err}"
+            );
+        }
+
+        #[test]
+        fn all_named_inputs() {
+            let dir = env::temp_dir().join("common_input_tests_all_named_inputs");
+            std::fs::create_dir_all(dir.join("day_01")).unwrap();
+            std::fs::write(dir.join("day_01.txt"), "1\n").unwrap();
+            std::fs::write(dir.join("day_01/bob.txt"), "3\n").unwrap();
+            std::fs::write(dir.join("day_01/alice.txt"), "2\n").unwrap();
+            std::fs::write(dir.join("day_01/notes.md"), "ignored").unwrap();
+
+            let inputs = InputSource::DataDir(dir.clone()).read_all("day_01.txt");
+            std::fs::remove_dir_all(&dir).unwrap();
+
+            let named = |name: &str, data: &str| NamedInput {
+                name: Some(name.to_owned()),
+                data: data.to_owned(),
+            };
+            assert_eq!(
+                vec![
+                    named("alice", "2"),
+                    named("bob", "3"),
+                    named(DEFAULT_INPUT, "1")
+                ],
+                inputs.unwrap()
+            );
+        }
+
+        #[test]
+        fn skips_encrypted_inputs() {
+            let dir = env::temp_dir().join("common_input_tests_skips_encrypted_inputs");
+            std::fs::create_dir_all(dir.join("day_03")).unwrap();
+            std::fs::write(dir.join("day_03/alice.txt"), "2").unwrap();
+            std::fs::write(dir.join("day_03/alice.enc.txt"), "c2FsdA==").unwrap();
+
+            let inputs = InputSource::DataDir(dir.clone()).read_all("day_03.txt");
+            std::fs::remove_dir_all(&dir).unwrap();
+
+            assert_eq!(
+                vec![NamedInput {
+                    name: Some(String::from("alice")),
+                    data: String::from("2"),
+                }],
+                inputs.unwrap()
+            );
+        }
+
+        #[test]
+        fn only_named_inputs() {
+            let dir = env::temp_dir().join("common_input_tests_only_named_inputs");
+            std::fs::create_dir_all(dir.join("day_02")).unwrap();
+            std::fs::write(dir.join("day_02/alice.txt"), "2").unwrap();
+
+            let inputs = InputSource::DataDir(dir.clone()).read_all("day_02.txt");
+            std::fs::remove_dir_all(&dir).unwrap();
+
+            assert_eq!(
+                vec![NamedInput {
+                    name: Some(String::from("alice")),
+                    data: String::from("2"),
+                }],
+                inputs.unwrap()
+            );
+        }
+
+        #[test]
+        fn no_inputs_names_default_file() {
+            let err = InputSource::DataDir(PathBuf::from("missing_dir"))
+                .read_all("day_01.txt")
+                .unwrap_err();
+
+            assert_eq!(
+                Some(Path::new("missing_dir/day_01.txt")),
+                err.path(),
+                "The error should name the default input"
+            );
+        }
+
+        #[test]
+        fn file_is_trimmed() {
+            let path = env::temp_dir().join("common_input_tests_trimmed.txt");
+            std::fs::write(&path, "\n1 2\n3 4\n\n").unwrap();
+
+            let contents = InputSource::File(path.clone()).read("ignored.txt");
+            std::fs::remove_file(&path).unwrap();
+
+            assert_eq!("1 2\n3 4", contents.unwrap());
+        }
+    }
+}
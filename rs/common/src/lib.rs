@@ -0,0 +1,177 @@
+use std::borrow::Cow;
+
+use core::{fmt::Display, time::Duration};
+
+pub mod answers;
+pub mod build;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod search;
+
+use parse::ParseError;
+
+/// The answer to one part of a day's puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Answer {
+    Number(usize),
+    Text(Cow<'static, str>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Number(number) => number.fmt(formatter),
+            Self::Text(text) => text.fmt(formatter),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(Cow::Owned(value))
+    }
+}
+
+impl From<&'static str> for Answer {
+    fn from(value: &'static str) -> Self {
+        Self::Text(Cow::Borrowed(value))
+    }
+}
+
+/// A single day's puzzle: parsed from the raw input, then solved one part at a time.
+pub trait Solution<'d>: Sized {
+    /// Year of the event the puzzle is from.
+    const YEAR: u16;
+
+    /// Day of the month the puzzle was released on.
+    const DAY: u8;
+
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn try_from_data(data: &'d str) -> Result<Self, ParseError>;
+
+    /// Parses input that is known to be well-formed, such as the committed puzzle inputs.
+    #[must_use]
+    #[expect(clippy::panic)]
+    fn from_data(data: &'d str) -> Self {
+        Self::try_from_data(data).unwrap_or_else(|err| panic!("Invalid input: {err}"))
+    }
+
+    #[must_use]
+    fn part_1(&self) -> Self::Part1;
+
+    #[must_use]
+    fn part_2(&self) -> Self::Part2;
+}
+
+/// Formats a duration with a unit suited to its size, such as `45.1 µs`.
+#[must_use]
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos} ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.1} \u{b5}s", duration.as_secs_f64() * 1e6)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1} ms", duration.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.2} s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod common_tests {
+    use super::*;
+
+    mod answer {
+        use super::*;
+
+        struct Case {
+            answer: Answer,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn number() {
+            run(&Case {
+                answer: Answer::from(2_264_607_usize),
+                expected: "2264607",
+            });
+        }
+
+        #[test]
+        fn text() {
+            run(&Case {
+                answer: Answer::from(String::from("30,12")),
+                expected: "30,12",
+            });
+        }
+
+        #[test]
+        fn borrowed_and_owned_text_are_equal() {
+            assert_eq!(
+                Answer::from("30,12"),
+                Answer::from(String::from("30,12")),
+                "Borrowed and owned answers should compare by value"
+            );
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.answer.to_string());
+        }
+    }
+
+    mod format_duration {
+        use super::*;
+
+        struct Case {
+            input: Duration,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn nanos() {
+            run(&Case {
+                input: Duration::from_nanos(999),
+                expected: "999 ns",
+            });
+        }
+
+        #[test]
+        fn micros() {
+            run(&Case {
+                input: Duration::from_nanos(45_120),
+                expected: "45.1 \u{b5}s",
+            });
+        }
+
+        #[test]
+        fn millis() {
+            run(&Case {
+                input: Duration::from_micros(1_260),
+                expected: "1.3 ms",
+            });
+        }
+
+        #[test]
+        fn seconds() {
+            run(&Case {
+                input: Duration::from_millis(2_500),
+                expected: "2.50 s",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, format_duration(test.input));
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use core::{any::type_name, fmt::Display, str::FromStr};
+
+/// A failure to parse puzzle input, pointing at the offending line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    expected: String,
+    found: String,
+}
+
+impl ParseError {
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    #[must_use]
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    #[must_use]
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// The raw puzzle input being parsed, used to locate slices of it when reporting errors.
+///
+/// Every `&str` handed to a `Source` method must be a slice of the data it was created from;
+/// anything else is reported as being at the end of the input.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'d> {
+    data: &'d str,
+}
+
+impl<'d> Source<'d> {
+    const MAX_FOUND_CHARS: usize = 20;
+
+    #[must_use]
+    pub const fn new(data: &'d str) -> Self {
+        Self { data }
+    }
+
+    /// An error located at the start of `at`.
+    #[must_use]
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let offset = self.offset_of(at);
+        let before = self.data.get(..offset).unwrap_or_default();
+
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .map_or(0, |line_start| line_start.chars().count())
+            + 1;
+
+        let found = if at.is_empty() {
+            self.data.get(offset..).unwrap_or_default()
+        } else {
+            at
+        };
+        let found = match found.lines().next() {
+            None => String::from("end of input"),
+            Some("") => String::from("end of line"),
+            Some(text) if text.chars().count() > Self::MAX_FOUND_CHARS => format!(
+                "'{}...'",
+                text.chars().take(Self::MAX_FOUND_CHARS).collect::<String>()
+            ),
+            Some(text) => format!("'{text}'"),
+        };
+
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    /// An error for something that never appeared before the end of the input.
+    #[must_use]
+    pub fn missing(&self, expected: impl Into<String>) -> ParseError {
+        self.error(
+            self.data.get(self.data.len()..).unwrap_or_default(),
+            expected,
+        )
+    }
+
+    pub fn number<T: FromStr>(&self, token: &'d str) -> Result<T, ParseError> {
+        token
+            .parse()
+            .map_err(|_err| self.error(token, format!("an integer ({})", type_name::<T>())))
+    }
+
+    pub fn strip_prefix(&self, value: &'d str, prefix: &str) -> Result<&'d str, ParseError> {
+        value
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(value, format!("'{}'", prefix.escape_debug())))
+    }
+
+    /// Splits `value` around the first `delimiter`, reporting its absence at the end of `value`.
+    pub fn split_once(
+        &self,
+        value: &'d str,
+        delimiter: &str,
+    ) -> Result<(&'d str, &'d str), ParseError> {
+        value.split_once(delimiter).ok_or_else(|| {
+            self.error(
+                value.get(value.len()..).unwrap_or_default(),
+                format!("'{}'", delimiter.escape_debug()),
+            )
+        })
+    }
+
+    fn offset_of(&self, at: &str) -> usize {
+        at.as_ptr()
+            .addr()
+            .checked_sub(self.data.as_ptr().addr())
+            .filter(|offset| *offset <= self.data.len())
+            .unwrap_or(self.data.len())
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    const DATA: &str = "3   4\n4   3\n2   x\n1";
+
+    mod error {
+        use super::*;
+
+        struct Case {
+            error: ParseError,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn token() {
+            let source = Source::new(DATA);
+            let token = DATA.get(16..17).unwrap();
+            run(&Case {
+                error: source.number::<usize>(token).unwrap_err(),
+                expected: "line 3, column 5: expected an integer (usize), found 'x'",
+            });
+        }
+
+        #[test]
+        fn missing_delimiter() {
+            let source = Source::new(DATA);
+            let last_line = DATA.lines().last().unwrap();
+            run(&Case {
+                error: source.split_once(last_line, "   ").unwrap_err(),
+                expected: "line 4, column 2: expected '   ', found end of input",
+            });
+        }
+
+        #[test]
+        fn missing_prefix() {
+            let source = Source::new(DATA);
+            let second_line = DATA.lines().nth(1).unwrap();
+            run(&Case {
+                error: source.strip_prefix(second_line, "p=").unwrap_err(),
+                expected: "line 2, column 1: expected 'p=', found '4   3'",
+            });
+        }
+
+        #[test]
+        fn end_of_line() {
+            let source = Source::new(DATA);
+            let first_line = DATA.lines().next().unwrap();
+            run(&Case {
+                error: source.split_once(first_line, ",").unwrap_err(),
+                expected: "line 1, column 6: expected ',', found end of line",
+            });
+        }
+
+        #[test]
+        fn missing() {
+            run(&Case {
+                error: Source::new(DATA).missing("a start tile 'S'"),
+                expected: "line 4, column 2: expected a start tile 'S', found end of input",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.error.to_string());
+        }
+    }
+}
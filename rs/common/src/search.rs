@@ -0,0 +1,452 @@
+use core::{cmp::Reverse, hash::Hash};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+/// The outcome of a breadth-first or Dijkstra search: the cheapest cost of every explored state,
+/// and for each of them every predecessor that reaches it at that cost.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S> {
+    distances: HashMap<S, usize>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    fn new(starts: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            distances: starts.into_iter().map(|start| (start, 0)).collect(),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// Records reaching `next` from `state` at `cost`, returning whether it improved on the best
+    /// known cost and so needs exploring.
+    fn relax(&mut self, state: &S, next: S, cost: usize) -> bool {
+        match self.distances.get(&next) {
+            Some(&existing) if existing < cost => false,
+            Some(&existing) if existing == cost => {
+                if let Some(predecessors) = self.predecessors.get_mut(&next) {
+                    predecessors.push(state.clone());
+                }
+                false
+            }
+            _ => {
+                _ = self.distances.insert(next.clone(), cost);
+                let _prev = self.predecessors.insert(next, vec![state.clone()]);
+                true
+            }
+        }
+    }
+
+    /// Every goal state reached at the cheapest goal cost, in the order they were found.
+    #[must_use]
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// The cost of the cheapest goal, if any goal was reached.
+    #[must_use]
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    #[must_use]
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// The cheapest cost of every state explored.
+    #[must_use]
+    pub const fn distances(&self) -> &HashMap<S, usize> {
+        &self.distances
+    }
+
+    /// Every state that reaches `state` along one of its cheapest paths.
+    #[must_use]
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One cheapest path from a start to `to`, including both ends.
+    #[must_use]
+    pub fn path(&self, to: &S) -> Option<Vec<S>> {
+        _ = self.distances.get(to)?;
+
+        let mut path = vec![to.clone()];
+        let mut current = to;
+        while let Some(previous) = self.predecessors(current).first() {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every state lying on at least one cheapest path to any of `targets`, including the targets.
+    #[must_use]
+    pub fn on_paths_to<'p>(&'p self, targets: impl IntoIterator<Item = &'p S>) -> HashSet<&'p S> {
+        let mut on_paths = HashSet::new();
+        let mut to_visit = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(*target))
+            .collect::<Vec<_>>();
+        while let Some(state) = to_visit.pop() {
+            if on_paths.insert(state) {
+                to_visit.extend(self.predecessors(state));
+            }
+        }
+        on_paths
+    }
+
+    /// How many distinct cheapest paths lead from the starts to `to`.
+    #[must_use]
+    pub fn count_paths(&self, to: &S) -> usize {
+        let on_paths = self.on_paths_to([to]);
+        let mut ordered = on_paths.into_iter().collect::<Vec<_>>();
+        ordered.sort_by_key(|state| self.distances.get(*state));
+
+        let mut counts = HashMap::<&S, usize>::with_capacity(ordered.len());
+        for state in ordered {
+            let predecessors = self.predecessors(state);
+            let count = if predecessors.is_empty() {
+                1
+            } else {
+                predecessors
+                    .iter()
+                    .filter_map(|previous| counts.get(previous))
+                    .sum()
+            };
+            _ = counts.insert(state, count);
+        }
+
+        counts.get(to).copied().unwrap_or_default()
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+///
+/// Stops once every goal at the cheapest goal distance has been found, or explores everything
+/// reachable if `is_goal` never matches.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = ShortestPaths::new(starts);
+    let mut queue = paths.distances.keys().cloned().collect::<VecDeque<_>>();
+    let mut goal_distance = None;
+
+    while let Some(state) = queue.pop_front() {
+        let distance = paths.distance(&state).unwrap_or_default();
+        if goal_distance.is_some_and(|best| distance > best) {
+            break;
+        }
+
+        if is_goal(&state) {
+            goal_distance = Some(distance);
+            paths.goals.push(state);
+            continue;
+        }
+
+        for next in neighbours(&state) {
+            if paths.relax(&state, next.clone(), distance + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm over positive step costs.
+///
+/// Stops once every goal at the cheapest goal cost has been found, or explores everything
+/// reachable if `is_goal` never matches.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut paths = ShortestPaths::new(starts);
+    let mut queue = paths
+        .distances
+        .keys()
+        .map(|start| Reverse((0, start.clone())))
+        .collect::<BinaryHeap<_>>();
+    let mut goal_distance = None;
+
+    while let Some(Reverse((distance, state))) = queue.pop() {
+        if paths.distance(&state).is_some_and(|best| best < distance) {
+            continue;
+        }
+        if goal_distance.is_some_and(|best| distance > best) {
+            break;
+        }
+
+        if is_goal(&state) {
+            goal_distance = Some(distance);
+            paths.goals.push(state);
+            continue;
+        }
+
+        for (next, cost) in neighbours(&state) {
+            if paths.relax(&state, next.clone(), distance + cost) {
+                queue.push(Reverse((distance + cost, next)));
+            }
+        }
+    }
+
+    paths
+}
+
+/// A* search for a single cheapest path to a goal, guided by a `heuristic` that must never
+/// overestimate the remaining cost.
+///
+/// Returns the path's cost and its states, including both ends.
+pub fn astar<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut came_from = HashMap::<S, S>::new();
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, start))]);
+
+    while let Some(Reverse((_estimate, distance, state))) = queue.pop() {
+        if distances.get(&state).is_some_and(|&best| best < distance) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            let mut path = vec![state];
+            while let Some(previous) = path.last().and_then(|current| came_from.get(current)) {
+                path.push(previous.clone());
+            }
+            path.reverse();
+            return Some((distance, path));
+        }
+
+        for (next, cost) in neighbours(&state) {
+            let next_distance = distance + cost;
+            if distances
+                .get(&next)
+                .is_some_and(|&existing| existing <= next_distance)
+            {
+                continue;
+            }
+
+            _ = distances.insert(next.clone(), next_distance);
+            let _prev = came_from.insert(next.clone(), state.clone());
+            queue.push(Reverse((
+                next_distance + heuristic(&next),
+                next_distance,
+                next,
+            )));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    use crate::grid::{Grid, Pos};
+
+    /// Two equally short routes around a wall, plus a dead end.
+    const MAZE: &str = "S..\n.#.\n..E\n#..";
+
+    fn maze() -> (Grid<bool>, [Pos; 2]) {
+        Grid::parse_with_markers(
+            &crate::parse::Source::new(MAZE),
+            MAZE,
+            "a maze",
+            [('S', "a start"), ('E', "an end")],
+            |char| Some(char == '#'),
+        )
+        .unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<bool>, pos: Pos) -> Vec<Pos> {
+        grid.neighbours(pos)
+            .filter(|next| grid.get(*next) == Some(&false))
+            .collect()
+    }
+
+    mod bfs {
+        use super::*;
+
+        #[test]
+        fn goal_distance() {
+            let (grid, [start, end]) = maze();
+            let paths = bfs(
+                [start],
+                |pos| open_neighbours(&grid, *pos),
+                |pos| *pos == end,
+            );
+
+            assert_eq!(Some(4), paths.goal_distance());
+            assert_eq!([end], paths.goals());
+        }
+
+        #[test]
+        fn all_shortest_paths() {
+            let (grid, [start, end]) = maze();
+            let paths = bfs(
+                [start],
+                |pos| open_neighbours(&grid, *pos),
+                |pos| *pos == end,
+            );
+
+            assert_eq!(2, paths.count_paths(&end));
+            assert_eq!(8, paths.on_paths_to([&end]).len());
+            assert_eq!(
+                2,
+                paths.predecessors(&end).len(),
+                "The end should be reachable from both sides of the wall"
+            );
+        }
+
+        #[test]
+        fn path() {
+            let (grid, [start, end]) = maze();
+            let paths = bfs(
+                [start],
+                |pos| open_neighbours(&grid, *pos),
+                |pos| *pos == end,
+            );
+            let path = paths.path(&end).unwrap();
+
+            assert_eq!(5, path.len());
+            assert_eq!(Some(&start), path.first());
+            assert_eq!(Some(&end), path.last());
+            assert!(
+                path.windows(2).all(|pair| match pair {
+                    [lhs, rhs] => lhs.manhattan(*rhs) == 1,
+                    _ => false,
+                }),
+                "Each step should move to an adjacent cell"
+            );
+        }
+
+        #[test]
+        fn explores_everything_without_goal() {
+            let (grid, [start, _]) = maze();
+            let paths = bfs([start], |pos| open_neighbours(&grid, *pos), |_| false);
+
+            assert_eq!(None, paths.goal_distance());
+            assert_eq!(Some(4), paths.distance(&Pos::new(1, 3)));
+            assert_eq!(10, paths.distances().len());
+        }
+    }
+
+    mod dijkstra {
+        use super::*;
+
+        #[test]
+        fn weighted() {
+            // Cells along the top and right edges are cheap to enter, so the route hugs them.
+            let (grid, [start, end]) = maze();
+            let paths = dijkstra(
+                [start],
+                |pos| {
+                    open_neighbours(&grid, *pos)
+                        .into_iter()
+                        .map(|next| (next, if next.y == 0 || next.x == 2 { 1 } else { 10 }))
+                        .collect::<Vec<_>>()
+                },
+                |pos| *pos == end,
+            );
+
+            assert_eq!(Some(4), paths.goal_distance());
+            assert_eq!(1, paths.count_paths(&end));
+            assert_eq!(
+                Some(vec![
+                    start,
+                    Pos::new(1, 0),
+                    Pos::new(2, 0),
+                    Pos::new(2, 1),
+                    end
+                ]),
+                paths.path(&end)
+            );
+        }
+
+        #[test]
+        fn unreachable() {
+            let (grid, [start, _]) = maze();
+            let paths = dijkstra(
+                [start],
+                |pos| {
+                    open_neighbours(&grid, *pos)
+                        .into_iter()
+                        .map(|next| (next, 1))
+                        .collect::<Vec<_>>()
+                },
+                |pos| *pos == Pos::new(0, 3),
+            );
+
+            assert_eq!(None, paths.goal_distance());
+            assert_eq!(None, paths.path(&Pos::new(0, 3)));
+        }
+    }
+
+    mod astar {
+        use super::*;
+
+        #[test]
+        fn manhattan() {
+            let (grid, [start, end]) = maze();
+            let (distance, path) = astar(
+                start,
+                |pos| {
+                    open_neighbours(&grid, *pos)
+                        .into_iter()
+                        .map(|next| (next, 1))
+                        .collect::<Vec<_>>()
+                },
+                |pos| pos.manhattan(end),
+                |pos| *pos == end,
+            )
+            .unwrap();
+
+            assert_eq!(4, distance);
+            assert_eq!(5, path.len());
+            assert_eq!(Some(&end), path.last());
+        }
+
+        #[test]
+        fn unreachable() {
+            let (grid, [start, _]) = maze();
+            assert_eq!(
+                None,
+                astar(
+                    start,
+                    |pos| {
+                        open_neighbours(&grid, *pos)
+                            .into_iter()
+                            .map(|next| (next, 1))
+                            .collect::<Vec<_>>()
+                    },
+                    |_| 0,
+                    |pos| *pos == Pos::new(0, 3),
+                )
+            );
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use common::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_01.txt")
+}
+
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_01.txt", name)
+}
+
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Input {
+    lhs: Vec<usize>,
+    rhs: Vec<usize>,
+}
+
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
+        let (mut lhs, mut rhs): (Vec<_>, Vec<_>) = data
+            .trim()
+            .lines()
+            .map(|line| {
+                let (lhs, rhs) = source.split_once(line.trim(), "   ")?;
+                Ok((source.number::<usize>(lhs)?, source.number::<usize>(rhs)?))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?
+            .into_iter()
+            .unzip();
+
+        lhs.sort_unstable();
+        rhs.sort_unstable();
+
+        Ok(Self { lhs, rhs })
+    }
+
+    fn part_1(&self) -> usize {
+        self.lhs
+            .iter()
+            .zip(&self.rhs)
+            .map(|(left, right)| left.abs_diff(*right))
+            .sum()
+    }
+
+    fn part_2(&self) -> usize {
+        self.lhs
+            .iter()
+            .map(|left| left * self.rhs.iter().filter(|right| left.eq(right)).count())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod y2024_day_01_tests {
+    use super::*;
+    use common::answers::Answers;
+
+    const DATA_DIR: &str = "../../data";
+
+    mod from_data {
+        use super::*;
+
+        struct Case<'c> {
+            input: &'c str,
+            expected: Input,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                input: super::example().0,
+                expected: super::example().1,
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(test.expected, Input::from_data(test.input));
+        }
+    }
+
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn missing_separator() {
+            run(&Case {
+                input: "3   4\n4 3",
+                expected: "line 2, column 4: expected '   ', found end of input",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
+    mod part_1 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: 11,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_1());
+        }
+    }
+
+    mod part_2 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: 31,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_2());
+        }
+    }
+
+    fn example() -> (&'static str, Input) {
+        (
+            "3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+            Input {
+                lhs: vec![1, 2, 3, 3, 3, 4],
+                rhs: vec![3, 3, 3, 4, 5, 9],
+            },
+        )
+    }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
+}
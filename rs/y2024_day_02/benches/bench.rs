@@ -0,0 +1,49 @@
+use common::{
+    answers::{assert_known, Answers},
+    input::{InputSource, DEFAULT_INPUT},
+    Solution,
+};
+use criterion::Criterion;
+use y2024_day_02::{read_data, Input};
+
+fn main() {
+    bench();
+
+    Criterion::default().configure_from_args().final_summary();
+}
+
+fn bench() {
+    let mut criterion = Criterion::default().configure_from_args();
+
+    let source = InputSource::data_dir_or("../../data");
+    let contents = read_data(&source).unwrap();
+    let input = Input::from_data(&contents);
+
+    let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+    let part_1 = answers.get(Input::YEAR, Input::DAY, 1, DEFAULT_INPUT);
+    let part_2 = answers.get(Input::YEAR, Input::DAY, 2, DEFAULT_INPUT);
+
+    let mut group = criterion.benchmark_group("2024 Day 02");
+
+    let _parse = group.bench_with_input("parse contents", &contents, |bench, data| {
+        bench.iter(|| Input::from_data(data));
+    });
+
+    let _part_1 = group.bench_with_input("part 1", &input, |bench, day| {
+        bench.iter(|| assert_known(part_1.as_ref(), day.part_1()));
+    });
+
+    let _part_2 = group.bench_with_input("part 2", &input, |bench, day| {
+        bench.iter(|| assert_known(part_2.as_ref(), day.part_2()));
+    });
+
+    let _total = group.bench_with_input("total", &contents, |bench, data| {
+        bench.iter(|| {
+            let day = Input::from_data(data);
+            assert_known(part_1.as_ref(), day.part_1());
+            assert_known(part_2.as_ref(), day.part_2());
+        });
+    });
+
+    group.finish();
+}
@@ -0,0 +1,3 @@
+fn main() -> std::io::Result<()> {
+    common::build::write_input_tests("../../data", "y2024_day_02.txt")
+}
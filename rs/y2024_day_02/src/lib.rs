@@ -1,14 +1,15 @@
-use std::{fs::read_to_string, path::Path};
-
-pub const PART_1: usize = 686;
-pub const PART_2: usize = 717;
+use common::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_02.txt")
+}
 
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("day_02.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_02.txt", name)
 }
 
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -16,26 +17,32 @@ pub struct Input {
     list: Vec<Vec<u8>>,
 }
 
-impl Input {
-    #[must_use]
-    pub fn from_data(data: &str) -> Self {
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
         let list = data
             .trim()
             .lines()
             .map(|line| {
                 line.trim()
                     .split_ascii_whitespace()
-                    .map(|entry| entry.parse::<u8>().unwrap())
-                    .collect::<Vec<_>>()
+                    .map(|entry| source.number::<u8>(entry))
+                    .collect::<Result<Vec<_>, _>>()
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Self { list }
+        Ok(Self { list })
     }
 
-    #[must_use]
     #[expect(clippy::indexing_slicing)]
-    pub fn part_1(&self) -> usize {
+    fn part_1(&self) -> usize {
         self.list
             .iter()
             .filter(|levels| {
@@ -48,9 +55,8 @@ impl Input {
             .count()
     }
 
-    #[must_use]
     #[expect(clippy::indexing_slicing)]
-    pub fn part_2(&self) -> usize {
+    fn part_2(&self) -> usize {
         self.list
             .iter()
             .filter(|&levels| {
@@ -75,8 +81,9 @@ impl Input {
 }
 
 #[cfg(test)]
-mod day_02_tests {
+mod y2024_day_02_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -101,6 +108,32 @@ mod day_02_tests {
         }
     }
 
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn invalid_level() {
+            run(&Case {
+                input: "7 6 4 2 1\n1 2 -7 8 9",
+                expected: "line 2, column 5: expected an integer (u8), found '-7'",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
     mod part_1 {
         use super::*;
 
@@ -117,11 +150,12 @@ mod day_02_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
 
@@ -146,11 +180,12 @@ mod day_02_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
             });
         }
 
@@ -179,4 +214,10 @@ mod day_02_tests {
             },
         )
     }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
 }
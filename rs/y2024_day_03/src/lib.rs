@@ -1,14 +1,15 @@
-use std::{fs::read_to_string, path::Path};
-
-pub const PART_1: usize = 182_619_815;
-pub const PART_2: usize = 80_747_545;
+use common::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::ParseError,
+    Solution,
+};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_03.txt")
+}
 
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("day_03.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_03.txt", name)
 }
 
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -16,14 +17,18 @@ pub struct Input<'s> {
     input: &'s str,
 }
 
-impl<'s> Input<'s> {
-    #[must_use]
-    pub const fn from_data(data: &'s str) -> Self {
-        Self { input: data }
+impl<'s> Solution<'s> for Input<'s> {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &'s str) -> Result<Self, ParseError> {
+        Ok(Self { input: data })
     }
 
-    #[must_use]
-    pub fn part_1(&self) -> usize {
+    fn part_1(&self) -> usize {
         regex::Regex::new(r"(mul\(\d{1,3},\d{1,3}\))")
             .unwrap()
             .captures_iter(self.input)
@@ -41,8 +46,7 @@ impl<'s> Input<'s> {
             .sum()
     }
 
-    #[must_use]
-    pub fn part_2(&self) -> usize {
+    fn part_2(&self) -> usize {
         regex::Regex::new(r"(mul\(\d{1,3},\d{1,3}\)|do\(\)|don\'t\(\))")
             .unwrap()
             .captures_iter(self.input)
@@ -72,8 +76,9 @@ impl<'s> Input<'s> {
 }
 
 #[cfg(test)]
-mod day_03_tests {
+mod y2024_day_03_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -122,11 +127,12 @@ mod day_03_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
 
@@ -151,11 +157,12 @@ mod day_03_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
             });
         }
 
@@ -181,4 +188,10 @@ mod day_03_tests {
             },
         )
     }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
 }
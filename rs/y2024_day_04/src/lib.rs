@@ -0,0 +1,209 @@
+use common::{
+    grid::{Grid, Pos},
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_04.txt")
+}
+
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_04.txt", name)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Input {
+    grid: Grid<u8>,
+}
+
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(&Source::new(data), data, "an ASCII letter", |char| {
+            u8::try_from(char).ok()
+        })?;
+        Ok(Self { grid })
+    }
+
+    fn part_1(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|&(_, letter)| *letter == b'X')
+            .map(|(pos, _)| {
+                Pos::OFFSETS_8
+                    .into_iter()
+                    .filter(|&(dx, dy)| {
+                        (1..).zip(*b"MAS").all(|(distance, letter)| {
+                            pos.offset(dx * distance, dy * distance)
+                                .and_then(|next| self.grid.get(next))
+                                == Some(&letter)
+                        })
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    fn part_2(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|&(pos, letter)| {
+                let corner = |dx, dy| pos.offset(dx, dy).and_then(|next| self.grid.get(next));
+                let is_mas = |lhs, rhs| {
+                    matches!(
+                        (lhs, rhs),
+                        (Some(&b'M'), Some(&b'S')) | (Some(&b'S'), Some(&b'M'))
+                    )
+                };
+
+                *letter == b'A'
+                    && is_mas(corner(-1, -1), corner(1, 1))
+                    && is_mas(corner(1, -1), corner(-1, 1))
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod y2024_day_04_tests {
+    use super::*;
+    use common::answers::Answers;
+
+    const DATA_DIR: &str = "../../data";
+
+    mod from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: Input,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                input: super::example().0,
+                expected: super::example().1,
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, Input::from_data(test.input));
+        }
+    }
+
+    mod part_1 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: 18,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_1());
+        }
+    }
+
+    mod part_2 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: 9,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_2());
+        }
+    }
+
+    fn example() -> (&'static str, Input) {
+        (
+            "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX",
+            Input {
+                grid: {
+                    let mut grid = Grid::new(10, 10, 0);
+                    grid.extend(
+                        [
+                            b"MMMSXXMASM",
+                            b"MSAMXMSMSA",
+                            b"AMXSXMAAMM",
+                            b"MSAMASMSMX",
+                            b"XMASAMXAMM",
+                            b"XXAMMXXAMA",
+                            b"SMSMSASXSS",
+                            b"SAXAMASAAA",
+                            b"MAMMMXMMMM",
+                            b"MXMXAXMASX",
+                        ]
+                        .into_iter()
+                        .enumerate()
+                        .flat_map(|(y, row)| {
+                            row.iter()
+                                .enumerate()
+                                .map(move |(x, letter)| (Pos::new(x, y), *letter))
+                        }),
+                    );
+                    grid
+                },
+            },
+        )
+    }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
+}
@@ -1,15 +1,16 @@
+use common::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
 use core::cmp::Ordering;
-use std::{fs::read_to_string, path::Path};
 
-pub const PART_1: usize = 5091;
-pub const PART_2: usize = 4681;
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_05.txt")
+}
 
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("day_05.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_05.txt", name)
 }
 
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -18,33 +19,39 @@ pub struct Input {
     pages: Vec<Vec<u8>>,
 }
 
-impl Input {
-    #[must_use]
-    pub fn from_data(data: &str) -> Self {
-        let (rules, pages) = data.trim().split_once("\n\n").unwrap();
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
+        let (rules, pages) = source.split_once(data.trim(), "\n\n")?;
 
-        let mut page_ordering_rules: Vec<[u8; 2]> = rules
+        let mut page_ordering_rules = rules
             .lines()
             .map(|line| {
-                let (lhs, rhs) = line.split_once('|').unwrap();
-                [lhs.parse().unwrap(), rhs.parse().unwrap()]
+                let (lhs, rhs) = source.split_once(line, "|")?;
+                Ok([source.number(lhs)?, source.number(rhs)?])
             })
-            .collect();
+            .collect::<Result<Vec<[u8; 2]>, ParseError>>()?;
         page_ordering_rules.sort_unstable();
 
         let pages = pages
             .lines()
-            .map(|line| line.split(',').map(|num| num.parse().unwrap()).collect())
-            .collect();
+            .map(|line| line.split(',').map(|num| source.number(num)).collect())
+            .collect::<Result<_, _>>()?;
 
-        Self {
+        Ok(Self {
             page_ordering_rules,
             pages,
-        }
+        })
     }
 
-    #[must_use]
-    pub fn part_1(&self) -> usize {
+    fn part_1(&self) -> usize {
         self.pages
             .iter()
             .filter_map(|page| {
@@ -68,8 +75,7 @@ impl Input {
             .sum()
     }
 
-    #[must_use]
-    pub fn part_2(&self) -> usize {
+    fn part_2(&self) -> usize {
         self.pages
             .iter()
             .filter(|page| {
@@ -112,8 +118,9 @@ impl Input {
 }
 
 #[cfg(test)]
-mod day_05_tests {
+mod y2024_day_05_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -138,6 +145,32 @@ mod day_05_tests {
         }
     }
 
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn missing_pages() {
+            run(&Case {
+                input: "47|53\n97|13",
+                expected: "line 2, column 6: expected '\\n\\n', found end of input",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
     mod part_1 {
         use super::*;
 
@@ -154,11 +187,12 @@ mod day_05_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
 
@@ -183,11 +217,12 @@ mod day_05_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
             });
         }
 
@@ -261,4 +296,10 @@ mod day_05_tests {
             },
         )
     }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
 }
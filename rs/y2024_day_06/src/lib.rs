@@ -0,0 +1,252 @@
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use std::collections::{HashMap, HashSet};
+
+use common::{
+    grid::{Direction, Grid, Pos},
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_06.txt")
+}
+
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_06.txt", name)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Input {
+    obstacles: Grid<bool>,
+    guard_position: Pos,
+}
+
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let (obstacles, [guard_position]) = Grid::parse_with_markers(
+            &Source::new(data),
+            data.trim(),
+            "one of '.', '#' or '^'",
+            [('^', "a guard '^'")],
+            |char| match char {
+                '#' => Some(true),
+                '.' | '^' => Some(false),
+                _ => None,
+            },
+        )?;
+
+        Ok(Self {
+            obstacles,
+            guard_position,
+        })
+    }
+
+    fn part_1(&self) -> usize {
+        self.walk(None).unwrap().len()
+    }
+
+    fn part_2(&self) -> usize {
+        let initial_path = self.walk(None).unwrap();
+
+        initial_path
+            .into_par_iter()
+            .filter(|&obstacle| {
+                obstacle != self.guard_position && self.walk(Some(obstacle)).is_none()
+            })
+            .count()
+    }
+}
+
+impl Input {
+    fn walk(&self, extra_obstacle: Option<Pos>) -> Option<HashSet<Pos>> {
+        let mut guard = self.guard_position;
+        let mut guard_facing = Direction::Up;
+
+        let mut visited = HashMap::<Pos, Vec<Direction>>::new();
+        loop {
+            if let Some(facings) = visited.get_mut(&guard) {
+                if facings.contains(&guard_facing) {
+                    return None;
+                }
+                facings.push(guard_facing);
+            } else {
+                let _prev = visited.insert(guard, vec![guard_facing]);
+            }
+
+            let Some(next_location) = guard.step(guard_facing) else {
+                return Some(visited.into_keys().collect());
+            };
+
+            match self.obstacles.get(next_location) {
+                None => return Some(visited.into_keys().collect()),
+                Some(&is_obstacle) if is_obstacle || extra_obstacle == Some(next_location) => {
+                    guard_facing = guard_facing.turn_right();
+                }
+                Some(_) => guard = next_location,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod y2024_day_06_tests {
+    use super::*;
+    use common::answers::Answers;
+
+    const DATA_DIR: &str = "../../data";
+
+    mod from_data {
+        use super::*;
+
+        struct Case<'c> {
+            input: &'c str,
+            expected: Input,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                input: super::example().0,
+                expected: super::example().1,
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(test.expected, Input::from_data(test.input));
+        }
+    }
+
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn missing_guard() {
+            run(&Case {
+                input: "....#\n.....",
+                expected: "line 2, column 6: expected a guard '^', found end of input",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
+    mod part_1 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: 41,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_1());
+        }
+    }
+
+    mod part_2 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: 6,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_2());
+        }
+    }
+
+    fn example() -> (&'static str, Input) {
+        (
+            "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...",
+            Input {
+                obstacles: {
+                    let mut obstacles = Grid::new(10, 10, false);
+                    obstacles.extend(
+                        [
+                            Pos::new(4, 0),
+                            Pos::new(9, 1),
+                            Pos::new(2, 3),
+                            Pos::new(7, 4),
+                            Pos::new(1, 6),
+                            Pos::new(8, 7),
+                            Pos::new(0, 8),
+                            Pos::new(6, 9),
+                        ]
+                        .map(|pos| (pos, true)),
+                    );
+                    obstacles
+                },
+                guard_position: Pos::new(4, 6),
+            },
+        )
+    }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
+}
@@ -1,16 +1,17 @@
-use std::{fs::read_to_string, path::Path};
-
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 
-pub const PART_1: usize = 663_613_490_587;
-pub const PART_2: usize = 110_365_987_435_001;
+use common::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_07.txt")
+}
 
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("day_07.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_07.txt", name)
 }
 
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -18,30 +19,36 @@ pub struct Input {
     equations: Vec<(usize, Vec<usize>)>,
 }
 
-impl Input {
-    #[must_use]
-    pub fn from_data(data: &str) -> Self {
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
         let equations = data
             .trim()
             .lines()
             .map(|line| {
-                let (total, parts) = line.split_once(": ").unwrap();
-                (
-                    total.parse().unwrap(),
+                let (total, parts) = source.split_once(line, ": ")?;
+                Ok((
+                    source.number(total)?,
                     parts
                         .trim()
                         .split_ascii_whitespace()
-                        .map(|part| part.parse().unwrap())
-                        .collect(),
-                )
+                        .map(|part| source.number(part))
+                        .collect::<Result<_, _>>()?,
+                ))
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, ParseError>>()?;
 
-        Self { equations }
+        Ok(Self { equations })
     }
 
-    #[must_use]
-    pub fn part_1(&self) -> usize {
+    fn part_1(&self) -> usize {
         self.equations
             .iter()
             .filter_map(|(target_ref, parts)| {
@@ -64,8 +71,7 @@ impl Input {
             .sum()
     }
 
-    #[must_use]
-    pub fn part_2(&self) -> usize {
+    fn part_2(&self) -> usize {
         self.equations
             .par_iter()
             .filter_map(|(target_ref, parts)| {
@@ -110,8 +116,9 @@ impl Input {
 }
 
 #[cfg(test)]
-mod day_07_tests {
+mod y2024_day_07_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -136,6 +143,32 @@ mod day_07_tests {
         }
     }
 
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn invalid_operand() {
+            run(&Case {
+                input: "190: 10 19\n3267: 81 forty 27",
+                expected: "line 2, column 10: expected an integer (usize), found 'forty'",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
     mod part_1 {
         use super::*;
 
@@ -152,11 +185,12 @@ mod day_07_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
 
@@ -181,11 +215,12 @@ mod day_07_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
             });
         }
 
@@ -220,4 +255,10 @@ mod day_07_tests {
             },
         )
     }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
 }
@@ -0,0 +1,251 @@
+use common::{
+    grid::{Grid, Pos},
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
+use core::iter::successors;
+use std::collections::{HashMap, HashSet};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_08.txt")
+}
+
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_08.txt", name)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Input {
+    map: Grid<u8>,
+    antennae: HashMap<u8, Vec<Pos>>,
+}
+
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(
+            &Source::new(data),
+            data.trim(),
+            "an ASCII character",
+            |char| u8::try_from(char).ok(),
+        )?;
+
+        let mut antennae = HashMap::new();
+        for (pos, &char) in map.iter() {
+            if char == b'.' {
+                continue;
+            }
+
+            _ = antennae
+                .entry(char)
+                .and_modify(|locs: &mut Vec<Pos>| {
+                    locs.push(pos);
+                })
+                .or_insert_with(|| vec![pos]);
+        }
+
+        Ok(Self { map, antennae })
+    }
+
+    fn part_1(&self) -> usize {
+        self.antennae
+            .values()
+            .fold(HashSet::<Pos>::new(), |mut acc, locations| {
+                for &lhs in locations {
+                    for &rhs in locations {
+                        if lhs == rhs {
+                            continue;
+                        }
+
+                        let Some((x, y)) = rhs.offset_to(lhs) else {
+                            continue;
+                        };
+
+                        acc.extend(
+                            [lhs.offset(x, y), rhs.offset(-x, -y)]
+                                .into_iter()
+                                .flatten()
+                                .filter(|node| self.map.contains(*node)),
+                        );
+                    }
+                }
+                acc
+            })
+            .len()
+    }
+
+    fn part_2(&self) -> usize {
+        self.antennae
+            .values()
+            .fold(HashSet::<Pos>::new(), |mut acc, locations| {
+                for &lhs in locations {
+                    _ = acc.insert(lhs);
+                    for &rhs in locations {
+                        if lhs == rhs {
+                            continue;
+                        }
+
+                        let Some((x, y)) = rhs.offset_to(lhs) else {
+                            continue;
+                        };
+
+                        for (start, [step_x, step_y]) in [(lhs, [x, y]), (rhs, [-x, -y])] {
+                            acc.extend(
+                                successors(start.offset(step_x, step_y), |node| {
+                                    node.offset(step_x, step_y)
+                                })
+                                .take_while(|node| self.map.contains(*node)),
+                            );
+                        }
+                    }
+                }
+                acc
+            })
+            .len()
+    }
+}
+
+#[cfg(test)]
+mod y2024_day_08_tests {
+    use super::*;
+    use common::answers::Answers;
+
+    const DATA_DIR: &str = "../../data";
+
+    mod from_data {
+        use super::*;
+
+        struct Case<'c> {
+            input: &'c str,
+            expected: Input,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                input: super::example().0,
+                expected: super::example().1,
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(test.expected, Input::from_data(test.input));
+        }
+    }
+
+    mod part_1 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: 14,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_1());
+        }
+    }
+
+    mod part_2 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: 34,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_2());
+        }
+    }
+
+    fn example() -> (&'static str, Input) {
+        (
+            "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............",
+            Input {
+                map: {
+                    let mut map = Grid::new(12, 12, b'.');
+                    map.extend([
+                        (Pos::new(8, 1), b'0'),
+                        (Pos::new(5, 2), b'0'),
+                        (Pos::new(7, 3), b'0'),
+                        (Pos::new(4, 4), b'0'),
+                        (Pos::new(6, 5), b'A'),
+                        (Pos::new(8, 8), b'A'),
+                        (Pos::new(9, 9), b'A'),
+                    ]);
+                    map
+                },
+                antennae: HashMap::from([
+                    (
+                        b'0',
+                        vec![
+                            Pos::new(8, 1),
+                            Pos::new(5, 2),
+                            Pos::new(7, 3),
+                            Pos::new(4, 4),
+                        ],
+                    ),
+                    (b'A', vec![Pos::new(6, 5), Pos::new(8, 8), Pos::new(9, 9)]),
+                ]),
+            },
+        )
+    }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
+}
@@ -1,15 +1,16 @@
+use common::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
 use core::iter;
-use std::{fs::read_to_string, path::Path};
 
-pub const PART_1: usize = 6_334_655_979_668;
-pub const PART_2: usize = 6_349_492_251_099;
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_09.txt")
+}
 
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("day_09.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_09.txt", name)
 }
 
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -17,15 +18,37 @@ pub struct Input {
     disk_map: Vec<u8>,
 }
 
-impl Input {
-    #[must_use]
-    pub fn from_data(data: &str) -> Self {
-        let disk_map = data.trim().bytes().map(|byte| byte - b'0').collect();
-        Self { disk_map }
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
+        let disk_map_str = data.trim();
+        let disk_map = disk_map_str
+            .char_indices()
+            .map(|(idx, char)| {
+                char.to_digit(10)
+                    .and_then(|digit| u8::try_from(digit).ok())
+                    .ok_or_else(|| {
+                        source.error(
+                            disk_map_str
+                                .get(idx..idx + char.len_utf8())
+                                .unwrap_or_default(),
+                            "a digit",
+                        )
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { disk_map })
     }
 
-    #[must_use]
-    pub fn part_1(&self) -> usize {
+    fn part_1(&self) -> usize {
         let mut map =
             self.disk_map
                 .iter()
@@ -61,8 +84,7 @@ impl Input {
             .sum()
     }
 
-    #[must_use]
-    pub fn part_2(&self) -> usize {
+    fn part_2(&self) -> usize {
         let mut map = self
             .disk_map
             .iter()
@@ -138,8 +160,9 @@ enum DiskMapEntry {
 }
 
 #[cfg(test)]
-mod day_09_tests {
+mod y2024_day_09_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -164,6 +187,32 @@ mod day_09_tests {
         }
     }
 
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn invalid_digit() {
+            run(&Case {
+                input: "2333133121414131a402",
+                expected: "line 1, column 17: expected a digit, found 'a'",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
     mod part_1 {
         use super::*;
 
@@ -180,11 +229,12 @@ mod day_09_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
 
@@ -209,11 +259,12 @@ mod day_09_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
             });
         }
 
@@ -230,4 +281,10 @@ mod day_09_tests {
             },
         )
     }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
 }
@@ -1,135 +1,94 @@
-use std::collections::{BTreeSet, HashSet};
-use std::{fs::read_to_string, path::Path};
-
-pub const PART_1: usize = 698;
-pub const PART_2: usize = 1436;
-
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("day_10.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+use common::{
+    grid::{Grid, Pos},
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    search::{bfs, ShortestPaths},
+    Solution,
+};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_10.txt")
 }
 
-#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_10.txt", name)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
 pub struct Input {
-    map: Vec<Vec<u8>>,
+    map: Grid<u8>,
 }
 
-impl Input {
-    #[must_use]
-    pub fn from_data(data: &str) -> Self {
-        let map = data
-            .trim()
-            .lines()
-            .map(|line| {
-                line.bytes()
-                    .map(|byte| if byte == b'.' { 10 } else { byte - b'0' })
-                    .collect()
-            })
-            .collect();
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(
+            &Source::new(data),
+            data.trim(),
+            "a digit or '.'",
+            |char| match char {
+                '.' => Some(10),
+                _ => char.to_digit(10).and_then(|digit| u8::try_from(digit).ok()),
+            },
+        )?;
 
-        Self { map }
+        Ok(Self { map })
     }
 
-    #[must_use]
-    pub fn part_1(&self) -> usize {
-        self.map
-            .iter()
-            .enumerate()
-            .flat_map(|(y, vals)| {
-                vals.iter()
-                    .copied()
-                    .enumerate()
-                    .map(move |(x, val)| (y, x, val))
+    fn part_1(&self) -> usize {
+        self.trailheads()
+            .map(|trails| {
+                trails
+                    .distances()
+                    .keys()
+                    .filter(|&&pos| self.map.get(pos) == Some(&9))
+                    .count()
             })
-            .filter_map(|(y, x, val)| (val == 0).then_some((y, x)))
-            .map(|trailhead| {
-                let mut found_ends = HashSet::new();
-                let mut working_set = BTreeSet::from([(trailhead.0, trailhead.1, 0_u8)]);
-                while let Some((y, x, val)) = working_set.pop_first() {
-                    if val == 9 {
-                        _ = found_ends.insert((y, x));
-                        continue;
-                    }
-
-                    for new in [
-                        x.checked_add(1).map(|new_x| (y, new_x)),
-                        y.checked_add(1).map(|new_y| (new_y, x)),
-                        x.checked_sub(1).map(|new_x| (y, new_x)),
-                        y.checked_sub(1).map(|new_y| (new_y, x)),
-                    ]
-                    .into_iter()
-                    .flatten()
-                    .filter_map(|(new_y, new_x)| {
-                        self.map
-                            .get(new_y)
-                            .and_then(|ys| ys.get(new_x))
-                            .and_then(|new_val| {
-                                (*new_val == val + 1).then_some((new_y, new_x, *new_val))
-                            })
-                    }) {
-                        _ = working_set.insert(new);
-                    }
-                }
-                found_ends.len()
+            .sum()
+    }
+
+    fn part_2(&self) -> usize {
+        self.trailheads()
+            .map(|trails| {
+                trails
+                    .distances()
+                    .keys()
+                    .filter(|&&pos| self.map.get(pos) == Some(&9))
+                    .map(|end| trails.count_paths(end))
+                    .sum::<usize>()
             })
             .sum()
     }
+}
 
-    #[must_use]
-    pub fn part_2(&self) -> usize {
+impl Input {
+    /// Every uphill trail from each trailhead. Each step climbs exactly one, so every route to a
+    /// cell is also a shortest one.
+    fn trailheads(&self) -> impl Iterator<Item = ShortestPaths<Pos>> + '_ {
         self.map
             .iter()
-            .enumerate()
-            .flat_map(|(y, vals)| {
-                vals.iter()
-                    .copied()
-                    .enumerate()
-                    .map(move |(x, val)| (y, x, val))
-            })
-            .filter_map(|(y, x, val)| (val == 0).then_some((y, x)))
-            .map(|trailhead| {
-                let mut found_trails = 0;
-                let mut working_set = BTreeSet::from([(vec![trailhead], 0_u8)]);
-                while let Some((trail, val)) = working_set.pop_first() {
-                    if val == 9 {
-                        found_trails += 1;
-                        continue;
-                    }
-                    let (y, x) = trail.last().copied().unwrap();
-
-                    for (new_y, new_x, new_val) in [
-                        x.checked_add(1).map(|new_x| (y, new_x)),
-                        y.checked_add(1).map(|new_y| (new_y, x)),
-                        x.checked_sub(1).map(|new_x| (y, new_x)),
-                        y.checked_sub(1).map(|new_y| (new_y, x)),
-                    ]
-                    .into_iter()
-                    .flatten()
-                    .filter_map(|(new_y, new_x)| {
-                        self.map
-                            .get(new_y)
-                            .and_then(|ys| ys.get(new_x))
-                            .and_then(|new_val| {
-                                (*new_val == val + 1).then_some((new_y, new_x, *new_val))
-                            })
-                    }) {
-                        let mut new_trail = trail.clone();
-                        new_trail.push((new_y, new_x));
-                        _ = working_set.insert((new_trail, new_val));
-                    }
-                }
-                found_trails
-            })
-            .sum()
+            .filter_map(|(pos, &val)| (val == 0).then_some(pos))
+            .map(|trailhead| bfs([trailhead], |&pos| self.uphill(pos), |_| false))
+    }
+
+    /// Neighbours of `pos` exactly one step higher than it.
+    fn uphill(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let val = self.map.get(pos).copied().unwrap_or_default();
+        self.map
+            .neighbours(pos)
+            .filter(move |&new_pos| self.map.get(new_pos) == Some(&(val + 1)))
     }
 }
 
 #[cfg(test)]
-mod day_10_tests {
+mod y2024_day_10_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -194,6 +153,32 @@ mod day_10_tests {
         }
     }
 
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn invalid_height() {
+            run(&Case {
+                input: "0123\n1x34",
+                expected: "line 2, column 2: expected a digit or '.', found 'x'",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
     mod part_1 {
         use super::*;
 
@@ -242,11 +227,12 @@ mod day_10_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
 
@@ -295,11 +281,12 @@ mod day_10_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
             });
         }
 
@@ -315,12 +302,13 @@ mod day_10_tests {
 8765
 9876",
             Input {
-                map: vec![
+                map: Grid::from_rows(vec![
                     vec![0, 1, 2, 3],
                     vec![1, 2, 3, 4],
                     vec![8, 7, 6, 5],
                     vec![9, 8, 7, 6],
-                ],
+                ])
+                .unwrap(),
             },
         )
     }
@@ -335,7 +323,7 @@ mod day_10_tests {
 8.....8
 9.....9",
             Input {
-                map: vec![
+                map: Grid::from_rows(vec![
                     vec![10, 10, 10, 0, 10, 10, 10],
                     vec![10, 10, 10, 1, 10, 10, 10],
                     vec![10, 10, 10, 2, 10, 10, 10],
@@ -343,7 +331,8 @@ mod day_10_tests {
                     vec![7, 10, 10, 10, 10, 10, 7],
                     vec![8, 10, 10, 10, 10, 10, 8],
                     vec![9, 10, 10, 10, 10, 10, 9],
-                ],
+                ])
+                .unwrap(),
             },
         )
     }
@@ -358,7 +347,7 @@ mod day_10_tests {
 876....
 987....",
             Input {
-                map: vec![
+                map: Grid::from_rows(vec![
                     vec![10, 10, 9, 0, 10, 10, 9],
                     vec![10, 10, 10, 1, 10, 9, 8],
                     vec![10, 10, 10, 2, 10, 10, 7],
@@ -366,7 +355,8 @@ mod day_10_tests {
                     vec![7, 6, 5, 10, 9, 8, 7],
                     vec![8, 7, 6, 10, 10, 10, 10],
                     vec![9, 8, 7, 10, 10, 10, 10],
-                ],
+                ])
+                .unwrap(),
             },
         )
     }
@@ -381,7 +371,7 @@ mod day_10_tests {
 ...9..2
 .....01",
             Input {
-                map: vec![
+                map: Grid::from_rows(vec![
                     vec![1, 0, 10, 10, 9, 10, 10],
                     vec![2, 10, 10, 10, 8, 10, 10],
                     vec![3, 10, 10, 10, 7, 10, 10],
@@ -389,7 +379,8 @@ mod day_10_tests {
                     vec![10, 10, 10, 8, 10, 10, 3],
                     vec![10, 10, 10, 9, 10, 10, 2],
                     vec![10, 10, 10, 10, 10, 0, 1],
-                ],
+                ])
+                .unwrap(),
             },
         )
     }
@@ -404,7 +395,7 @@ mod day_10_tests {
 ..8765.
 ..9....",
             Input {
-                map: vec![
+                map: Grid::from_rows(vec![
                     vec![10, 10, 10, 10, 10, 0, 10],
                     vec![10, 10, 4, 3, 2, 1, 10],
                     vec![10, 10, 5, 10, 10, 2, 10],
@@ -412,7 +403,8 @@ mod day_10_tests {
                     vec![10, 10, 7, 10, 10, 4, 10],
                     vec![10, 10, 8, 7, 6, 5, 10],
                     vec![10, 10, 9, 10, 10, 10, 10],
-                ],
+                ])
+                .unwrap(),
             },
         )
     }
@@ -426,14 +418,15 @@ mod day_10_tests {
 4.6789
 56789.",
             Input {
-                map: vec![
+                map: Grid::from_rows(vec![
                     vec![0, 1, 2, 3, 4, 5],
                     vec![1, 2, 3, 4, 5, 6],
                     vec![2, 3, 4, 5, 6, 7],
                     vec![3, 4, 5, 6, 7, 8],
                     vec![4, 10, 6, 7, 8, 9],
                     vec![5, 6, 7, 8, 9, 10],
-                ],
+                ])
+                .unwrap(),
             },
         )
     }
@@ -449,7 +442,7 @@ mod day_10_tests {
 01329801
 10456732",
             Input {
-                map: vec![
+                map: Grid::from_rows(vec![
                     vec![8, 9, 0, 1, 0, 1, 2, 3],
                     vec![7, 8, 1, 2, 1, 8, 7, 4],
                     vec![8, 7, 4, 3, 0, 9, 6, 5],
@@ -458,8 +451,15 @@ mod day_10_tests {
                     vec![3, 2, 0, 1, 9, 0, 1, 2],
                     vec![0, 1, 3, 2, 9, 8, 0, 1],
                     vec![1, 0, 4, 5, 6, 7, 3, 2],
-                ],
+                ])
+                .unwrap(),
             },
         )
     }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
 }
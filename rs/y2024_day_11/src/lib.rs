@@ -1,15 +1,16 @@
+use common::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
 use std::collections::HashMap;
-use std::{fs::read_to_string, path::Path};
 
-pub const PART_1: usize = 175_006;
-pub const PART_2: usize = 207_961_583_799_296;
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_11.txt")
+}
 
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("day_11.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_11.txt", name)
 }
 
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -17,28 +18,35 @@ pub struct Input {
     stones: Vec<usize>,
 }
 
-impl Input {
-    #[must_use]
-    pub fn from_data(data: &str) -> Self {
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
         let stones = data
             .trim()
             .split_ascii_whitespace()
-            .map(|num| num.parse().unwrap())
-            .collect();
+            .map(|num| source.number(num))
+            .collect::<Result<_, _>>()?;
 
-        Self { stones }
+        Ok(Self { stones })
     }
 
-    #[must_use]
-    pub fn part_1(&self) -> usize {
+    fn part_1(&self) -> usize {
         self.blink::<25>()
     }
 
-    #[must_use]
-    pub fn part_2(&self) -> usize {
+    fn part_2(&self) -> usize {
         self.blink::<75>()
     }
+}
 
+impl Input {
     fn blink<const COUNT: u8>(&self) -> usize {
         (0..COUNT)
             .fold(
@@ -99,8 +107,9 @@ impl Input {
 }
 
 #[cfg(test)]
-mod day_11_tests {
+mod y2024_day_11_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -162,6 +171,32 @@ mod day_11_tests {
         }
     }
 
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn invalid_stone() {
+            run(&Case {
+                input: "125 17 x",
+                expected: "line 1, column 8: expected an integer (usize), found 'x'",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
     mod part_1 {
         use super::*;
 
@@ -178,11 +213,12 @@ mod day_11_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
 
@@ -194,22 +230,14 @@ mod day_11_tests {
     mod part_2 {
         use super::*;
 
-        struct Case {
-            data: Input,
-            expected: usize,
-        }
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
             });
         }
-
-        fn run(test: &Case) {
-            assert_eq!(test.expected, test.data.part_2());
-        }
     }
 
     fn example_1() -> (&'static str, Input) {
@@ -229,4 +257,10 @@ mod day_11_tests {
             },
         )
     }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
 }
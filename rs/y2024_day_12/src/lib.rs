@@ -0,0 +1,616 @@
+use common::{
+    grid::{Direction, Grid, Pos},
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
+use std::collections::{BTreeSet, HashSet};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_12.txt")
+}
+
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_12.txt", name)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Input {
+    plots: Vec<HashSet<Pos>>,
+}
+
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let garden = Grid::parse(&Source::new(data), data.trim(), "an ASCII plant", |char| {
+            u8::try_from(char).ok()
+        })?;
+
+        let mut visited = Grid::new(garden.width(), garden.height(), false);
+        let mut plots = Vec::new();
+        for (start, &plant) in garden.iter() {
+            if visited.get(start) != Some(&false) {
+                continue;
+            }
+
+            let mut plot = HashSet::new();
+            let mut working_set = BTreeSet::from([start]);
+            while let Some(pos) = working_set.pop_first() {
+                _ = plot.insert(pos);
+                if let Some(seen) = visited.get_mut(pos) {
+                    *seen = true;
+                }
+
+                working_set.extend(garden.neighbours(pos).filter(|&neighbour| {
+                    garden.get(neighbour) == Some(&plant) && visited.get(neighbour) == Some(&false)
+                }));
+            }
+            plots.push((plant, plot));
+        }
+        plots.sort_by_key(|&(plant, _)| plant);
+
+        Ok(Self {
+            plots: plots.into_iter().map(|(_, plot)| plot).collect(),
+        })
+    }
+
+    fn part_1(&self) -> usize {
+        self.plots
+            .iter()
+            .map(|plot| {
+                let area = plot.len();
+
+                let perimeter: usize = plot
+                    .iter()
+                    .map(|&pos| {
+                        Direction::ALL
+                            .into_iter()
+                            .filter(|&direction| {
+                                !pos.step(direction)
+                                    .is_some_and(|neighbour| plot.contains(&neighbour))
+                            })
+                            .count()
+                    })
+                    .sum();
+
+                area * perimeter
+            })
+            .sum()
+    }
+
+    fn part_2(&self) -> usize {
+        self.plots
+            .iter()
+            .map(|plot| {
+                let area = plot.len();
+
+                let corners: usize = plot
+                    .iter()
+                    .flat_map(|&pos| {
+                        // Each corner lies between one side, the diagonal, and the side clockwise
+                        // from the first.
+                        Direction::ALL.into_iter().map(move |direction| {
+                            let one_side = pos.step(direction);
+                            let diagonal =
+                                one_side.and_then(|side| side.step(direction.turn_right()));
+                            let other_side = pos.step(direction.turn_right());
+
+                            let has_one_side = one_side.is_some_and(|val| plot.contains(&val));
+                            let has_diagonal = diagonal.is_some_and(|val| plot.contains(&val));
+                            let has_other_side = other_side.is_some_and(|val| plot.contains(&val));
+
+                            match [has_one_side, has_diagonal, has_other_side] {
+                                [false, _, false] | [true, false, true] => 1,
+                                _ => 0,
+                            }
+                        })
+                    })
+                    .sum();
+
+                area * corners
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod y2024_day_12_tests {
+    use super::*;
+    use common::answers::Answers;
+
+    const DATA_DIR: &str = "../../data";
+
+    mod from_data {
+        use super::*;
+
+        struct Case<'c> {
+            input: &'c str,
+            expected: Input,
+        }
+
+        #[test]
+        fn example_1() {
+            run(&Case {
+                input: super::example_1().0,
+                expected: super::example_1().1,
+            });
+        }
+
+        #[test]
+        fn example_2() {
+            run(&Case {
+                input: super::example_2().0,
+                expected: super::example_2().1,
+            });
+        }
+
+        #[test]
+        fn example_3() {
+            run(&Case {
+                input: super::example_3().0,
+                expected: super::example_3().1,
+            });
+        }
+
+        #[test]
+        fn example_4() {
+            run(&Case {
+                input: super::example_4().0,
+                expected: super::example_4().1,
+            });
+        }
+
+        #[test]
+        fn larger_example() {
+            run(&Case {
+                input: super::larger_example().0,
+                expected: super::larger_example().1,
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(test.expected, Input::from_data(test.input));
+        }
+    }
+
+    mod part_1 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example_1() {
+            run(&Case {
+                data: super::example_1().1,
+                expected: 140,
+            });
+        }
+
+        #[test]
+        fn example_2() {
+            run(&Case {
+                data: super::example_2().1,
+                expected: 772,
+            });
+        }
+
+        #[test]
+        fn larger_example() {
+            run(&Case {
+                data: super::larger_example().1,
+                expected: 1930,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_1());
+        }
+    }
+
+    mod part_2 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example_1() {
+            run(&Case {
+                data: super::example_1().1,
+                expected: 80,
+            });
+        }
+
+        #[test]
+        fn example_2() {
+            run(&Case {
+                data: super::example_2().1,
+                expected: 436,
+            });
+        }
+
+        #[test]
+        fn example_3() {
+            run(&Case {
+                data: super::example_3().1,
+                expected: 236,
+            });
+        }
+
+        #[test]
+        fn example_4() {
+            run(&Case {
+                data: super::example_4().1,
+                expected: 368,
+            });
+        }
+
+        #[test]
+        fn larger_example() {
+            run(&Case {
+                data: super::larger_example().1,
+                expected: 1206,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_2());
+        }
+    }
+
+    fn example_1() -> (&'static str, Input) {
+        (
+            "AAAA
+BBCD
+BBCC
+EEEC",
+            Input {
+                plots: vec![
+                    HashSet::from([
+                        Pos::new(0, 0),
+                        Pos::new(1, 0),
+                        Pos::new(2, 0),
+                        Pos::new(3, 0),
+                    ]),
+                    HashSet::from([
+                        Pos::new(0, 1),
+                        Pos::new(1, 1),
+                        Pos::new(0, 2),
+                        Pos::new(1, 2),
+                    ]),
+                    HashSet::from([
+                        Pos::new(2, 1),
+                        Pos::new(2, 2),
+                        Pos::new(3, 2),
+                        Pos::new(3, 3),
+                    ]),
+                    HashSet::from([Pos::new(3, 1)]),
+                    HashSet::from([Pos::new(0, 3), Pos::new(1, 3), Pos::new(2, 3)]),
+                ],
+            },
+        )
+    }
+
+    fn example_2() -> (&'static str, Input) {
+        (
+            "OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO",
+            Input {
+                plots: vec![
+                    HashSet::from([
+                        Pos::new(0, 0),
+                        Pos::new(1, 0),
+                        Pos::new(2, 0),
+                        Pos::new(3, 0),
+                        Pos::new(4, 0),
+                        Pos::new(0, 1),
+                        Pos::new(2, 1),
+                        Pos::new(4, 1),
+                        Pos::new(0, 2),
+                        Pos::new(1, 2),
+                        Pos::new(2, 2),
+                        Pos::new(3, 2),
+                        Pos::new(4, 2),
+                        Pos::new(0, 3),
+                        Pos::new(2, 3),
+                        Pos::new(4, 3),
+                        Pos::new(0, 4),
+                        Pos::new(1, 4),
+                        Pos::new(2, 4),
+                        Pos::new(3, 4),
+                        Pos::new(4, 4),
+                    ]),
+                    HashSet::from([Pos::new(1, 1)]),
+                    HashSet::from([Pos::new(3, 1)]),
+                    HashSet::from([Pos::new(1, 3)]),
+                    HashSet::from([Pos::new(3, 3)]),
+                ],
+            },
+        )
+    }
+
+    fn example_3() -> (&'static str, Input) {
+        (
+            "EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE",
+            Input {
+                plots: vec![
+                    HashSet::from([
+                        Pos::new(0, 0),
+                        Pos::new(1, 0),
+                        Pos::new(2, 0),
+                        Pos::new(3, 0),
+                        Pos::new(4, 0),
+                        Pos::new(0, 1),
+                        Pos::new(0, 2),
+                        Pos::new(1, 2),
+                        Pos::new(2, 2),
+                        Pos::new(3, 2),
+                        Pos::new(4, 2),
+                        Pos::new(0, 3),
+                        Pos::new(0, 4),
+                        Pos::new(1, 4),
+                        Pos::new(2, 4),
+                        Pos::new(3, 4),
+                        Pos::new(4, 4),
+                    ]),
+                    HashSet::from([
+                        Pos::new(1, 1),
+                        Pos::new(2, 1),
+                        Pos::new(3, 1),
+                        Pos::new(4, 1),
+                    ]),
+                    HashSet::from([
+                        Pos::new(1, 3),
+                        Pos::new(2, 3),
+                        Pos::new(3, 3),
+                        Pos::new(4, 3),
+                    ]),
+                ],
+            },
+        )
+    }
+
+    fn example_4() -> (&'static str, Input) {
+        (
+            "AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA",
+            Input {
+                plots: vec![
+                    HashSet::from([
+                        Pos::new(0, 0),
+                        Pos::new(1, 0),
+                        Pos::new(2, 0),
+                        Pos::new(3, 0),
+                        Pos::new(4, 0),
+                        Pos::new(5, 0),
+                        Pos::new(0, 1),
+                        Pos::new(1, 1),
+                        Pos::new(2, 1),
+                        Pos::new(5, 1),
+                        Pos::new(0, 2),
+                        Pos::new(1, 2),
+                        Pos::new(2, 2),
+                        Pos::new(5, 2),
+                        Pos::new(0, 3),
+                        Pos::new(3, 3),
+                        Pos::new(4, 3),
+                        Pos::new(5, 3),
+                        Pos::new(0, 4),
+                        Pos::new(3, 4),
+                        Pos::new(4, 4),
+                        Pos::new(5, 4),
+                        Pos::new(0, 5),
+                        Pos::new(1, 5),
+                        Pos::new(2, 5),
+                        Pos::new(3, 5),
+                        Pos::new(4, 5),
+                        Pos::new(5, 5),
+                    ]),
+                    HashSet::from([
+                        Pos::new(3, 1),
+                        Pos::new(4, 1),
+                        Pos::new(3, 2),
+                        Pos::new(4, 2),
+                    ]),
+                    HashSet::from([
+                        Pos::new(1, 3),
+                        Pos::new(2, 3),
+                        Pos::new(1, 4),
+                        Pos::new(2, 4),
+                    ]),
+                ],
+            },
+        )
+    }
+
+    #[expect(clippy::too_many_lines)]
+    fn larger_example() -> (&'static str, Input) {
+        (
+            "
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE",
+            Input {
+                plots: vec![
+                    // C
+                    HashSet::from([
+                        Pos::new(6, 0),
+                        Pos::new(7, 0),
+                        Pos::new(6, 1),
+                        Pos::new(7, 1),
+                        Pos::new(8, 1),
+                        Pos::new(5, 2),
+                        Pos::new(6, 2),
+                        Pos::new(3, 3),
+                        Pos::new(4, 3),
+                        Pos::new(5, 3),
+                        Pos::new(4, 4),
+                        Pos::new(4, 5),
+                        Pos::new(5, 5),
+                        Pos::new(5, 6),
+                    ]),
+                    HashSet::from([Pos::new(7, 4)]),
+                    // E
+                    HashSet::from([
+                        Pos::new(9, 4),
+                        Pos::new(8, 5),
+                        Pos::new(9, 5),
+                        Pos::new(8, 6),
+                        Pos::new(9, 6),
+                        Pos::new(8, 7),
+                        Pos::new(9, 7),
+                        Pos::new(7, 8),
+                        Pos::new(8, 8),
+                        Pos::new(9, 8),
+                        Pos::new(7, 9),
+                        Pos::new(8, 9),
+                        Pos::new(9, 9),
+                    ]),
+                    // F
+                    HashSet::from([
+                        Pos::new(8, 0),
+                        Pos::new(9, 0),
+                        Pos::new(9, 1),
+                        Pos::new(7, 2),
+                        Pos::new(8, 2),
+                        Pos::new(9, 2),
+                        Pos::new(7, 3),
+                        Pos::new(8, 3),
+                        Pos::new(9, 3),
+                        Pos::new(8, 4),
+                    ]),
+                    // I
+                    HashSet::from([
+                        Pos::new(4, 0),
+                        Pos::new(5, 0),
+                        Pos::new(4, 1),
+                        Pos::new(5, 1),
+                    ]),
+                    HashSet::from([
+                        Pos::new(2, 5),
+                        Pos::new(2, 6),
+                        Pos::new(3, 6),
+                        Pos::new(4, 6),
+                        Pos::new(1, 7),
+                        Pos::new(2, 7),
+                        Pos::new(3, 7),
+                        Pos::new(4, 7),
+                        Pos::new(5, 7),
+                        Pos::new(1, 8),
+                        Pos::new(2, 8),
+                        Pos::new(3, 8),
+                        Pos::new(5, 8),
+                        Pos::new(3, 9),
+                    ]),
+                    // J
+                    HashSet::from([
+                        Pos::new(6, 3),
+                        Pos::new(5, 4),
+                        Pos::new(6, 4),
+                        Pos::new(6, 5),
+                        Pos::new(7, 5),
+                        Pos::new(6, 6),
+                        Pos::new(7, 6),
+                        Pos::new(6, 7),
+                        Pos::new(7, 7),
+                        Pos::new(6, 8),
+                        Pos::new(6, 9),
+                    ]),
+                    // M
+                    HashSet::from([
+                        Pos::new(0, 7),
+                        Pos::new(0, 8),
+                        Pos::new(0, 9),
+                        Pos::new(1, 9),
+                        Pos::new(2, 9),
+                    ]),
+                    // R
+                    HashSet::from([
+                        Pos::new(0, 0),
+                        Pos::new(1, 0),
+                        Pos::new(2, 0),
+                        Pos::new(3, 0),
+                        Pos::new(0, 1),
+                        Pos::new(1, 1),
+                        Pos::new(2, 1),
+                        Pos::new(3, 1),
+                        Pos::new(2, 2),
+                        Pos::new(3, 2),
+                        Pos::new(4, 2),
+                        Pos::new(2, 3),
+                    ]),
+                    // S
+                    HashSet::from([Pos::new(4, 8), Pos::new(4, 9), Pos::new(5, 9)]),
+                    // V
+                    HashSet::from([
+                        Pos::new(0, 2),
+                        Pos::new(1, 2),
+                        Pos::new(0, 3),
+                        Pos::new(1, 3),
+                        Pos::new(0, 4),
+                        Pos::new(1, 4),
+                        Pos::new(2, 4),
+                        Pos::new(3, 4),
+                        Pos::new(0, 5),
+                        Pos::new(1, 5),
+                        Pos::new(3, 5),
+                        Pos::new(0, 6),
+                        Pos::new(1, 6),
+                    ]),
+                ],
+            },
+        )
+    }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
+}
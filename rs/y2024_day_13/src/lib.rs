@@ -1,14 +1,15 @@
-use std::{fs::read_to_string, path::Path};
-
-pub const PART_1: usize = 26_810;
-pub const PART_2: usize = 108_713_182_988_244;
+use common::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_13.txt")
+}
 
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("day_13.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_13.txt", name)
 }
 
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -16,57 +17,69 @@ pub struct Input {
     machines: Vec<Machine>,
 }
 
-impl Input {
-    #[must_use]
-    pub fn from_data(data: &str) -> Self {
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
         let machines = data
             .trim()
             .split("\n\n")
             .map(|machine_chunk| {
+                let chunk_end = machine_chunk.get(machine_chunk.len()..).unwrap_or_default();
                 let mut lines = machine_chunk.lines();
-                let a_str = lines.next().unwrap().strip_prefix("Button A: ").unwrap();
+                let mut next_line = |prefix: &str| {
+                    let line = lines
+                        .next()
+                        .ok_or_else(|| source.error(chunk_end, format!("'{prefix}'")))?;
+                    source.strip_prefix(line, prefix)
+                };
 
+                let a_str = next_line("Button A: ")?;
                 let button_a = {
-                    let (x_str, y_str) = a_str.split_once(", ").unwrap();
-                    let a_x = x_str.strip_prefix("X+").unwrap().parse().unwrap();
-                    let a_y = y_str.strip_prefix("Y+").unwrap().parse().unwrap();
+                    let (x_str, y_str) = source.split_once(a_str, ", ")?;
+                    let a_x = source.number(source.strip_prefix(x_str, "X+")?)?;
+                    let a_y = source.number(source.strip_prefix(y_str, "Y+")?)?;
                     [a_x, a_y]
                 };
 
-                let b_str = lines.next().unwrap().strip_prefix("Button B: ").unwrap();
+                let b_str = next_line("Button B: ")?;
                 let button_b = {
-                    let (x_str, y_str) = b_str.split_once(", ").unwrap();
-                    let b_x = x_str.strip_prefix("X+").unwrap().parse().unwrap();
-                    let b_y = y_str.strip_prefix("Y+").unwrap().parse().unwrap();
+                    let (x_str, y_str) = source.split_once(b_str, ", ")?;
+                    let b_x = source.number(source.strip_prefix(x_str, "X+")?)?;
+                    let b_y = source.number(source.strip_prefix(y_str, "Y+")?)?;
                     [b_x, b_y]
                 };
 
-                let prize_str = lines.next().unwrap().strip_prefix("Prize: ").unwrap();
+                let prize_str = next_line("Prize: ")?;
                 let prize = {
-                    let (x_str, y_str) = prize_str.split_once(", ").unwrap();
-                    let prize_x = x_str.strip_prefix("X=").unwrap().parse().unwrap();
-                    let prize_y = y_str.strip_prefix("Y=").unwrap().parse().unwrap();
+                    let (x_str, y_str) = source.split_once(prize_str, ", ")?;
+                    let prize_x = source.number(source.strip_prefix(x_str, "X=")?)?;
+                    let prize_y = source.number(source.strip_prefix(y_str, "Y=")?)?;
                     [prize_x, prize_y]
                 };
 
-                Machine {
+                Ok(Machine {
                     button_a,
                     button_b,
                     prize,
-                }
+                })
             })
-            .collect();
+            .collect::<Result<_, ParseError>>()?;
 
-        Self { machines }
+        Ok(Self { machines })
     }
 
-    #[must_use]
-    pub fn part_1(&self) -> usize {
+    fn part_1(&self) -> usize {
         self.machines.iter().filter_map(tokens_for_prize).sum()
     }
 
-    #[must_use]
-    pub fn part_2(&self) -> usize {
+    fn part_2(&self) -> usize {
         self.machines
             .iter()
             .filter_map(|machine| {
@@ -143,8 +156,9 @@ struct Machine {
 }
 
 #[cfg(test)]
-mod day_13_tests {
+mod y2024_day_13_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -169,36 +183,41 @@ mod day_13_tests {
         }
     }
 
-    mod part_1 {
+    mod try_from_data {
         use super::*;
 
         struct Case {
-            data: Input,
-            expected: usize,
+            input: &'static str,
+            expected: &'static str,
         }
 
         #[test]
-        fn example() {
+        fn truncated_machine() {
             run(&Case {
-                data: super::example().1,
-                expected: 480,
+                input: "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\nButton A: X+26, Y+66\nButton B: X+67, Y+21",
+                expected: "line 6, column 21: expected 'Prize: ', found end of input",
             });
         }
 
         #[test]
-        fn actual() {
+        fn missing_prefix() {
             run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+                input: "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, 5400",
+                expected: "line 3, column 16: expected 'Y=', found '5400'",
             });
         }
 
         fn run(test: &Case) {
-            assert_eq!(test.expected, test.data.part_1());
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
         }
     }
 
-    mod part_2 {
+    mod part_1 {
         use super::*;
 
         struct Case {
@@ -207,15 +226,37 @@ mod day_13_tests {
         }
 
         #[test]
-        fn actual() {
+        fn example() {
             run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+                data: super::example().1,
+                expected: 480,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
 
         fn run(test: &Case) {
-            assert_eq!(test.expected, test.data.part_2());
+            assert_eq!(test.expected, test.data.part_1());
+        }
+    }
+
+    mod part_2 {
+        use super::*;
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
+            });
         }
     }
 
@@ -262,4 +303,10 @@ Prize: X=18641, Y=10279",
             },
         )
     }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
 }
@@ -1,16 +1,18 @@
 use std::collections::HashSet;
 use std::io::{stdin, Write};
-use std::{fs::read_to_string, path::Path};
 
-pub const PART_1: usize = 230_900_224;
-pub const PART_2: usize = 6_532;
+use common::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
 
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("day_14.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_14.txt")
+}
+
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_14.txt", name)
 }
 
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -18,56 +20,57 @@ pub struct Input {
     robots: Vec<Robot>,
 }
 
-impl Input {
-    #[must_use]
-    pub fn from_data(data: &str) -> Self {
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
         let robots = data
             .trim()
             .lines()
             .map(|line| {
-                let (position_str, velocity_str) = line.split_once(' ').unwrap();
+                let (position_str, velocity_str) = source.split_once(line, " ")?;
 
-                let (position_x_str, position_y_str) = position_str
-                    .strip_prefix("p=")
-                    .unwrap()
-                    .split_once(',')
-                    .unwrap();
+                let (position_x_str, position_y_str) =
+                    source.split_once(source.strip_prefix(position_str, "p=")?, ",")?;
                 let position = [
-                    position_x_str.parse().unwrap(),
-                    position_y_str.parse().unwrap(),
+                    source.number(position_x_str)?,
+                    source.number(position_y_str)?,
                 ];
 
-                let (velocity_x_str, velocity_y_str) = velocity_str
-                    .strip_prefix("v=")
-                    .unwrap()
-                    .split_once(',')
-                    .unwrap();
+                let (velocity_x_str, velocity_y_str) =
+                    source.split_once(source.strip_prefix(velocity_str, "v=")?, ",")?;
                 let velocity = [
-                    velocity_x_str.parse().unwrap(),
-                    velocity_y_str.parse().unwrap(),
+                    source.number(velocity_x_str)?,
+                    source.number(velocity_y_str)?,
                 ];
 
-                Robot { position, velocity }
+                Ok(Robot { position, velocity })
             })
-            .collect();
+            .collect::<Result<_, ParseError>>()?;
 
-        Self { robots }
+        Ok(Self { robots })
     }
 
-    #[must_use]
-    pub fn part_1(&self) -> usize {
+    fn part_1(&self) -> usize {
         self.part_1_adjustable::<101, 103>()
     }
 
-    #[must_use]
-    pub fn part_2(&self) -> usize {
+    fn part_2(&self) -> usize {
         if false {
             self.finding_part_2()
         } else {
             6532
         }
     }
+}
 
+impl Input {
     fn finding_part_2(&self) -> usize {
         const WIDTH: usize = 101;
         const HEIGHT: usize = 103;
@@ -166,7 +169,7 @@ impl Input {
                     _ => {
                         // middle, ignore
                     }
-                };
+                }
                 quads
             })
             .into_iter()
@@ -181,8 +184,9 @@ struct Robot {
 }
 
 #[cfg(test)]
-mod day_14_tests {
+mod y2024_day_14_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -207,6 +211,32 @@ mod day_14_tests {
         }
     }
 
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn missing_velocity() {
+            run(&Case {
+                input: "p=0,4 v=3,-3\np=6,3",
+                expected: "line 2, column 6: expected ' ', found end of input",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
     mod part_1_adjustable {
         use super::*;
 
@@ -234,43 +264,27 @@ mod day_14_tests {
     mod part_1 {
         use super::*;
 
-        struct Case {
-            data: Input,
-            expected: usize,
-        }
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
-
-        fn run(test: &Case) {
-            assert_eq!(test.expected, test.data.part_1());
-        }
     }
 
     mod part_2 {
         use super::*;
 
-        struct Case {
-            data: Input,
-            expected: usize,
-        }
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
             });
         }
-
-        fn run(test: &Case) {
-            assert_eq!(test.expected, test.data.part_2());
-        }
     }
 
     fn example() -> (&'static str, Input) {
@@ -341,4 +355,10 @@ p=9,5 v=-3,-3",
             },
         )
     }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
 }
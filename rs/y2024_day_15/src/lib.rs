@@ -1,110 +1,105 @@
-use std::collections::{BTreeSet, HashMap, HashSet};
-use std::{fs::read_to_string, path::Path};
+use common::{
+    grid::{Direction, Grid, Pos},
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
+use core::mem;
+use std::collections::{BTreeSet, HashSet};
 
-pub const PART_1: usize = 1_414_416;
-pub const PART_2: usize = 1_386_070;
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_15.txt")
+}
 
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("day_15.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_15.txt", name)
 }
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct Input {
-    warehouse: HashMap<[usize; 2], Obstacle>,
-    robot: [usize; 2],
+    warehouse: Grid<Option<Obstacle>>,
+    robot: Pos,
     instructions: Vec<Direction>,
 }
 
-impl Input {
-    #[must_use]
-    pub fn from_data(data: &str) -> Self {
-        let (warehouse_str, instruction_str) = data.trim().split_once("\n\n").unwrap();
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
 
-        let mut warehouse = HashMap::new();
-        let mut robot = [0_usize; 2];
-        for (y, line) in warehouse_str.trim().lines().enumerate() {
-            for (x, char) in line.char_indices() {
-                let obstacle = match char {
-                    '#' => Obstacle::Wall,
-                    'O' => Obstacle::Box,
-                    '@' => {
-                        robot = [y, x];
-                        continue;
-                    }
-                    _ => continue,
-                };
-                _ = warehouse.insert([y, x], obstacle);
-            }
-        }
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
+        let (warehouse_str, instruction_str) = source.split_once(data.trim(), "\n\n")?;
 
+        let (warehouse, [robot]) = Grid::parse_with_markers(
+            &source,
+            warehouse_str.trim(),
+            "one of '.', '#', 'O' or '@'",
+            [('@', "a robot '@'")],
+            |char| match char {
+                '#' => Some(Some(Obstacle::Wall)),
+                'O' => Some(Some(Obstacle::Box)),
+                '.' | '@' => Some(None),
+                _ => None,
+            },
+        )?;
+
+        let instruction_str = instruction_str.trim();
         let instructions = instruction_str
-            .trim()
-            .chars()
-            .filter(|char| *char != '\n')
-            .map(|char| match char {
-                '^' => Direction::Up,
-                '>' => Direction::Right,
-                'v' => Direction::Down,
-                '<' => Direction::Left,
-                #[expect(clippy::unreachable)]
-                _ => unreachable!(),
+            .char_indices()
+            .filter(|&(_, char)| char != '\n')
+            .map(|(idx, char)| {
+                Direction::from_arrow(char).ok_or_else(|| {
+                    source.error(
+                        instruction_str
+                            .get(idx..idx + char.len_utf8())
+                            .unwrap_or_default(),
+                        "one of '^', '>', 'v' or '<'",
+                    )
+                })
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        Self {
+        Ok(Self {
             warehouse,
             robot,
             instructions,
-        }
+        })
     }
 
-    #[must_use]
-    pub fn part_1(&self) -> usize {
+    fn part_1(&self) -> usize {
         let warehouse = self
             .instructions
             .iter()
             .fold(
                 (self.robot, self.warehouse.clone()),
                 |(robot, mut warehouse), &direction| {
-                    let [d_y, d_x]: [isize; 2] = match direction {
-                        Direction::Up => [-1, 0],
-                        Direction::Right => [0, 1],
-                        Direction::Down => [1, 0],
-                        Direction::Left => [0, -1],
-                    };
-
                     let mut empty_loc = robot;
                     loop {
-                        empty_loc = [
-                            empty_loc[0].saturating_add_signed(d_y),
-                            empty_loc[1].saturating_add_signed(d_x),
-                        ];
-                        match warehouse.get(&empty_loc) {
-                            Some(Obstacle::Wall) => {
+                        let Some(next_loc) = empty_loc.step(direction) else {
+                            return (robot, warehouse);
+                        };
+                        empty_loc = next_loc;
+                        match warehouse.get(empty_loc) {
+                            Some(Some(Obstacle::Wall)) | None => {
                                 return (robot, warehouse);
                             }
-                            None => {
+                            Some(None) => {
                                 break;
                             }
-                            Some(_) => {}
+                            Some(Some(_)) => {}
                         }
                     }
 
                     let mut tile = empty_loc;
-                    loop {
-                        let prev_tile = [
-                            tile[0].saturating_add_signed(-d_y),
-                            tile[1].saturating_add_signed(-d_x),
-                        ];
-
-                        let current = warehouse.remove(&prev_tile);
+                    while let Some(prev_tile) = tile.step(direction.reverse()) {
+                        let current = replace(&mut warehouse, prev_tile, None);
                         match current {
                             Some(obstacle) => {
-                                _ = warehouse.insert(tile, obstacle);
+                                _ = replace(&mut warehouse, tile, Some(obstacle));
                             }
                             None => break,
                         }
@@ -118,102 +113,87 @@ impl Input {
 
         warehouse
             .iter()
-            .filter_map(|([y, x], obstacle)| match obstacle {
-                Obstacle::Box => Some((100 * y) + x),
-                Obstacle::Wall | Obstacle::BoxLeft | Obstacle::BoxRight => None,
+            .filter_map(|(pos, obstacle)| match obstacle {
+                Some(Obstacle::Box) => Some((100 * pos.y) + pos.x),
+                Some(Obstacle::Wall | Obstacle::BoxLeft | Obstacle::BoxRight) | None => None,
             })
             .sum()
     }
 
-    #[must_use]
-    pub fn part_2(&self) -> usize {
-        let mut wide_warehouse = HashMap::with_capacity(2 * self.warehouse.len());
+    fn part_2(&self) -> usize {
+        let mut wide_warehouse =
+            Grid::new(2 * self.warehouse.width(), self.warehouse.height(), None);
 
-        for (&[y, x], &obstacle) in &self.warehouse {
-            match obstacle {
-                Obstacle::Box => {
-                    _ = wide_warehouse.insert([y, 2 * x], Obstacle::BoxLeft);
-                    _ = wide_warehouse.insert([y, (2 * x) + 1], Obstacle::BoxRight);
-                }
-                Obstacle::Wall => {
-                    _ = wide_warehouse.insert([y, 2 * x], Obstacle::Wall);
-                    _ = wide_warehouse.insert([y, (2 * x) + 1], Obstacle::Wall);
-                }
-                Obstacle::BoxLeft | Obstacle::BoxRight => {}
-            }
+        for (pos, &obstacle) in self.warehouse.iter() {
+            let (left, right) = match obstacle {
+                Some(Obstacle::Box) => (Obstacle::BoxLeft, Obstacle::BoxRight),
+                Some(Obstacle::Wall) => (Obstacle::Wall, Obstacle::Wall),
+                Some(Obstacle::BoxLeft | Obstacle::BoxRight) | None => continue,
+            };
+            wide_warehouse.extend([
+                (Pos::new(2 * pos.x, pos.y), Some(left)),
+                (Pos::new((2 * pos.x) + 1, pos.y), Some(right)),
+            ]);
         }
 
-        let wide_robot = [self.robot[0], self.robot[1] * 2];
+        let wide_robot = Pos::new(self.robot.x * 2, self.robot.y);
 
         let (_robot, warehouse) = self.instructions.iter().fold(
             (wide_robot, wide_warehouse),
             |(robot, mut warehouse), &direction| {
-                let [d_y, d_x]: [isize; 2] = match direction {
-                    Direction::Up => [-1, 0],
-                    Direction::Right => [0, 1],
-                    Direction::Down => [1, 0],
-                    Direction::Left => [0, -1],
+                let Some(new_robot) = robot.step(direction) else {
+                    return (robot, warehouse);
                 };
 
-                let new_robot = [
-                    robot[0].saturating_add_signed(d_y),
-                    robot[1].saturating_add_signed(d_x),
-                ];
-
                 let mut visited = HashSet::new();
                 let mut to_check = BTreeSet::from([new_robot]);
-                while let Some([y, x]) = to_check.pop_first() {
-                    if visited.contains(&[y, x]) {
+                while let Some(pos) = to_check.pop_first() {
+                    if visited.contains(&pos) {
                         continue;
                     }
 
-                    _ = visited.insert([y, x]);
+                    _ = visited.insert(pos);
 
-                    let next_loc = [y.saturating_add_signed(d_y), x.saturating_add_signed(d_x)];
-                    match warehouse.get(&[y, x]) {
-                        Some(Obstacle::Wall) => {
+                    let next_loc = pos.step(direction);
+                    match warehouse.get(pos) {
+                        Some(Some(Obstacle::Wall)) | None => {
                             return (robot, warehouse);
                         }
-                        Some(Obstacle::BoxLeft) => {
-                            _ = to_check.insert(next_loc);
-                            _ = to_check.insert([next_loc[0], next_loc[1] + 1]);
+                        Some(Some(Obstacle::BoxLeft)) => {
+                            to_check.extend(next_loc);
+                            to_check.extend(next_loc.and_then(|loc| loc.step(Direction::Right)));
                         }
-                        Some(Obstacle::BoxRight) => {
-                            _ = to_check.insert(next_loc);
-                            _ = to_check.insert([next_loc[0], next_loc[1].saturating_sub(1)]);
+                        Some(Some(Obstacle::BoxRight)) => {
+                            to_check.extend(next_loc);
+                            to_check.extend(next_loc.and_then(|loc| loc.step(Direction::Left)));
                         }
-                        Some(Obstacle::Box) | None => {}
+                        Some(Some(Obstacle::Box) | None) => {}
                     }
                 }
 
                 let mut removed = BTreeSet::from([(robot, None)]);
-                while let Some(([y, x], obstacle)) = removed.pop_first() {
-                    let target = [y.saturating_add_signed(d_y), x.saturating_add_signed(d_x)];
-
-                    let prev_target_value = if let Some(obstacle) = obstacle {
-                        warehouse.insert(target, obstacle)
-                    } else {
-                        warehouse.remove(&target)
+                while let Some((pos, obstacle)) = removed.pop_first() {
+                    let Some(target) = pos.step(direction) else {
+                        continue;
                     };
 
+                    let prev_target_value = replace(&mut warehouse, target, obstacle);
+
                     if prev_target_value.is_some() {
                         _ = removed.insert((target, prev_target_value));
                     }
 
-                    if d_y == 0 {
+                    if matches!(direction, Direction::Left | Direction::Right) {
                         continue;
                     }
 
-                    match prev_target_value {
-                        Some(Obstacle::BoxLeft) => {
-                            let neighbour = [target[0], target[1] + 1];
-                            _ = removed.insert((neighbour, warehouse.remove(&neighbour)));
-                        }
-                        Some(Obstacle::BoxRight) => {
-                            let neighbour = [target[0], target[1].saturating_sub(1)];
-                            _ = removed.insert((neighbour, warehouse.remove(&neighbour)));
-                        }
-                        Some(Obstacle::Box | Obstacle::Wall) | None => {}
+                    let neighbour = match prev_target_value {
+                        Some(Obstacle::BoxLeft) => target.step(Direction::Right),
+                        Some(Obstacle::BoxRight) => target.step(Direction::Left),
+                        Some(Obstacle::Box | Obstacle::Wall) | None => None,
+                    };
+                    if let Some(neighbour) = neighbour {
+                        _ = removed.insert((neighbour, replace(&mut warehouse, neighbour, None)));
                     }
                 }
 
@@ -223,14 +203,25 @@ impl Input {
 
         warehouse
             .iter()
-            .filter_map(|([y, x], obstacle)| match obstacle {
-                Obstacle::BoxLeft => Some((100 * y) + x),
-                Obstacle::Wall | Obstacle::Box | Obstacle::BoxRight => None,
+            .filter_map(|(pos, obstacle)| match obstacle {
+                Some(Obstacle::BoxLeft) => Some((100 * pos.y) + pos.x),
+                Some(Obstacle::Wall | Obstacle::Box | Obstacle::BoxRight) | None => None,
             })
             .sum()
     }
 }
 
+/// Sets the cell at `pos`, returning whatever was there before.
+fn replace(
+    warehouse: &mut Grid<Option<Obstacle>>,
+    pos: Pos,
+    obstacle: Option<Obstacle>,
+) -> Option<Obstacle> {
+    warehouse
+        .get_mut(pos)
+        .and_then(|cell| mem::replace(cell, obstacle))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum Obstacle {
     Box,
@@ -239,19 +230,10 @@ enum Obstacle {
     BoxRight,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
 #[cfg(test)]
-mod day_15_tests {
-    use std::collections::HashMap;
-
+mod y2024_day_15_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -292,6 +274,32 @@ mod day_15_tests {
         }
     }
 
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn unknown_direction() {
+            run(&Case {
+                input: "#####\n#@O.#\n#####\n\n<>^vx",
+                expected: "line 5, column 5: expected one of '^', '>', 'v' or '<', found 'x'",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
     mod part_1 {
         use super::*;
 
@@ -316,11 +324,12 @@ mod day_15_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
 
@@ -353,11 +362,12 @@ mod day_15_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
             });
         }
 
@@ -379,45 +389,49 @@ mod day_15_tests {
 
 <^^>>>vv<v>>v<<",
             Input {
-                warehouse: HashMap::from([
-                    ([0, 0], Obstacle::Wall),
-                    ([0, 1], Obstacle::Wall),
-                    ([0, 2], Obstacle::Wall),
-                    ([0, 3], Obstacle::Wall),
-                    ([0, 4], Obstacle::Wall),
-                    ([0, 5], Obstacle::Wall),
-                    ([0, 6], Obstacle::Wall),
-                    ([0, 7], Obstacle::Wall),
-                    ([1, 0], Obstacle::Wall),
-                    ([1, 3], Obstacle::Box),
-                    ([1, 5], Obstacle::Box),
-                    ([1, 7], Obstacle::Wall),
-                    ([2, 0], Obstacle::Wall),
-                    ([2, 1], Obstacle::Wall),
-                    ([2, 4], Obstacle::Box),
-                    ([2, 7], Obstacle::Wall),
-                    ([3, 0], Obstacle::Wall),
-                    ([3, 4], Obstacle::Box),
-                    ([3, 7], Obstacle::Wall),
-                    ([4, 0], Obstacle::Wall),
-                    ([4, 2], Obstacle::Wall),
-                    ([4, 4], Obstacle::Box),
-                    ([4, 7], Obstacle::Wall),
-                    ([5, 0], Obstacle::Wall),
-                    ([5, 4], Obstacle::Box),
-                    ([5, 7], Obstacle::Wall),
-                    ([6, 0], Obstacle::Wall),
-                    ([6, 7], Obstacle::Wall),
-                    ([7, 0], Obstacle::Wall),
-                    ([7, 1], Obstacle::Wall),
-                    ([7, 2], Obstacle::Wall),
-                    ([7, 3], Obstacle::Wall),
-                    ([7, 4], Obstacle::Wall),
-                    ([7, 5], Obstacle::Wall),
-                    ([7, 6], Obstacle::Wall),
-                    ([7, 7], Obstacle::Wall),
-                ]),
-                robot: [2, 2],
+                warehouse: {
+                    let mut warehouse = Grid::new(8, 8, None);
+                    warehouse.extend([
+                        (Pos::new(0, 0), Some(Obstacle::Wall)),
+                        (Pos::new(1, 0), Some(Obstacle::Wall)),
+                        (Pos::new(2, 0), Some(Obstacle::Wall)),
+                        (Pos::new(3, 0), Some(Obstacle::Wall)),
+                        (Pos::new(4, 0), Some(Obstacle::Wall)),
+                        (Pos::new(5, 0), Some(Obstacle::Wall)),
+                        (Pos::new(6, 0), Some(Obstacle::Wall)),
+                        (Pos::new(7, 0), Some(Obstacle::Wall)),
+                        (Pos::new(0, 1), Some(Obstacle::Wall)),
+                        (Pos::new(3, 1), Some(Obstacle::Box)),
+                        (Pos::new(5, 1), Some(Obstacle::Box)),
+                        (Pos::new(7, 1), Some(Obstacle::Wall)),
+                        (Pos::new(0, 2), Some(Obstacle::Wall)),
+                        (Pos::new(1, 2), Some(Obstacle::Wall)),
+                        (Pos::new(4, 2), Some(Obstacle::Box)),
+                        (Pos::new(7, 2), Some(Obstacle::Wall)),
+                        (Pos::new(0, 3), Some(Obstacle::Wall)),
+                        (Pos::new(4, 3), Some(Obstacle::Box)),
+                        (Pos::new(7, 3), Some(Obstacle::Wall)),
+                        (Pos::new(0, 4), Some(Obstacle::Wall)),
+                        (Pos::new(2, 4), Some(Obstacle::Wall)),
+                        (Pos::new(4, 4), Some(Obstacle::Box)),
+                        (Pos::new(7, 4), Some(Obstacle::Wall)),
+                        (Pos::new(0, 5), Some(Obstacle::Wall)),
+                        (Pos::new(4, 5), Some(Obstacle::Box)),
+                        (Pos::new(7, 5), Some(Obstacle::Wall)),
+                        (Pos::new(0, 6), Some(Obstacle::Wall)),
+                        (Pos::new(7, 6), Some(Obstacle::Wall)),
+                        (Pos::new(0, 7), Some(Obstacle::Wall)),
+                        (Pos::new(1, 7), Some(Obstacle::Wall)),
+                        (Pos::new(2, 7), Some(Obstacle::Wall)),
+                        (Pos::new(3, 7), Some(Obstacle::Wall)),
+                        (Pos::new(4, 7), Some(Obstacle::Wall)),
+                        (Pos::new(5, 7), Some(Obstacle::Wall)),
+                        (Pos::new(6, 7), Some(Obstacle::Wall)),
+                        (Pos::new(7, 7), Some(Obstacle::Wall)),
+                    ]);
+                    warehouse
+                },
+                robot: Pos::new(2, 2),
                 instructions: vec![
                     Direction::Left,
                     Direction::Up,
@@ -451,37 +465,41 @@ mod day_15_tests {
 
 <vv<<^^<<^^",
             Input {
-                warehouse: HashMap::from([
-                    ([0, 0], Obstacle::Wall),
-                    ([0, 1], Obstacle::Wall),
-                    ([0, 2], Obstacle::Wall),
-                    ([0, 3], Obstacle::Wall),
-                    ([0, 4], Obstacle::Wall),
-                    ([0, 5], Obstacle::Wall),
-                    ([0, 6], Obstacle::Wall),
-                    ([1, 0], Obstacle::Wall),
-                    ([1, 4], Obstacle::Wall),
-                    ([1, 6], Obstacle::Wall),
-                    ([2, 0], Obstacle::Wall),
-                    ([2, 6], Obstacle::Wall),
-                    ([3, 0], Obstacle::Wall),
-                    ([3, 3], Obstacle::Box),
-                    ([3, 4], Obstacle::Box),
-                    ([3, 6], Obstacle::Wall),
-                    ([4, 0], Obstacle::Wall),
-                    ([4, 3], Obstacle::Box),
-                    ([4, 6], Obstacle::Wall),
-                    ([5, 0], Obstacle::Wall),
-                    ([5, 6], Obstacle::Wall),
-                    ([6, 0], Obstacle::Wall),
-                    ([6, 1], Obstacle::Wall),
-                    ([6, 2], Obstacle::Wall),
-                    ([6, 3], Obstacle::Wall),
-                    ([6, 4], Obstacle::Wall),
-                    ([6, 5], Obstacle::Wall),
-                    ([6, 6], Obstacle::Wall),
-                ]),
-                robot: [3, 5],
+                warehouse: {
+                    let mut warehouse = Grid::new(7, 7, None);
+                    warehouse.extend([
+                        (Pos::new(0, 0), Some(Obstacle::Wall)),
+                        (Pos::new(1, 0), Some(Obstacle::Wall)),
+                        (Pos::new(2, 0), Some(Obstacle::Wall)),
+                        (Pos::new(3, 0), Some(Obstacle::Wall)),
+                        (Pos::new(4, 0), Some(Obstacle::Wall)),
+                        (Pos::new(5, 0), Some(Obstacle::Wall)),
+                        (Pos::new(6, 0), Some(Obstacle::Wall)),
+                        (Pos::new(0, 1), Some(Obstacle::Wall)),
+                        (Pos::new(4, 1), Some(Obstacle::Wall)),
+                        (Pos::new(6, 1), Some(Obstacle::Wall)),
+                        (Pos::new(0, 2), Some(Obstacle::Wall)),
+                        (Pos::new(6, 2), Some(Obstacle::Wall)),
+                        (Pos::new(0, 3), Some(Obstacle::Wall)),
+                        (Pos::new(3, 3), Some(Obstacle::Box)),
+                        (Pos::new(4, 3), Some(Obstacle::Box)),
+                        (Pos::new(6, 3), Some(Obstacle::Wall)),
+                        (Pos::new(0, 4), Some(Obstacle::Wall)),
+                        (Pos::new(3, 4), Some(Obstacle::Box)),
+                        (Pos::new(6, 4), Some(Obstacle::Wall)),
+                        (Pos::new(0, 5), Some(Obstacle::Wall)),
+                        (Pos::new(6, 5), Some(Obstacle::Wall)),
+                        (Pos::new(0, 6), Some(Obstacle::Wall)),
+                        (Pos::new(1, 6), Some(Obstacle::Wall)),
+                        (Pos::new(2, 6), Some(Obstacle::Wall)),
+                        (Pos::new(3, 6), Some(Obstacle::Wall)),
+                        (Pos::new(4, 6), Some(Obstacle::Wall)),
+                        (Pos::new(5, 6), Some(Obstacle::Wall)),
+                        (Pos::new(6, 6), Some(Obstacle::Wall)),
+                    ]);
+                    warehouse
+                },
+                robot: Pos::new(5, 3),
                 instructions: vec![
                     Direction::Left,
                     Direction::Down,
@@ -524,67 +542,71 @@ vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
 ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
 v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^",
             Input {
-                warehouse: HashMap::from([
-                    ([0, 0], Obstacle::Wall),
-                    ([0, 1], Obstacle::Wall),
-                    ([0, 2], Obstacle::Wall),
-                    ([0, 3], Obstacle::Wall),
-                    ([0, 4], Obstacle::Wall),
-                    ([0, 5], Obstacle::Wall),
-                    ([0, 6], Obstacle::Wall),
-                    ([0, 7], Obstacle::Wall),
-                    ([0, 8], Obstacle::Wall),
-                    ([0, 9], Obstacle::Wall),
-                    ([1, 0], Obstacle::Wall),
-                    ([1, 3], Obstacle::Box),
-                    ([1, 6], Obstacle::Box),
-                    ([1, 8], Obstacle::Box),
-                    ([1, 9], Obstacle::Wall),
-                    ([2, 0], Obstacle::Wall),
-                    ([2, 7], Obstacle::Box),
-                    ([2, 9], Obstacle::Wall),
-                    ([3, 0], Obstacle::Wall),
-                    ([3, 2], Obstacle::Box),
-                    ([3, 3], Obstacle::Box),
-                    ([3, 6], Obstacle::Box),
-                    ([3, 8], Obstacle::Box),
-                    ([3, 9], Obstacle::Wall),
-                    ([4, 0], Obstacle::Wall),
-                    ([4, 3], Obstacle::Box),
-                    ([4, 7], Obstacle::Box),
-                    ([4, 9], Obstacle::Wall),
-                    ([5, 0], Obstacle::Wall),
-                    ([5, 1], Obstacle::Box),
-                    ([5, 2], Obstacle::Wall),
-                    ([5, 5], Obstacle::Box),
-                    ([5, 9], Obstacle::Wall),
-                    ([6, 0], Obstacle::Wall),
-                    ([6, 1], Obstacle::Box),
-                    ([6, 4], Obstacle::Box),
-                    ([6, 7], Obstacle::Box),
-                    ([6, 9], Obstacle::Wall),
-                    ([7, 0], Obstacle::Wall),
-                    ([7, 2], Obstacle::Box),
-                    ([7, 3], Obstacle::Box),
-                    ([7, 5], Obstacle::Box),
-                    ([7, 7], Obstacle::Box),
-                    ([7, 8], Obstacle::Box),
-                    ([7, 9], Obstacle::Wall),
-                    ([8, 0], Obstacle::Wall),
-                    ([8, 5], Obstacle::Box),
-                    ([8, 9], Obstacle::Wall),
-                    ([9, 0], Obstacle::Wall),
-                    ([9, 1], Obstacle::Wall),
-                    ([9, 2], Obstacle::Wall),
-                    ([9, 3], Obstacle::Wall),
-                    ([9, 4], Obstacle::Wall),
-                    ([9, 5], Obstacle::Wall),
-                    ([9, 6], Obstacle::Wall),
-                    ([9, 7], Obstacle::Wall),
-                    ([9, 8], Obstacle::Wall),
-                    ([9, 9], Obstacle::Wall),
-                ]),
-                robot: [4, 4],
+                warehouse: {
+                    let mut warehouse = Grid::new(10, 10, None);
+                    warehouse.extend([
+                        (Pos::new(0, 0), Some(Obstacle::Wall)),
+                        (Pos::new(1, 0), Some(Obstacle::Wall)),
+                        (Pos::new(2, 0), Some(Obstacle::Wall)),
+                        (Pos::new(3, 0), Some(Obstacle::Wall)),
+                        (Pos::new(4, 0), Some(Obstacle::Wall)),
+                        (Pos::new(5, 0), Some(Obstacle::Wall)),
+                        (Pos::new(6, 0), Some(Obstacle::Wall)),
+                        (Pos::new(7, 0), Some(Obstacle::Wall)),
+                        (Pos::new(8, 0), Some(Obstacle::Wall)),
+                        (Pos::new(9, 0), Some(Obstacle::Wall)),
+                        (Pos::new(0, 1), Some(Obstacle::Wall)),
+                        (Pos::new(3, 1), Some(Obstacle::Box)),
+                        (Pos::new(6, 1), Some(Obstacle::Box)),
+                        (Pos::new(8, 1), Some(Obstacle::Box)),
+                        (Pos::new(9, 1), Some(Obstacle::Wall)),
+                        (Pos::new(0, 2), Some(Obstacle::Wall)),
+                        (Pos::new(7, 2), Some(Obstacle::Box)),
+                        (Pos::new(9, 2), Some(Obstacle::Wall)),
+                        (Pos::new(0, 3), Some(Obstacle::Wall)),
+                        (Pos::new(2, 3), Some(Obstacle::Box)),
+                        (Pos::new(3, 3), Some(Obstacle::Box)),
+                        (Pos::new(6, 3), Some(Obstacle::Box)),
+                        (Pos::new(8, 3), Some(Obstacle::Box)),
+                        (Pos::new(9, 3), Some(Obstacle::Wall)),
+                        (Pos::new(0, 4), Some(Obstacle::Wall)),
+                        (Pos::new(3, 4), Some(Obstacle::Box)),
+                        (Pos::new(7, 4), Some(Obstacle::Box)),
+                        (Pos::new(9, 4), Some(Obstacle::Wall)),
+                        (Pos::new(0, 5), Some(Obstacle::Wall)),
+                        (Pos::new(1, 5), Some(Obstacle::Box)),
+                        (Pos::new(2, 5), Some(Obstacle::Wall)),
+                        (Pos::new(5, 5), Some(Obstacle::Box)),
+                        (Pos::new(9, 5), Some(Obstacle::Wall)),
+                        (Pos::new(0, 6), Some(Obstacle::Wall)),
+                        (Pos::new(1, 6), Some(Obstacle::Box)),
+                        (Pos::new(4, 6), Some(Obstacle::Box)),
+                        (Pos::new(7, 6), Some(Obstacle::Box)),
+                        (Pos::new(9, 6), Some(Obstacle::Wall)),
+                        (Pos::new(0, 7), Some(Obstacle::Wall)),
+                        (Pos::new(2, 7), Some(Obstacle::Box)),
+                        (Pos::new(3, 7), Some(Obstacle::Box)),
+                        (Pos::new(5, 7), Some(Obstacle::Box)),
+                        (Pos::new(7, 7), Some(Obstacle::Box)),
+                        (Pos::new(8, 7), Some(Obstacle::Box)),
+                        (Pos::new(9, 7), Some(Obstacle::Wall)),
+                        (Pos::new(0, 8), Some(Obstacle::Wall)),
+                        (Pos::new(5, 8), Some(Obstacle::Box)),
+                        (Pos::new(9, 8), Some(Obstacle::Wall)),
+                        (Pos::new(0, 9), Some(Obstacle::Wall)),
+                        (Pos::new(1, 9), Some(Obstacle::Wall)),
+                        (Pos::new(2, 9), Some(Obstacle::Wall)),
+                        (Pos::new(3, 9), Some(Obstacle::Wall)),
+                        (Pos::new(4, 9), Some(Obstacle::Wall)),
+                        (Pos::new(5, 9), Some(Obstacle::Wall)),
+                        (Pos::new(6, 9), Some(Obstacle::Wall)),
+                        (Pos::new(7, 9), Some(Obstacle::Wall)),
+                        (Pos::new(8, 9), Some(Obstacle::Wall)),
+                        (Pos::new(9, 9), Some(Obstacle::Wall)),
+                    ]);
+                    warehouse
+                },
+                robot: Pos::new(4, 4),
                 instructions: vec![
                     Direction::Left,
                     Direction::Down,
@@ -1290,4 +1312,10 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^",
             },
         )
     }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
 }
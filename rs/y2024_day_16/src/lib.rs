@@ -0,0 +1,580 @@
+use common::{
+    grid::{Direction, Grid, Pos},
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    search::{dijkstra, ShortestPaths},
+    Solution,
+};
+use std::collections::HashSet;
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_16.txt")
+}
+
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_16.txt", name)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Input {
+    start: Pos,
+    end: Pos,
+    walls: Grid<bool>,
+}
+
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let (walls, [start, end]) = Grid::parse_with_markers(
+            &Source::new(data),
+            data.trim(),
+            "one of '.', '#', 'S' or 'E'",
+            [('S', "a start tile 'S'"), ('E', "an end tile 'E'")],
+            |char| match char {
+                '#' => Some(true),
+                '.' | 'S' | 'E' => Some(false),
+                _ => None,
+            },
+        )?;
+
+        Ok(Self { start, end, walls })
+    }
+
+    fn part_1(&self) -> usize {
+        self.best_paths().goal_distance().unwrap()
+    }
+
+    fn part_2(&self) -> usize {
+        let paths = self.best_paths();
+
+        paths
+            .on_paths_to(paths.goals())
+            .into_iter()
+            .map(|&(location, _facing)| location)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
+
+impl Input {
+    /// Every cheapest route from the start, facing east, to the end tile in any direction.
+    fn best_paths(&self) -> ShortestPaths<(Pos, Direction)> {
+        dijkstra(
+            [(self.start, Direction::Right)],
+            |&(location, facing)| {
+                let forward = location
+                    .step(facing)
+                    .filter(|&pos| self.walls.get(pos) == Some(&false))
+                    .map(|pos| ((pos, facing), 1));
+
+                [
+                    ((location, facing.turn_left()), 1000),
+                    ((location, facing.turn_right()), 1000),
+                ]
+                .into_iter()
+                .chain(forward)
+            },
+            |&(location, _facing)| location == self.end,
+        )
+    }
+}
+
+#[cfg(test)]
+mod y2024_day_16_tests {
+    use super::*;
+    use common::answers::Answers;
+
+    const DATA_DIR: &str = "../../data";
+
+    mod from_data {
+        use super::*;
+
+        struct Case<'c> {
+            input: &'c str,
+            expected: Input,
+        }
+
+        #[test]
+        fn example_1() {
+            run(&Case {
+                input: super::example_1().0,
+                expected: super::example_1().1,
+            });
+        }
+
+        #[test]
+        fn example_2() {
+            run(&Case {
+                input: super::example_2().0,
+                expected: super::example_2().1,
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(test.expected, Input::from_data(test.input));
+        }
+    }
+
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn missing_end() {
+            run(&Case {
+                input: "#####\n#S..#\n#####",
+                expected: "line 3, column 6: expected an end tile 'E', found end of input",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
+    mod part_1 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example_1() {
+            run(&Case {
+                data: super::example_1().1,
+                expected: 7036,
+            });
+        }
+
+        #[test]
+        fn example_2() {
+            run(&Case {
+                data: super::example_2().1,
+                expected: 11048,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_1());
+        }
+    }
+
+    mod part_2 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example_1() {
+            run(&Case {
+                data: super::example_1().1,
+                expected: 45,
+            });
+        }
+
+        #[test]
+        fn example_2() {
+            run(&Case {
+                data: super::example_2().1,
+                expected: 64,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_2());
+        }
+    }
+
+    #[expect(clippy::too_many_lines)]
+    fn example_1() -> (&'static str, Input) {
+        (
+            "
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############",
+            Input {
+                start: Pos::new(1, 13),
+                end: Pos::new(13, 1),
+                walls: {
+                    let mut walls = Grid::new(15, 15, false);
+                    walls.extend(
+                        [
+                            Pos::new(0, 0),
+                            Pos::new(1, 0),
+                            Pos::new(2, 0),
+                            Pos::new(3, 0),
+                            Pos::new(4, 0),
+                            Pos::new(5, 0),
+                            Pos::new(6, 0),
+                            Pos::new(7, 0),
+                            Pos::new(8, 0),
+                            Pos::new(9, 0),
+                            Pos::new(10, 0),
+                            Pos::new(11, 0),
+                            Pos::new(12, 0),
+                            Pos::new(13, 0),
+                            Pos::new(14, 0),
+                            Pos::new(0, 1),
+                            Pos::new(8, 1),
+                            Pos::new(14, 1),
+                            Pos::new(0, 2),
+                            Pos::new(2, 2),
+                            Pos::new(4, 2),
+                            Pos::new(5, 2),
+                            Pos::new(6, 2),
+                            Pos::new(8, 2),
+                            Pos::new(10, 2),
+                            Pos::new(11, 2),
+                            Pos::new(12, 2),
+                            Pos::new(14, 2),
+                            Pos::new(0, 3),
+                            Pos::new(6, 3),
+                            Pos::new(8, 3),
+                            Pos::new(12, 3),
+                            Pos::new(14, 3),
+                            Pos::new(0, 4),
+                            Pos::new(2, 4),
+                            Pos::new(3, 4),
+                            Pos::new(4, 4),
+                            Pos::new(6, 4),
+                            Pos::new(7, 4),
+                            Pos::new(8, 4),
+                            Pos::new(9, 4),
+                            Pos::new(10, 4),
+                            Pos::new(12, 4),
+                            Pos::new(14, 4),
+                            Pos::new(0, 5),
+                            Pos::new(2, 5),
+                            Pos::new(4, 5),
+                            Pos::new(12, 5),
+                            Pos::new(14, 5),
+                            Pos::new(0, 6),
+                            Pos::new(2, 6),
+                            Pos::new(4, 6),
+                            Pos::new(5, 6),
+                            Pos::new(6, 6),
+                            Pos::new(7, 6),
+                            Pos::new(8, 6),
+                            Pos::new(10, 6),
+                            Pos::new(11, 6),
+                            Pos::new(12, 6),
+                            Pos::new(14, 6),
+                            Pos::new(0, 7),
+                            Pos::new(12, 7),
+                            Pos::new(14, 7),
+                            Pos::new(0, 8),
+                            Pos::new(1, 8),
+                            Pos::new(2, 8),
+                            Pos::new(4, 8),
+                            Pos::new(6, 8),
+                            Pos::new(7, 8),
+                            Pos::new(8, 8),
+                            Pos::new(9, 8),
+                            Pos::new(10, 8),
+                            Pos::new(12, 8),
+                            Pos::new(14, 8),
+                            Pos::new(0, 9),
+                            Pos::new(4, 9),
+                            Pos::new(10, 9),
+                            Pos::new(12, 9),
+                            Pos::new(14, 9),
+                            Pos::new(0, 10),
+                            Pos::new(2, 10),
+                            Pos::new(4, 10),
+                            Pos::new(6, 10),
+                            Pos::new(7, 10),
+                            Pos::new(8, 10),
+                            Pos::new(10, 10),
+                            Pos::new(12, 10),
+                            Pos::new(14, 10),
+                            Pos::new(0, 11),
+                            Pos::new(6, 11),
+                            Pos::new(10, 11),
+                            Pos::new(12, 11),
+                            Pos::new(14, 11),
+                            Pos::new(0, 12),
+                            Pos::new(2, 12),
+                            Pos::new(3, 12),
+                            Pos::new(4, 12),
+                            Pos::new(6, 12),
+                            Pos::new(8, 12),
+                            Pos::new(10, 12),
+                            Pos::new(12, 12),
+                            Pos::new(14, 12),
+                            Pos::new(0, 13),
+                            Pos::new(4, 13),
+                            Pos::new(10, 13),
+                            Pos::new(14, 13),
+                            Pos::new(0, 14),
+                            Pos::new(1, 14),
+                            Pos::new(2, 14),
+                            Pos::new(3, 14),
+                            Pos::new(4, 14),
+                            Pos::new(5, 14),
+                            Pos::new(6, 14),
+                            Pos::new(7, 14),
+                            Pos::new(8, 14),
+                            Pos::new(9, 14),
+                            Pos::new(10, 14),
+                            Pos::new(11, 14),
+                            Pos::new(12, 14),
+                            Pos::new(13, 14),
+                            Pos::new(14, 14),
+                        ]
+                        .map(|pos| (pos, true)),
+                    );
+                    walls
+                },
+            },
+        )
+    }
+
+    #[expect(clippy::too_many_lines)]
+    fn example_2() -> (&'static str, Input) {
+        (
+            "
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################",
+            Input {
+                start: Pos::new(1, 15),
+                end: Pos::new(15, 1),
+                walls: {
+                    let mut walls = Grid::new(17, 17, false);
+                    walls.extend(
+                        [
+                            Pos::new(0, 0),
+                            Pos::new(1, 0),
+                            Pos::new(2, 0),
+                            Pos::new(3, 0),
+                            Pos::new(4, 0),
+                            Pos::new(5, 0),
+                            Pos::new(6, 0),
+                            Pos::new(7, 0),
+                            Pos::new(8, 0),
+                            Pos::new(9, 0),
+                            Pos::new(10, 0),
+                            Pos::new(11, 0),
+                            Pos::new(12, 0),
+                            Pos::new(13, 0),
+                            Pos::new(14, 0),
+                            Pos::new(15, 0),
+                            Pos::new(16, 0),
+                            Pos::new(0, 1),
+                            Pos::new(4, 1),
+                            Pos::new(8, 1),
+                            Pos::new(12, 1),
+                            Pos::new(16, 1),
+                            Pos::new(0, 2),
+                            Pos::new(2, 2),
+                            Pos::new(4, 2),
+                            Pos::new(6, 2),
+                            Pos::new(8, 2),
+                            Pos::new(10, 2),
+                            Pos::new(12, 2),
+                            Pos::new(14, 2),
+                            Pos::new(16, 2),
+                            Pos::new(0, 3),
+                            Pos::new(2, 3),
+                            Pos::new(4, 3),
+                            Pos::new(6, 3),
+                            Pos::new(10, 3),
+                            Pos::new(14, 3),
+                            Pos::new(16, 3),
+                            Pos::new(0, 4),
+                            Pos::new(2, 4),
+                            Pos::new(4, 4),
+                            Pos::new(6, 4),
+                            Pos::new(8, 4),
+                            Pos::new(9, 4),
+                            Pos::new(10, 4),
+                            Pos::new(12, 4),
+                            Pos::new(14, 4),
+                            Pos::new(16, 4),
+                            Pos::new(0, 5),
+                            Pos::new(4, 5),
+                            Pos::new(6, 5),
+                            Pos::new(8, 5),
+                            Pos::new(14, 5),
+                            Pos::new(16, 5),
+                            Pos::new(0, 6),
+                            Pos::new(2, 6),
+                            Pos::new(4, 6),
+                            Pos::new(6, 6),
+                            Pos::new(8, 6),
+                            Pos::new(10, 6),
+                            Pos::new(11, 6),
+                            Pos::new(12, 6),
+                            Pos::new(13, 6),
+                            Pos::new(14, 6),
+                            Pos::new(16, 6),
+                            Pos::new(0, 7),
+                            Pos::new(2, 7),
+                            Pos::new(6, 7),
+                            Pos::new(8, 7),
+                            Pos::new(10, 7),
+                            Pos::new(16, 7),
+                            Pos::new(0, 8),
+                            Pos::new(2, 8),
+                            Pos::new(4, 8),
+                            Pos::new(5, 8),
+                            Pos::new(6, 8),
+                            Pos::new(7, 8),
+                            Pos::new(8, 8),
+                            Pos::new(10, 8),
+                            Pos::new(12, 8),
+                            Pos::new(13, 8),
+                            Pos::new(14, 8),
+                            Pos::new(16, 8),
+                            Pos::new(0, 9),
+                            Pos::new(2, 9),
+                            Pos::new(4, 9),
+                            Pos::new(12, 9),
+                            Pos::new(16, 9),
+                            Pos::new(0, 10),
+                            Pos::new(2, 10),
+                            Pos::new(4, 10),
+                            Pos::new(5, 10),
+                            Pos::new(6, 10),
+                            Pos::new(8, 10),
+                            Pos::new(9, 10),
+                            Pos::new(10, 10),
+                            Pos::new(11, 10),
+                            Pos::new(12, 10),
+                            Pos::new(14, 10),
+                            Pos::new(15, 10),
+                            Pos::new(16, 10),
+                            Pos::new(0, 11),
+                            Pos::new(2, 11),
+                            Pos::new(4, 11),
+                            Pos::new(8, 11),
+                            Pos::new(14, 11),
+                            Pos::new(16, 11),
+                            Pos::new(0, 12),
+                            Pos::new(2, 12),
+                            Pos::new(4, 12),
+                            Pos::new(6, 12),
+                            Pos::new(7, 12),
+                            Pos::new(8, 12),
+                            Pos::new(9, 12),
+                            Pos::new(10, 12),
+                            Pos::new(12, 12),
+                            Pos::new(13, 12),
+                            Pos::new(14, 12),
+                            Pos::new(16, 12),
+                            Pos::new(0, 13),
+                            Pos::new(2, 13),
+                            Pos::new(4, 13),
+                            Pos::new(14, 13),
+                            Pos::new(16, 13),
+                            Pos::new(0, 14),
+                            Pos::new(2, 14),
+                            Pos::new(4, 14),
+                            Pos::new(6, 14),
+                            Pos::new(7, 14),
+                            Pos::new(8, 14),
+                            Pos::new(9, 14),
+                            Pos::new(10, 14),
+                            Pos::new(11, 14),
+                            Pos::new(12, 14),
+                            Pos::new(13, 14),
+                            Pos::new(14, 14),
+                            Pos::new(16, 14),
+                            Pos::new(0, 15),
+                            Pos::new(2, 15),
+                            Pos::new(16, 15),
+                            Pos::new(0, 16),
+                            Pos::new(1, 16),
+                            Pos::new(2, 16),
+                            Pos::new(3, 16),
+                            Pos::new(4, 16),
+                            Pos::new(5, 16),
+                            Pos::new(6, 16),
+                            Pos::new(7, 16),
+                            Pos::new(8, 16),
+                            Pos::new(9, 16),
+                            Pos::new(10, 16),
+                            Pos::new(11, 16),
+                            Pos::new(12, 16),
+                            Pos::new(13, 16),
+                            Pos::new(14, 16),
+                            Pos::new(15, 16),
+                            Pos::new(16, 16),
+                        ]
+                        .map(|pos| (pos, true)),
+                    );
+                    walls
+                },
+            },
+        )
+    }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
+}
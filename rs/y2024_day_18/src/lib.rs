@@ -0,0 +1,308 @@
+use common::{
+    grid::{Grid, Pos},
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    search::{astar, bfs},
+    Solution,
+};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_18.txt")
+}
+
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_18.txt", name)
+}
+
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Input {
+    incoming_bytes: Vec<Pos>,
+}
+
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
+        let incoming_bytes = data
+            .trim()
+            .lines()
+            .map(|line| {
+                let (x_str, y_str) = source.split_once(line, ",")?;
+                Ok(Pos::new(source.number(x_str)?, source.number(y_str)?))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Self { incoming_bytes })
+    }
+
+    fn part_1(&self) -> usize {
+        self.part_1_sized::<70, 1024>()
+    }
+
+    fn part_2(&self) -> String {
+        self.part_2_sized::<70, 1024>()
+    }
+}
+
+impl Input {
+    fn part_1_sized<const SIZE: usize, const SIMULATION_COUNT: usize>(&self) -> usize {
+        let occupied = self.occupied::<SIZE>(SIMULATION_COUNT);
+        let target = Pos::new(SIZE, SIZE);
+
+        bfs(
+            [Pos::new(0, 0)],
+            |&position| Self::free_neighbours(&occupied, position),
+            |&position| position == target,
+        )
+        .goal_distance()
+        .unwrap()
+    }
+
+    fn part_2_sized<const SIZE: usize, const MIN_SIMULATIONS: usize>(&self) -> String {
+        let target = Pos::new(SIZE, SIZE);
+
+        // Bytes only ever block more of the memory space, so the exit stays unreachable once it
+        // first becomes so.
+        let mut reachable = MIN_SIMULATIONS;
+        let mut blocked = self.incoming_bytes.len();
+        while reachable < blocked {
+            let idx = reachable.midpoint(blocked);
+            let occupied = self.occupied::<SIZE>(idx + 1);
+
+            let path = astar(
+                Pos::new(0, 0),
+                |&position| {
+                    Self::free_neighbours(&occupied, position)
+                        .map(|next_position| (next_position, 1))
+                },
+                |position| position.manhattan(target),
+                |&position| position == target,
+            );
+
+            if path.is_some() {
+                reachable = idx + 1;
+            } else {
+                blocked = idx;
+            }
+        }
+
+        self.incoming_bytes
+            .get(blocked)
+            .map(|byte| format!("{},{}", byte.x, byte.y))
+            .unwrap()
+    }
+
+    /// The memory space once the first `count` bytes have fallen.
+    fn occupied<const SIZE: usize>(&self, count: usize) -> Grid<bool> {
+        let mut occupied = Grid::new(SIZE + 1, SIZE + 1, false);
+        occupied.extend(
+            self.incoming_bytes
+                .iter()
+                .take(count)
+                .map(|&byte| (byte, true)),
+        );
+        occupied
+    }
+
+    fn free_neighbours(occupied: &Grid<bool>, position: Pos) -> impl Iterator<Item = Pos> + '_ {
+        occupied
+            .neighbours(position)
+            .filter(|next_pos| occupied.get(*next_pos) == Some(&false))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GridSize<const WIDTH: usize = 70, const HEIGHT: usize = 70> {}
+
+#[cfg(test)]
+mod y2024_day_18_tests {
+    use super::*;
+    use common::answers::Answers;
+
+    const DATA_DIR: &str = "../../data";
+
+    mod from_data {
+        use super::*;
+
+        struct Case<'c> {
+            input: &'c str,
+            expected: Input,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                input: super::example().0,
+                expected: super::example().1,
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(test.expected, Input::from_data(test.input));
+        }
+    }
+
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn negative() {
+            run(&Case {
+                input: "5,4\n4,-1",
+                expected: "line 2, column 3: expected an integer (usize), found '-1'",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
+    mod part_1_sized {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: 22,
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_1_sized::<6, 12>());
+        }
+    }
+
+    mod part_1 {
+        use super::*;
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
+            });
+        }
+    }
+
+    mod part_2_sized {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: "6,1",
+            });
+        }
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_2_sized::<6, 12>());
+        }
+    }
+
+    mod part_2 {
+        use super::*;
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
+            });
+        }
+    }
+
+    fn example() -> (&'static str, Input) {
+        (
+            "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0",
+            Input {
+                incoming_bytes: vec![
+                    Pos::new(5, 4),
+                    Pos::new(4, 2),
+                    Pos::new(4, 5),
+                    Pos::new(3, 0),
+                    Pos::new(2, 1),
+                    Pos::new(6, 3),
+                    Pos::new(2, 4),
+                    Pos::new(1, 5),
+                    Pos::new(0, 6),
+                    Pos::new(3, 3),
+                    Pos::new(2, 6),
+                    Pos::new(5, 1),
+                    Pos::new(1, 2),
+                    Pos::new(5, 5),
+                    Pos::new(2, 5),
+                    Pos::new(6, 5),
+                    Pos::new(1, 4),
+                    Pos::new(0, 4),
+                    Pos::new(6, 4),
+                    Pos::new(1, 1),
+                    Pos::new(6, 1),
+                    Pos::new(1, 0),
+                    Pos::new(0, 5),
+                    Pos::new(1, 6),
+                    Pos::new(2, 0),
+                ],
+            },
+        )
+    }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::{BTreeMap, HashSet};
+
+use common::{
+    grid::{Grid, Pos},
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    search::bfs,
+    Solution,
+};
+
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_20.txt")
+}
+
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_20.txt", name)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Input {
+    race_track: Grid<bool>,
+    start: Pos,
+    end: Pos,
+}
+
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 20;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let (race_track, [start, end]) = Grid::parse_with_markers(
+            &Source::new(data),
+            data.trim(),
+            "one of '.', '#', 'S' or 'E'",
+            [('S', "a start tile 'S'"), ('E', "an end tile 'E'")],
+            |char| match char {
+                '#' => Some(true),
+                '.' | 'S' | 'E' => Some(false),
+                _ => None,
+            },
+        )?;
+
+        Ok(Self {
+            race_track,
+            start,
+            end,
+        })
+    }
+
+    fn part_1(&self) -> usize {
+        let cheats = self.cheat_savings::<2>();
+        cheats
+            .iter()
+            .filter_map(|(&size, options)| (size >= 100).then_some(options.len()))
+            .sum()
+    }
+
+    fn part_2(&self) -> usize {
+        let cheats = self.cheat_savings::<20>();
+        cheats
+            .iter()
+            .filter_map(|(&size, options)| (size >= 100).then_some(options.len()))
+            .sum()
+    }
+}
+
+impl Input {
+    fn cheat_savings<const MAX_LEN: usize>(&self) -> BTreeMap<usize, HashSet<[Pos; 2]>> {
+        let path = self.solve_maze();
+
+        let mut cheats = BTreeMap::new();
+        for (lhs_idx, lhs) in path.iter().copied().enumerate() {
+            for (rhs_idx, rhs) in path
+                .iter()
+                .copied()
+                .enumerate()
+                .rev()
+                .take_while(|(idx, _)| idx != &lhs_idx)
+            {
+                let distance = lhs.manhattan(rhs);
+                if distance > MAX_LEN {
+                    continue;
+                }
+                let cheat_size = rhs_idx - lhs_idx - distance;
+                _ = cheats
+                    .entry(cheat_size)
+                    .and_modify(|values: &mut HashSet<[Pos; 2]>| {
+                        _ = values.insert([lhs, rhs]);
+                    })
+                    .or_insert_with(|| HashSet::from([[lhs, rhs]]));
+            }
+        }
+        cheats
+    }
+
+    fn solve_maze(&self) -> Vec<Pos> {
+        bfs(
+            [self.start],
+            |&current| {
+                self.race_track
+                    .neighbours(current)
+                    .filter(|neighbour| self.race_track.get(*neighbour) == Some(&false))
+            },
+            |&current| current == self.end,
+        )
+        .path(&self.end)
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod y2024_day_20_tests {
+    use super::*;
+    use common::answers::Answers;
+
+    const DATA_DIR: &str = "../../data";
+
+    mod from_data {
+        use super::*;
+
+        struct Case<'c> {
+            input: &'c str,
+            expected: Input,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                input: super::example().0,
+                expected: super::example().1,
+            });
+        }
+
+        fn run(test: &Case<'_>) {
+            assert_eq!(test.expected, Input::from_data(test.input));
+        }
+    }
+
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn missing_start() {
+            run(&Case {
+                input: "#####\n#..E#\n#####",
+                expected: "line 3, column 6: expected a start tile 'S', found end of input",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
+    mod part_1 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: 0,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_1());
+        }
+    }
+
+    mod part_2 {
+        use super::*;
+
+        struct Case {
+            data: Input,
+            expected: usize,
+        }
+
+        #[test]
+        fn example() {
+            run(&Case {
+                data: super::example().1,
+                expected: 0,
+            });
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(test.expected, test.data.part_2());
+        }
+    }
+
+    fn example() -> (&'static str, Input) {
+        let example = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+        (example, Input::from_data(example))
+    }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
+}
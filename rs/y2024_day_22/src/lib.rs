@@ -1,14 +1,17 @@
-use std::{collections::HashMap, fs::read_to_string, path::Path};
+use std::collections::HashMap;
 
-pub const PART_1: usize = 17_724_064_040;
-pub const PART_2: usize = 1998;
+use common::{
+    input::{InputSource, NamedInput, ReadError},
+    parse::{ParseError, Source},
+    Solution,
+};
 
-#[must_use]
-pub fn read_data(data_dir: &str) -> String {
-    read_to_string(Path::new(data_dir).join("day_22.txt"))
-        .unwrap()
-        .trim()
-        .to_owned()
+pub fn read_data(source: &InputSource) -> Result<String, ReadError> {
+    source.read("y2024_day_22.txt")
+}
+
+pub fn read_input(source: &InputSource, name: &str) -> Result<NamedInput, ReadError> {
+    source.read_named("y2024_day_22.txt", name)
 }
 
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -16,28 +19,33 @@ pub struct Input {
     secret_seeds: Vec<usize>,
 }
 
-impl Input {
-    #[must_use]
-    pub fn from_data(data: &str) -> Self {
+impl Solution<'_> for Input {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 22;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn try_from_data(data: &str) -> Result<Self, ParseError> {
+        let source = Source::new(data);
+
         let secret_seeds = data
             .trim()
             .lines()
-            .map(|line| line.parse().unwrap())
-            .collect();
+            .map(|line| source.number(line))
+            .collect::<Result<_, _>>()?;
 
-        Self { secret_seeds }
+        Ok(Self { secret_seeds })
     }
 
-    #[must_use]
-    pub fn part_1(&self) -> usize {
+    fn part_1(&self) -> usize {
         self.secret_seeds
             .iter()
             .map(|&secret_number| Monkey { secret_number }.nth(2000).unwrap())
             .sum()
     }
 
-    #[must_use]
-    pub fn part_2(&self) -> usize {
+    fn part_2(&self) -> usize {
         let diffs = self
             .secret_seeds
             .iter()
@@ -102,19 +110,20 @@ impl Iterator for Monkey {
 
 impl Monkey {
     #[inline]
-    fn mix(&mut self, result: usize) {
+    const fn mix(&mut self, result: usize) {
         self.secret_number ^= result;
     }
 
     #[inline]
-    fn prune(&mut self) {
+    const fn prune(&mut self) {
         self.secret_number &= 0b1111_1111_1111_1111_1111_1111;
     }
 }
 
 #[cfg(test)]
-mod day_22_tests {
+mod y2024_day_22_tests {
     use super::*;
+    use common::answers::Answers;
 
     const DATA_DIR: &str = "../../data";
 
@@ -162,6 +171,32 @@ mod day_22_tests {
         }
     }
 
+    mod try_from_data {
+        use super::*;
+
+        struct Case {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        #[test]
+        fn invalid_seed() {
+            run(&Case {
+                input: "1\n10\n-100",
+                expected: "line 3, column 1: expected an integer (usize), found '-100'",
+            });
+        }
+
+        fn run(test: &Case) {
+            assert_eq!(
+                Some(test.expected.to_owned()),
+                Input::try_from_data(test.input)
+                    .err()
+                    .map(|err| err.to_string())
+            );
+        }
+    }
+
     mod part_1 {
         use super::*;
 
@@ -178,11 +213,12 @@ mod day_22_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_1,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 1, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_1())
             });
         }
 
@@ -207,11 +243,12 @@ mod day_22_tests {
             });
         }
 
-        #[test]
-        fn actual() {
-            run(&Case {
-                data: Input::from_data(&read_data(DATA_DIR)),
-                expected: PART_2,
+        include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+
+        fn actual(name: &str) {
+            let (named, answers) = super::actual(name);
+            answers.assert_input(Input::YEAR, Input::DAY, 2, &named, |data| {
+                Input::try_from_data(data).map(|input| input.part_2())
             });
         }
 
@@ -243,4 +280,10 @@ mod day_22_tests {
             },
         )
     }
+
+    fn actual(name: &str) -> (NamedInput, Answers) {
+        let source = InputSource::data_dir_or(DATA_DIR);
+        let answers = Answers::load(source.data_dir().unwrap()).unwrap();
+        (read_input(&source, name).unwrap(), answers)
+    }
 }